@@ -0,0 +1,49 @@
+use anyhow::Error;
+use pest_typed::{convert::FromTypedNode, ParsableTypedNode as _};
+use pest_typed_derive::{FromTypedNode, TypedParser};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+value      = _{ ident | number }
+item       =  { "let" ~ ident ~ "=" ~ value }
+list       =  { item ~ (";" ~ item)* ~ ";"? }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::value)]
+enum Value {
+    #[from(rule = ident, map = str::to_owned)]
+    Ident(String),
+    #[from(rule = number, with = str::parse)]
+    Number(i64),
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::item)]
+struct Item {
+    #[from(rule = ident, map = str::to_owned)]
+    name: String,
+    #[from(rule = value)]
+    value: Value,
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::list)]
+struct List {
+    #[from(rule = item)]
+    items: Vec<Item>,
+}
+
+fn main() -> Result<(), Error> {
+    let list = rules::list::try_parse("let a = 1; let b = a;")?;
+    let list = List::from_typed_node(&list)?;
+    assert_eq!(list.items[0].value, Value::Number(1));
+    assert_eq!(list.items[1].value, Value::Ident("a".to_owned()));
+    Ok(())
+}
@@ -34,10 +34,10 @@ fn parse(input: &'static str) -> Result<(), Error> {
     // Call `get_matched` to destruct the sequence.
     let (b_or_c, d) = a.get_matched();
     match b_or_c {
-        Choice2::_0(b) => assert_eq!(
-            std::mem::size_of_val(b.ref_inner()),
-            std::mem::size_of::<Box<rules::b<&str>>>()
-        ),
+        Choice2::_0(b) => {
+            assert_eq!(std::mem::size_of_val(b), std::mem::size_of::<Box<usize>>());
+            assert_eq!(b.ref_inner().to_string(), "b");
+        }
         Choice2::_1(c) => assert_eq!(std::mem::size_of_val(c), std::mem::size_of::<Span<&str>>()),
     }
     // Or match_choices from `pest_typed_derive`.
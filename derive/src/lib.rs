@@ -23,6 +23,7 @@
 //! When a rule is not atomic, inner contents that match `COMMENT` or `WHITESPACE` will be skipped automatically, and `COMMENT` is prior to `WHITESPACE`.
 //!
 //! Note that skipped items are taken into consideration when using [core::hash::Hash], [PartialEq] or [Eq].
+//! Use `pest_typed::semantic::SemanticEq` and `pest_typed::semantic::SemanticHash` to ignore them and positions of spans.
 //!
 //! ## Generation
 //!
@@ -85,6 +86,15 @@
 //! - Emit a span (atomic rule in **pest**).
 //! - Emit inner expression (silent rule in **pest**).
 //!
+//! #### Rules That Never Fail
+//!
+//! Rules made up of optional expressions and repetitions that may match nothing (such as `items = { item* }`),
+//! possibly in sequences, in choices with such a branch (such as `"c" | item?`)
+//! or through references to other such rules, always match a prefix of the input.
+//! They also implement `NeverFailedParsableTypedNode`,
+//! so `Parser::parse_partial` returns the rule struct and the rest of the input directly instead of a [`Result`].
+//! `Parser::parse` parses the whole input, and returns [`None`] if input other than trivia is left after the matched part.
+//!
 //! #### Example for Rule Structs
 //!
 //! ```rust
@@ -128,17 +138,17 @@
 //! |              Node Type              |                                                  Fields                                                   |                                             Functions                                             |
 //! | :---------------------------------: | :-------------------------------------------------------------------------------------------------------: | :-----------------------------------------------------------------------------------------------: |
 //! |           Non-silent rule           | Matched `content` (wrapped in a [`Box`]), which can be used to access matched expression; matched `span`. |                                See [Getter API](#getter-api).                                 |
-//! |    Exact string (case-sensitive)    |                                                                                                           | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
-//! |   Exact string (case-insensitive)   |                                     Matched `content` (an `&'i str`).                                     | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
+//! |    Exact string (case-sensitive)    |                                              Matched `span`.                                              | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
+//! |   Exact string (case-insensitive)   |                             Matched `content` (an `&'i str`); matched `span`.                             | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
 //! |        Sequence `T, Res...`         |                                       Matched `content` as a tuple.                                       |          `get_matched(&self)`, which returns referencs of all elemnets `(&elemnts...)`.           |
 //! |         Choices `T, Res...`         |                                   An enum, whose variants are choices.                                    |                       `if_then(&self)`, several functions `_0`, `_1`, etc.                        |
-//! | Optional (wrapped in an [`Option`]) |                                                                                                           |                                                                                                   |
-//! |          Repetition of `T`          |                                Matched `content` wrapped in a [`Vec<T>`].                                 | `iter_matched` and `iter_all` (by reference); `into_iter_matched` and `into_iter_all` (by value). |
+//! |              Optional               |                            Matched `content` (an [`Option`]); matched `span`.                             |                                                                                                   |
+//! |          Repetition of `T`          |                        Matched `content` wrapped in a [`Vec<T>`]; matched `span`.                         | `iter_matched` and `iter_all` (by reference); `into_iter_matched` and `into_iter_all` (by value). |
 //! |         Positive predicate          |                                     Matched `content` (not consumed).                                     |                                                                                                   |
-//! |         Negative predicate          |                                                                                                           |                                                                                                   |
+//! |         Negative predicate          |                                   An empty `span` where it is checked.                                    |                                                                                                   |
 //! |          `PUSH` and `PEEK`          |                                            Matched `content`.                                             |                                                                                                   |
 //! |         `POP` and `POP_ALL`         |                                              Popped `span`.                                               |                                                                                                   |
-//! |               `DROP`                |                                                                                                           |                                                                                                   |
+//! |               `DROP`                |                                   An empty `span` where it is matched.                                    |                                                                                                   |
 //!
 //! #### Sequence
 //!
@@ -152,6 +162,12 @@
 //! Auto-generated choices types are named as `Choice{n}` where `n` is the count of choices.
 //! And every generics used can be found in mod `generics`.
 //!
+//! Consecutive branches that match exactly one character (single-character strings, character ranges,
+//! built-in rules such as `ASCII_DIGIT` and unicode properties such as `XID_START`)
+//! are merged into a `CharSet`, whose `content` is the matched [`char`].
+//! For example, `'a'..'z' | "_" | XID_START` is a `CharSet` rather than a `Choice3`.
+//! Branches that are all single-character strings, such as `"+" | "-"`, are kept as choices.
+//!
 //! Similarly, we provide a proc macro `match_choices` to handle choices
 //! with a bit simpler syntax.
 //! Note that you need to import module `generics` to use the macro.
@@ -213,6 +229,10 @@ use proc_macro::TokenStream;
         simulate_pair_api,
         box_only_if_needed,
         no_warnings,
+        fast_trivia,
+        byte_input,
+        lazy_rule,
+        token_kind,
     )
 )]
 pub fn derive_typed_parser(input: TokenStream) -> TokenStream {
@@ -228,3 +248,31 @@ pub fn derive_typed_parser(input: TokenStream) -> TokenStream {
 pub fn match_choices(input: TokenStream) -> TokenStream {
     pest_typed_generator::match_choices(input.into()).into()
 }
+
+/// The main method that's called by the proc macro is [`pest_typed_generator::derive_from_typed_node()`].
+///
+/// Implements `pest_typed::convert::FromTypedNode` for a struct or an enum,
+/// so that typed nodes can be converted into user types, such as abstract syntax trees.
+///
+/// - `#[from(rule = rules::x)]` on the type sets the source node type, which is checked at compile time.
+/// - `#[from(rule = y)]` or `#[from(tag = y)]` on a field converts the nodes returned by getter `y`.
+///   The field type follows the return type of the getter, which is checked at compile time:
+///   `T` for a reference to a node, `Option<T>` for an optional node, and `Vec<T>` for others.
+///   Without them, the field is converted from the whole node.
+/// - `#[from(with = f)]` converts the text of a node with `f: fn(&str) -> Result<T, E>`, where `E: Display`,
+///   and `#[from(map = f)]` does so with `f: fn(&str) -> T`.
+///   Otherwise, `T: FromTypedNode` is required.
+/// - On an enum, each variant with `#[from(rule = y)]` or `#[from(tag = y)]` is tried in order,
+///   and the first one whose getter returns a node is converted from that node.
+///   A variant without them matches anything, and should be the last one.
+///   `with` and `map` on a variant apply to its only field.
+///
+/// Errors are `pest_typed::convert::ConvertError`, with the span of the failed node.
+///
+/// ```rust
+#[doc = include_str!("../examples/from_typed_node.rs")]
+/// ```
+#[proc_macro_derive(FromTypedNode, attributes(from))]
+pub fn derive_from_typed_node(input: TokenStream) -> TokenStream {
+    pest_typed_generator::derive_from_typed_node(input.into()).into()
+}
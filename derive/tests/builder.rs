@@ -0,0 +1,90 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    builder::Builder,
+    choices::Choice2,
+    iterators::PairTree,
+    predefined_node::{Optional, RepeatMin, Str},
+    semantic::SemanticEq as _,
+    sequence::Seq2,
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | NEWLINE }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+value      = _{ ident | number }
+item       =  { "let" ~ ident ~ "=" ~ value }
+list       =  { item ~ (";" ~ item)* ~ ";"? }
+"#]
+#[emit_rule_reference]
+#[fast_trivia]
+#[allow(dead_code)]
+struct Parser;
+
+fn item<'b>(builder: &'b Builder, name: &str, value: &str) -> rules::item<&'b str> {
+    let ident = rules::ident::from_children(builder.span(name));
+    let value = match value.parse::<u32>() {
+        Ok(_) => Choice2::_1(rules::number::from_children(builder.span(value))),
+        Err(_) => Choice2::_0(rules::ident::from_children(builder.span(value))),
+    };
+    // String literals are filled in.
+    rules::item::from_children(ident, rules::value::from_children(value))
+}
+
+#[test]
+fn build() {
+    let builder = Builder::new();
+    let a = builder.build(item(&builder, "a", "1"));
+    assert_eq!(a.span.as_str(), "let a = 1");
+    assert_eq!(a.to_string(), "let a = 1");
+    assert_eq!(a.ident().span.as_str(), "a");
+    assert_eq!((a.ident().span.start(), a.ident().span.end()), (4, 5));
+
+    // Children are not built until the root is.
+    let b = item(&builder, "b", "a");
+    assert_eq!(b.span.as_str(), "");
+    let list = builder.build(rules::list::from_children(
+        a,
+        RepeatMin::from(vec![Seq2::from((Str::default(), b))]),
+        Optional::default(),
+    ));
+    assert_eq!(list.to_string(), "let a = 1 ; let b = a");
+
+    // Spans of children are moved to the buffer of the root.
+    let items = list.find_all::<rules::item<&str>>();
+    assert_eq!(items[1].span.as_str(), "let b = a");
+    assert_eq!((items[1].span.start(), items[1].span.end()), (12, 21));
+    assert!(items[1].span.get_input() == list.span.get_input());
+
+    // Built trees are the same as parsed ones, even in positions, but in different inputs.
+    let parsed = rules::list::try_parse("let a = 1 ; let b = a").unwrap();
+    assert!(parsed.semantic_eq(&list));
+    assert_eq!(format!("{parsed:?}"), format!("{list:?}"));
+    assert_ne!(parsed, list);
+}
+
+#[test]
+fn trivia() {
+    let builder = Builder::with_trivia("\n");
+    let a = item(&builder, "x", "42");
+    assert_eq!(builder.trivia(), "\n");
+    assert_eq!(builder.text(&a), "let\nx\n=\n42");
+    let list = builder.build(rules::list::from_children(
+        a,
+        RepeatMin::from(vec![]),
+        Optional::from(Some(Str::default())),
+    ));
+    assert_eq!(list.to_string(), "let\nx\n=\n42\n;");
+    assert!(rules::list::try_parse(list.span.as_str()).is_ok());
+}
@@ -0,0 +1,50 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, RefStr as _, TypedParser as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+header = { "MAGIC" ~ version ~ payload }
+version = @{ '\u{00}'..'\u{7f}' }
+payload = @{ ('\u{80}'..'\u{ff}')* ~ ANY? }
+latin   = @{ "caf\u{e9}" ~ '\u{e0}'..'\u{ff}' }
+"#]
+#[emit_rule_reference]
+#[byte_input]
+struct Parser;
+
+#[test]
+fn binary() {
+    let input: &[u8] = b"MAGIC\x01\x80\xfe\xff";
+    let header: bytes::header = Parser::try_parse(input).unwrap();
+    assert_eq!(header.version().span.as_str(), b"\x01");
+    assert_eq!(header.payload().span.as_str().len(), 3);
+    assert_eq!(header.payload().span.end(), input.len());
+
+    let payload = bytes::payload::try_parse(&b"\xc3\xa9"[..]).unwrap();
+    assert_eq!(payload.span.end(), 2);
+}
+
+#[test]
+fn error() {
+    let input: &[u8] = b"MAGIC\xff";
+    let err = Parser::try_parse::<_, bytes::header>(input).unwrap_err();
+    let message = format!("{err}");
+    assert!(message.contains("version"), "{message}");
+}
+
+#[test]
+fn latin1() {
+    let latin = bytes::latin::try_parse(&b"caf\xe9\xe9"[..]).unwrap();
+    assert_eq!(latin.span.as_str().to_text(), "caf\u{e9}\u{e9}");
+
+    assert!(bytes::latin::try_parse("caf\u{e9}\u{e9}".as_bytes()).is_err());
+}
@@ -129,3 +129,44 @@ fn choices() {
     t!(_2);
     t!(_3);
 }
+
+mod char_set {
+    use anyhow::Error;
+    use pest_typed::TypedParser;
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+ident    = @{ (XID_START | "_") ~ (XID_CONTINUE | "$")* }
+hex      = @{ (ASCII_HEX_DIGIT | "_")+ }
+mixed    = { "x" | 'a'..'c' | "d" | "e" ~ "f" | "g" | "h" }
+literals = { "p" | "q" }
+"#]
+    #[emit_rule_reference]
+    struct Parser;
+
+    #[test]
+    fn merged() -> Result<(), Error> {
+        Parser::try_check::<&str, pairs::ident<&str>>("_foo$Bär0")?;
+        Parser::try_check::<&str, pairs::ident<&str>>("0foo").unwrap_err();
+        Parser::try_check::<&str, pairs::hex<&str>>("dead_BEEF_01")?;
+        Parser::try_check::<&str, pairs::hex<&str>>("g").unwrap_err();
+
+        let mixed = Parser::try_parse::<&str, pairs::mixed<&str>>("b")?;
+        assert_eq!(mixed.content._0().unwrap().content, 'b');
+        let mixed = Parser::try_parse::<&str, pairs::mixed<&str>>("ef")?;
+        assert!(mixed.content._1().is_some());
+        Parser::try_check::<&str, pairs::mixed<&str>>("f").unwrap_err();
+        Ok(())
+    }
+
+    /// Choices only of string literals are not merged, so each branch can be matched.
+    #[test]
+    fn literals_kept() -> Result<(), Error> {
+        let mixed = Parser::try_parse::<&str, pairs::mixed<&str>>("h")?;
+        assert!(mixed.content._3().is_some());
+        let literals = Parser::try_parse::<&str, pairs::literals<&str>>("q")?;
+        assert!(literals.content._1().is_some());
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::num::NonZeroUsize;
+use pest_typed::{
+    chunks::{split_chunks, try_parse_chunks, try_parse_chunks_with_threads},
+    ParsableTypedNode as _, RefStr as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+blank      =  { NEWLINE ~ NEWLINE+ }
+key        = @{ ASCII_ALPHA+ }
+value      = @{ (!NEWLINE ~ ANY)+ }
+entry      =  { key ~ "=" ~ value }
+record     =  { entry ~ (NEWLINE ~ entry)* }
+next       =  { &"section" }
+section    =  { "section" ~ key ~ NEWLINE* }
+tagged     =  { PUSH(key) ~ ":" ~ key }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn records(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("name = r{i}\nsize = {i}"))
+        .collect::<Vec<_>>()
+        .join("\n\n\n")
+}
+
+#[test]
+fn split() {
+    let input = "\na\n\nb\nc\n\n";
+    let chunks = split_chunks::<_, Rule, rules::blank<_>>(input);
+    let chunks: Vec<_> = chunks.iter().map(|chunk| chunk.as_str()).collect();
+    assert_eq!(chunks, ["\na", "b\nc"]);
+
+    let input = "section a\nsection b\n";
+    let chunks = split_chunks::<_, Rule, rules::next<_>>(input);
+    let chunks: Vec<_> = chunks.iter().map(|chunk| chunk.as_str()).collect();
+    assert_eq!(chunks, ["section a\n", "section b\n"]);
+
+    assert!(split_chunks::<_, Rule, rules::blank<_>>("").is_empty());
+    assert!(split_chunks::<_, Rule, rules::blank<_>>("\n\n").is_empty());
+}
+
+#[test]
+fn parallel() {
+    let input = records(1000);
+    let threads = NonZeroUsize::new(4).unwrap();
+    let results = try_parse_chunks_with_threads::<_, _, rules::record<_>, rules::blank<_>>(
+        input.as_str(),
+        threads,
+    );
+    assert_eq!(results.len(), 1000);
+    let mut offset = 0;
+    for (i, result) in results.into_iter().enumerate() {
+        let record = result.unwrap();
+        assert!(record.span.get_input().ptr_eq(&input.as_str()));
+        assert_eq!(record.span.start(), offset);
+        let (first, rest) = record.entry();
+        assert_eq!(first.value().span.as_str(), format!("r{i}"));
+        assert_eq!(rest[0].value().span.as_str(), format!("{i}"));
+        offset = record.span.end() + 3;
+    }
+}
+
+#[test]
+fn sections() {
+    let input = "section a\n\nsection b\nsection c";
+    let results = try_parse_chunks::<_, _, rules::section<_>, rules::next<_>>(input);
+    let keys: Vec<_> = results
+        .into_iter()
+        .map(|section| section.unwrap().key().span.as_str())
+        .collect();
+    assert_eq!(keys, ["a", "b", "c"]);
+}
+
+#[test]
+fn error() {
+    let input = records(100).replacen("size = 50", "size 50", 1);
+    let results = try_parse_chunks::<_, _, rules::record<_>, rules::blank<_>>(input.as_str());
+    let errors: Vec<_> = results.into_iter().filter_map(Result::err).collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        format!("{}", errors[0]),
+        "
+   --> 202:1
+    |
+202 | size 50
+    | ^---
+    |
+    = ^---"
+            .trim_start_matches('\n')
+    );
+    let whole = records(100);
+    let chunk = split_chunks::<_, Rule, rules::blank<_>>(whole.as_str())[50];
+    let record = rules::record::try_parse(chunk).unwrap();
+    assert_eq!(record.span.start(), whole.find("name = r50").unwrap());
+}
+
+#[test]
+fn stack() {
+    let input = "a: b\n\nc: d";
+    let results = try_parse_chunks::<_, _, rules::tagged<_>, rules::blank<_>>(input);
+    assert_eq!(results.len(), 2);
+    let error = results[1].as_ref().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 3:5
+  |
+3 | c: d
+  |     ^---
+  |
+  = 1 element(s) left on the stack, so the chunk can not be parsed independently."
+            .trim_start_matches('\n')
+    );
+}
@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{comments::Placement, iterators::PairTree, ParsableTypedNode as _, Span};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | NEWLINE }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* | "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+ident      = @{ ASCII_ALPHA+ }
+param      =  { ident }
+params     =  { param ~ ("," ~ param)* }
+block      =  { "{" ~ "}" }
+fn_decl    =  { "fn" ~ ident ~ "(" ~ params? ~ ")" ~ block }
+file       =  { SOI ~ fn_decl* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = "// leading of a
+fn a(x /* after x */, y) {} // trailing of a
+
+/* leading of b */
+fn b() { /* dangling in b */ }
+// trailing of b
+";
+
+fn texts<'i>(spans: Vec<&Span<&'i str>>) -> Vec<&'i str> {
+    spans.into_iter().map(|span| span.as_str()).collect()
+}
+
+#[test]
+fn attach() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let comments = file.comments();
+    let decls = file.find_all::<rules::fn_decl<&str>>();
+    assert_eq!(texts(comments.leading(decls[0])), ["// leading of a"]);
+    assert_eq!(texts(comments.trailing(decls[0])), ["// trailing of a"]);
+    assert_eq!(texts(comments.leading(decls[1])), ["/* leading of b */"]);
+    assert_eq!(texts(comments.trailing(decls[1])), ["// trailing of b"]);
+
+    // Inline comments trail the preceding node if other tokens follow.
+    let params = file.find_all::<rules::param<&str>>();
+    assert_eq!(texts(comments.trailing(params[0])), ["/* after x */"]);
+    assert!(comments.leading(params[1]).is_empty());
+
+    let blocks = file.find_all::<rules::block<&str>>();
+    assert!(comments.dangling(blocks[0]).is_empty());
+    assert_eq!(texts(comments.dangling(blocks[1])), ["/* dangling in b */"]);
+}
+
+#[test]
+fn all() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let comments = file.comments();
+    let all: Vec<_> = comments
+        .all()
+        .iter()
+        .map(|comment| (comment.placement, comment.rule, comment.span.as_str()))
+        .collect();
+    assert_eq!(
+        all,
+        [
+            (Placement::Leading, Rule::fn_decl, "// leading of a"),
+            (Placement::Trailing, Rule::param, "/* after x */"),
+            (Placement::Trailing, Rule::fn_decl, "// trailing of a"),
+            (Placement::Leading, Rule::fn_decl, "/* leading of b */"),
+            (Placement::Dangling, Rule::block, "/* dangling in b */"),
+            (Placement::Trailing, Rule::fn_decl, "// trailing of b"),
+        ]
+    );
+}
+
+#[test]
+fn inline_before_node() {
+    let file = rules::file::try_parse("fn a(/* first */ x, y) {}").unwrap();
+    let comments = file.comments();
+    // Comments are attached to the outermost node starting after them.
+    let params = file.find_all::<rules::params<&str>>();
+    assert_eq!(texts(comments.leading(params[0])), ["/* first */"]);
+    let param = file.find_all::<rules::param<&str>>();
+    assert!(comments.leading(param[0]).is_empty());
+}
@@ -0,0 +1,116 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    iterators::{Pair, PairTree, PairView},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+args       =  { ident? ~ ("," ~ ident)* }
+call       =  { ident ~ "(" ~ args ~ ")" }
+stmt       =  { call ~ ";" }
+block      =  { "{" ~ stmt* ~ "}" }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = "{ f(a, b); g(); }";
+
+fn describe(chain: Vec<PairView<&str, Rule>>) -> Vec<(Rule, &str)> {
+    chain
+        .into_iter()
+        .map(|view| (view.rule, view.span.as_str()))
+        .collect()
+}
+
+#[test]
+fn node_at() {
+    let block = rules::block::try_parse(INPUT).unwrap();
+    let offset = INPUT.find('b').unwrap();
+    assert_eq!(
+        describe(block.node_at(offset)),
+        [
+            (Rule::block, INPUT),
+            (Rule::stmt, "f(a, b);"),
+            (Rule::call, "f(a, b)"),
+            (Rule::args, "a, b"),
+            (Rule::ident, "b"),
+        ]
+    );
+    assert_eq!(
+        describe(block.node_at(INPUT.find(',').unwrap())),
+        [
+            (Rule::block, INPUT),
+            (Rule::stmt, "f(a, b);"),
+            (Rule::call, "f(a, b)"),
+            (Rule::args, "a, b"),
+        ]
+    );
+    assert_eq!(describe(block.node_at(INPUT.len())), [(Rule::block, INPUT)]);
+    assert!(block.node_at(INPUT.len() + 1).is_empty());
+    assert_eq!(describe(block.node_at(0)), [(Rule::block, INPUT)]);
+}
+
+#[test]
+fn covering() {
+    let block = rules::block::try_parse(INPUT).unwrap();
+    let start = INPUT.find("a,").unwrap();
+    let end = INPUT.find("b)").unwrap() + 1;
+    let enclosing = block.smallest_enclosing(start..end).unwrap();
+    assert_eq!(
+        (enclosing.rule, enclosing.span.as_str()),
+        (Rule::args, "a, b")
+    );
+
+    let start = INPUT.find('f').unwrap();
+    let end = INPUT.find('g').unwrap() + 1;
+    assert_eq!(
+        describe(block.nodes_covering(start..end)),
+        [(Rule::block, INPUT)]
+    );
+
+    // Empty arguments of `g`.
+    let offset = INPUT.find("g(").unwrap() + 2;
+    assert_eq!(
+        describe(block.nodes_covering(offset..offset)),
+        [
+            (Rule::block, INPUT),
+            (Rule::stmt, "g();"),
+            (Rule::call, "g()"),
+            (Rule::args, ""),
+        ]
+    );
+    assert_eq!(block.smallest_enclosing(0..INPUT.len() + 1), None);
+}
+
+#[test]
+fn token() {
+    let block = rules::block::try_parse(INPUT).unwrap();
+    let token = block.as_token();
+    for offset in 0..=INPUT.len() {
+        assert_eq!(token.node_at(offset), block.node_at(offset));
+        for end in offset..=INPUT.len() {
+            assert_eq!(
+                token.nodes_covering(offset..end),
+                block.nodes_covering(offset..end)
+            );
+        }
+    }
+    let offset = INPUT.find('g').unwrap();
+    assert_eq!(
+        token.smallest_enclosing(offset..offset + 1).unwrap().rule,
+        Rule::ident
+    );
+}
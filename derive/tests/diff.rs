@@ -0,0 +1,137 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    diff::{Change, TreeDiff},
+    iterators::{Pair, PairTree},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+value      = _{ ident | number }
+item       =  { ident ~ "=" ~ value }
+list       =  { item ~ ("," ~ item)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn describe<'i>(diff: &TreeDiff<&'i str, Rule>) -> Vec<(char, Rule, &'i str)> {
+    diff.changes()
+        .iter()
+        .map(|change| match change {
+            Change::Insert(rule, span) => ('+', *rule, span.as_str()),
+            Change::Delete(rule, span) => ('-', *rule, span.as_str()),
+            Change::Move(rule, _, span) => ('>', *rule, span.as_str()),
+            Change::Update(rule, _, span) => ('~', *rule, span.as_str()),
+        })
+        .collect()
+}
+
+#[test]
+fn same() {
+    let old = rules::list::try_parse("a = 1, b = c").unwrap();
+    assert!(old.diff(&old).is_empty());
+    // Only the text between children is changed.
+    let new = rules::list::try_parse("a = 1 , b=c").unwrap();
+    assert_eq!(describe(&old.diff(&new)), [('~', Rule::item, "b=c")]);
+}
+
+#[test]
+fn insert_delete_update() {
+    let old = rules::list::try_parse("a = 1, b = 2, c = 3").unwrap();
+    let new = rules::list::try_parse("a = 1, b = 5, d = 4, c = 3").unwrap();
+    let diff = old.diff(&new);
+    assert_eq!(
+        describe(&diff),
+        [('~', Rule::number, "5"), ('+', Rule::item, "d = 4"),]
+    );
+
+    let diff = new.diff(&old);
+    assert_eq!(
+        describe(&diff),
+        [('~', Rule::number, "2"), ('-', Rule::item, "d = 4"),]
+    );
+}
+
+#[test]
+fn replace() {
+    let old = rules::item::try_parse("a = 1").unwrap();
+    let new = rules::item::try_parse("a = b").unwrap();
+    assert_eq!(
+        describe(&old.diff(&new)),
+        [('-', Rule::number, "1"), ('+', Rule::ident, "b")]
+    );
+}
+
+#[test]
+fn moved() {
+    let old = rules::list::try_parse("a = 1, b = 2, c = 3").unwrap();
+    let new = rules::list::try_parse("b = 2, c = 3, a = 1").unwrap();
+    let diff = old.diff(&new);
+    assert_eq!(describe(&diff), [('>', Rule::item, "a = 1")]);
+    assert_eq!(
+        diff.changes()[0],
+        Change::Move(
+            Rule::item,
+            old.item().0.span,
+            new.find_all::<rules::item<&str>>()[2].span
+        )
+    );
+}
+
+#[test]
+fn format() {
+    let old = rules::list::try_parse("a = 1, b = 2").unwrap();
+    let new = rules::list::try_parse("a = x, b = 2, c = 3").unwrap();
+    let diff = TreeDiff::new(&old.as_token(), &new.as_token());
+    assert_eq!(
+        diff.to_string(),
+        r#"  list
+      item
+          ident "a"
+-         number "1"
++         ident "x"
+      item
+          ident "b"
+          number "2"
++     item
++         ident "c"
++         number "3"
+"#
+    );
+}
+
+#[test]
+fn long() {
+    let items =
+        |f: &dyn Fn(usize) -> Option<String>| (0..200).filter_map(f).collect::<Vec<_>>().join(", ");
+    let old = items(&|i| Some(format!("x = {i}")));
+    let new = items(&|i| match i {
+        50 => None,
+        120 => Some("x = 120, y = 1".to_owned()),
+        150 => Some("x = 0".to_owned()),
+        _ => Some(format!("x = {i}")),
+    });
+    let old = rules::list::try_parse(&old).unwrap();
+    let new = rules::list::try_parse(&new).unwrap();
+    assert_eq!(
+        describe(&old.diff(&new)),
+        [
+            ('-', Rule::item, "x = 50"),
+            ('+', Rule::item, "y = 1"),
+            ('~', Rule::number, "0"),
+        ]
+    );
+}
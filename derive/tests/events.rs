@@ -0,0 +1,137 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{tracker::Event, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+number     = @{ digit+ }
+digit      =  { ASCII_DIGIT }
+ident      = @{ ASCII_ALPHA+ }
+call       =  { ident ~ "(" ~ ")" }
+atom       =  { call | ident | number }
+pair       =  { atom ~ ":" ~ atom }
+item       =  { pair | atom }
+list       =  { item ~ ("," ~ item)* ~ ","? ~ !ident }
+main       =  { SOI ~ list }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// Collect events after applying rollbacks.
+fn collect(input: &str) -> (Vec<String>, bool) {
+    let mut events: Vec<String> = vec![];
+    let res = rules::main::try_check_with_events(input, |event| {
+        events.push(match event {
+            Event::Enter(rule, pos) => format!("enter {rule:?} {}", pos.pos()),
+            Event::Exit(rule, span) => format!("exit {rule:?} {:?}", span.as_str()),
+            Event::Fail(rule, pos) => format!("fail {rule:?} {}", pos.pos()),
+            Event::Rollback(mut count) => {
+                while count > 0 {
+                    let index = events.iter().rposition(|event| !event.starts_with("fail"));
+                    events.remove(index.unwrap());
+                    count -= 1;
+                }
+                return;
+            }
+        })
+    });
+    (events, res.is_ok())
+}
+
+#[test]
+fn backtracking() {
+    let (events, ok) = collect("a:1, f()");
+    assert!(ok);
+    assert_eq!(
+        events,
+        [
+            "enter main 0",
+            "enter list 0",
+            "enter item 0",
+            "enter pair 0",
+            "enter atom 0",
+            "fail call 0",
+            "enter ident 0",
+            "exit ident \"a\"",
+            "exit atom \"a\"",
+            "enter atom 2",
+            "fail ident 2",
+            "fail call 2",
+            "fail ident 2",
+            "enter number 2",
+            "exit number \"1\"",
+            "exit atom \"1\"",
+            "exit pair \"a:1\"",
+            "exit item \"a:1\"",
+            "enter item 5",
+            "fail pair 5",
+            "enter atom 5",
+            "enter call 5",
+            "enter ident 5",
+            "exit ident \"f\"",
+            "exit call \"f()\"",
+            "exit atom \"f()\"",
+            "exit item \"f()\"",
+            "exit list \"a:1, f()\"",
+            "exit main \"a:1, f()\"",
+            "enter EOI 8",
+            "exit EOI \"\"",
+        ]
+    );
+}
+
+#[test]
+fn balanced() {
+    let (events, ok) = collect("x, 1:y, g(), 2,");
+    assert!(ok);
+    let mut depth = 0usize;
+    for event in &events {
+        if event.starts_with("enter") {
+            depth += 1;
+        } else if event.starts_with("exit") {
+            depth = depth.checked_sub(1).unwrap();
+        }
+    }
+    assert_eq!(depth, 0);
+    assert!(!events.iter().any(|event| event.contains("digit")));
+}
+
+#[test]
+fn failure() {
+    let (events, ok) = collect(":");
+    assert!(!ok);
+    assert_eq!(events.last().unwrap(), "fail main 0");
+    assert!(events.iter().all(|event| event.starts_with("fail")));
+    let (events, ok) = collect("a 1");
+    assert!(!ok);
+    assert_eq!(events.last().unwrap(), "fail EOI 2");
+    let error = rules::main::try_check_with_events("a 1", |_| ()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        rules::main::try_check("a 1").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn rollback() {
+    let mut events = vec![];
+    let res = rules::main::try_check_with_events("f()", |event| {
+        if let Event::Rollback(count) = event {
+            events.push(format!("rollback {count}"));
+        } else if let Event::Fail(rule, pos) = event {
+            events.push(format!("fail {rule:?} {}", pos.pos()));
+        }
+    });
+    assert!(res.is_ok());
+    assert_eq!(events, ["rollback 7", "fail pair 0"]);
+}
@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    iterators::{PairTree, TypedRule},
+    ParsableTypedNode as _, Spanned,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+atom       = _{ ident | number | "(" ~ expr ~ ")" }
+call       =  { ident ~ "(" ~ (expr ~ ("," ~ expr)*)? ~ ")" }
+expr       =  { (call | atom) ~ ("+" ~ (call | atom))* }
+body       =  { expr }
+lambda     =  { "\\" ~ ident ~ "." ~ body }
+"#]
+#[emit_rule_reference]
+#[lazy_rule = "body"]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn find_all() {
+    let expr = rules::expr::try_parse("f(a, 1 + (b + g(c))) + d").unwrap();
+    let idents: Vec<&str> = expr
+        .find_all::<rules::ident<&str>>()
+        .into_iter()
+        .map(|ident| ident.span().as_str())
+        .collect();
+    assert_eq!(idents, ["f", "a", "b", "g", "c", "d"]);
+
+    let calls: Vec<&str> = expr
+        .find_all::<rules::call<&str>>()
+        .into_iter()
+        .map(|call| call.span().as_str())
+        .collect();
+    assert_eq!(calls, ["f(a, 1 + (b + g(c)))", "g(c)"]);
+
+    // Only descendants are searched.
+    assert_eq!(expr.find_all::<rules::expr<&str>>().len(), 4);
+    assert!(expr.find_all::<rules::lambda<&str>>().is_empty());
+}
+
+#[test]
+fn find_first() {
+    let expr = rules::expr::try_parse("1 + (2 + x) + y").unwrap();
+    let first = expr.find_first::<rules::ident<&str>>().unwrap();
+    assert_eq!(first.span().as_str(), "x");
+    let first = expr.find_first::<rules::number<&str>>().unwrap();
+    assert_eq!(first.span().as_str(), "1");
+    assert!(expr.find_first::<rules::call<&str>>().is_none());
+}
+
+#[test]
+fn reachability() {
+    fn may_contain<T: TypedRule<&'static str, Rule>>(rule: Rule) -> bool {
+        T::may_contain(rule)
+    }
+    assert!(may_contain::<rules::expr<&str>>(Rule::ident));
+    assert!(may_contain::<rules::expr<&str>>(Rule::expr));
+    assert!(may_contain::<rules::call<&str>>(Rule::number));
+    assert!(!may_contain::<rules::expr<&str>>(Rule::lambda));
+    // Atomic rules have no children.
+    assert!(!may_contain::<rules::ident<&str>>(Rule::ident));
+    // Children of lazy rules are not searched.
+    assert!(!may_contain::<rules::lambda<&str>>(Rule::expr));
+    assert!(may_contain::<rules::lambda<&str>>(Rule::ident));
+}
+
+#[test]
+fn lazy() {
+    let lambda = rules::lambda::try_parse("\\x. x + y").unwrap();
+    let idents: Vec<&str> = lambda
+        .find_all::<rules::ident<&str>>()
+        .into_iter()
+        .map(|ident| ident.span().as_str())
+        .collect();
+    assert_eq!(idents, ["x"]);
+    let body = lambda.body().force::<Rule>().unwrap();
+    assert_eq!(body.find_all::<rules::ident<&str>>().len(), 2);
+}
@@ -0,0 +1,122 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    flat_tree::{DecodeError, FlatTree},
+    iterators::Pair,
+    ParsableTypedNode as _, RuleType as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+val  = { ASCII_DIGIT+ }
+mid  = { "+" | "*" }
+term = { val | "(" ~ expr ~ ")" }
+expr = { term ~ (mid ~ term)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const RULES: [Rule; 4] = [Rule::val, Rule::mid, Rule::term, Rule::expr];
+
+fn rule(name: &str) -> Option<Rule> {
+    RULES.into_iter().find(|rule| rule.name() == name)
+}
+
+#[test]
+fn layout() {
+    let input = "1+(2*3)";
+    let expr = rules::expr::try_parse(input).unwrap();
+    let tree = FlatTree::from_pair(&expr);
+    let rules: Vec<_> = tree.nodes().iter().map(|node| node.rule).collect();
+    assert_eq!(
+        rules,
+        [
+            Rule::expr,
+            Rule::term,
+            Rule::val,
+            Rule::mid,
+            Rule::term,
+            Rule::expr,
+            Rule::term,
+            Rule::val,
+            Rule::mid,
+            Rule::term,
+            Rule::val,
+        ]
+    );
+    assert_eq!(tree.len(), 11);
+    assert_eq!(tree.root().end, input.len());
+    assert_eq!(tree.children(0).collect::<Vec<_>>(), [1, 3, 4]);
+    assert_eq!(tree.children(5).collect::<Vec<_>>(), [6, 8, 9]);
+    assert_eq!(tree.first_child(2), None);
+    assert_eq!(tree.next_sibling(4), None);
+    assert_eq!(tree.next_sibling(6), Some(8));
+    assert_eq!(tree.parent(10), Some(9));
+    assert_eq!(tree.parent(0), None);
+    assert_eq!(tree.descendants(4), 5..11);
+    let node = tree.get(7).unwrap();
+    assert_eq!(&input[node.start..node.end], "2");
+}
+
+#[test]
+fn tokens() {
+    let input = "(1*2)+3";
+    let expr = rules::expr::try_parse(input).unwrap();
+    let tree = FlatTree::from_pair(&expr);
+    assert_eq!(tree.to_thin_token(0).unwrap(), expr.as_thin_token());
+    assert_eq!(tree.to_token(input, 0).unwrap(), expr.as_token());
+    assert_eq!(FlatTree::from(&expr.as_thin_token()), tree);
+    assert_eq!(FlatTree::from(&expr.as_token()), tree);
+    assert_eq!(tree.to_token("", 0), None);
+    assert_eq!(tree.to_thin_token(100), None);
+}
+
+#[test]
+fn bytes() {
+    let input = "1+(2*3)+((4))";
+    let expr = rules::expr::try_parse(input).unwrap();
+    let tree = FlatTree::from_pair(&expr);
+    let bytes = tree.to_bytes();
+    assert!(bytes.starts_with(b"PTFT\x01"));
+    assert_eq!(FlatTree::from_bytes(&bytes, rule).unwrap(), tree);
+
+    assert_eq!(
+        FlatTree::from_bytes(&bytes, |name| (name != "mid").then(|| rule(name)).flatten()),
+        Err(DecodeError::UnknownRule("mid".to_owned()))
+    );
+    assert_eq!(
+        FlatTree::from_bytes(&bytes[..bytes.len() - 1], rule),
+        Err(DecodeError::UnexpectedEnd)
+    );
+    assert_eq!(
+        FlatTree::from_bytes(b"PTFS\x01", rule),
+        Err(DecodeError::InvalidMagic)
+    );
+    assert_eq!(
+        FlatTree::from_bytes(b"PTFT\x02", rule),
+        Err(DecodeError::UnsupportedVersion(2))
+    );
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert_eq!(
+        FlatTree::from_bytes(&trailing, rule),
+        Err(DecodeError::InvalidData)
+    );
+}
+
+#[test]
+fn send() {
+    let input = "1+2";
+    let tree = FlatTree::from_pair(&rules::expr::try_parse(input).unwrap());
+    let handle = std::thread::spawn(move || tree.children(0).count());
+    assert_eq!(handle.join().unwrap(), 3);
+}
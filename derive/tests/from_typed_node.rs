@@ -0,0 +1,163 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::num::ParseIntError;
+use pest_typed::{convert::FromTypedNode, ParsableTypedNode as _};
+use pest_typed_derive::{FromTypedNode, TypedParser};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ "-"? ~ ASCII_DIGIT+ }
+boolean    =  { "true" | "false" }
+value      = _{ boolean | ident | number }
+ty         = @{ ASCII_ALPHA+ }
+param      =  { ident ~ (":" ~ ty)? }
+params     =  { "(" ~ (param ~ ("," ~ param)*)? ~ ")" }
+call       =  { ident ~ "(" ~ (value ~ ("," ~ value)*)? ~ ")" }
+fn_decl    =  { "fn" ~ ident ~ params ~ "=" ~ call }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn parse_i64(text: &str) -> Result<i64, ParseIntError> {
+    text.parse()
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::value)]
+enum Value {
+    #[from(rule = boolean, map = |text| text == "true")]
+    Bool(bool),
+    #[from(rule = number, with = parse_i64)]
+    Number(i64),
+    Ident(#[from(map = str::to_owned)] String),
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::param)]
+struct Param {
+    #[from(rule = ident, map = str::to_owned)]
+    name: String,
+    #[from(rule = ty, map = str::to_owned)]
+    ty: Option<String>,
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::call)]
+struct Call {
+    #[from(rule = ident, map = str::to_owned)]
+    callee: String,
+    #[from(rule = value)]
+    args: Vec<Value>,
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::fn_decl)]
+struct FnDecl {
+    #[from(rule = ident, map = str::to_owned)]
+    name: String,
+    #[from(rule = params)]
+    params: Params,
+    #[from(rule = call)]
+    body: Call,
+}
+
+#[derive(Debug, PartialEq, FromTypedNode)]
+#[from(rule = rules::params)]
+struct Params(#[from(rule = param)] Vec<Param>);
+
+#[test]
+fn convert() {
+    let input = "fn f(a: int, b) = g(true, -1, b)";
+    let decl = rules::fn_decl::try_parse(input).unwrap();
+    let decl = FnDecl::from_typed_node(&decl).unwrap();
+    assert_eq!(
+        decl,
+        FnDecl {
+            name: "f".to_owned(),
+            params: Params(vec![
+                Param {
+                    name: "a".to_owned(),
+                    ty: Some("int".to_owned()),
+                },
+                Param {
+                    name: "b".to_owned(),
+                    ty: None,
+                },
+            ]),
+            body: Call {
+                callee: "g".to_owned(),
+                args: vec![
+                    Value::Bool(true),
+                    Value::Number(-1),
+                    Value::Ident("b".to_owned()),
+                ],
+            },
+        }
+    );
+}
+
+#[test]
+fn error_span() {
+    let input = "g(1, 99999999999999999999)";
+    let call = rules::call::try_parse(input).unwrap();
+    let error = Call::from_typed_node(&call).unwrap_err();
+    assert_eq!(error.span.as_str(), "99999999999999999999");
+    assert_eq!((error.span.start(), error.span.end()), (5, 25));
+    assert_eq!(
+        error.message,
+        "number too large to fit in target type".to_owned()
+    );
+    assert_eq!(
+        error.to_string(),
+        "number too large to fit in target type (at 5..25: \"99999999999999999999\")"
+    );
+}
+
+#[cfg(feature = "grammar-extras")]
+mod tagged {
+    use pest_typed::{convert::FromTypedNode, ParsableTypedNode as _};
+    use pest_typed_derive::{FromTypedNode, TypedParser};
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+assign     =  { #target = ident ~ "=" ~ #source = ident }
+"#]
+    #[emit_rule_reference]
+    #[emit_tagged_node_reference]
+    #[allow(dead_code)]
+    struct Parser;
+
+    #[derive(Debug, PartialEq, FromTypedNode)]
+    #[from(rule = rules::assign)]
+    struct Assign {
+        #[from(tag = target, map = str::to_owned)]
+        target: String,
+        #[from(tag = source, map = str::to_owned)]
+        source: String,
+    }
+
+    #[test]
+    fn convert() {
+        let assign = rules::assign::try_parse("a = b").unwrap();
+        let assign = Assign::from_typed_node(&assign).unwrap();
+        assert_eq!(
+            assign,
+            Assign {
+                target: "a".to_owned(),
+                source: "b".to_owned(),
+            }
+        );
+    }
+}
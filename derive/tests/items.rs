@@ -0,0 +1,143 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{items::Items, Position};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+field   = @{ ASCII_ALPHANUMERIC+ }
+row     =  { field ~ ("," ~ field)* }
+newline =  { "\n" }
+quote   =  { "'" | "\"" }
+cell    =  { (PEEK ~ ASCII_ALPHA+ ~ PEEK) | PUSH(quote) }
+maybe   =  { field? }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn rows(input: &str) -> Items<Position<&str>, Rule, rules::row<&str>, rules::newline<&str>> {
+    Items::new(input, Rule::EOI)
+}
+
+#[test]
+fn csv() {
+    let mut items = rows("a,b,c\nd,e\nf\n");
+    let row = items.next().unwrap().unwrap();
+    let (first, rest) = row.field();
+    assert_eq!(first.span.as_str(), "a");
+    assert_eq!(rest.len(), 2);
+    assert_eq!(items.cursor().pos(), 5);
+    let row = items.next().unwrap().unwrap();
+    assert_eq!(row.span.as_str(), "d,e");
+    let row = items.next().unwrap().unwrap();
+    assert_eq!(row.span.as_str(), "f");
+    assert!(items.next().is_none());
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn empty() {
+    assert_eq!(rows("").count(), 0);
+    assert_eq!(rows("a").count(), 1);
+}
+
+#[test]
+fn bad_row() {
+    let mut items = rows("a,b\nc,\nd");
+    assert!(items.next().unwrap().is_ok());
+    assert!(items.next().unwrap().is_ok());
+    let error = items.next().unwrap().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 2:3
+  |
+2 | c,␊
+  |   ^---
+  |
+  = c,^---
+    Expected [field], by row."
+            .trim_start_matches('\n')
+    );
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn missing_separator() {
+    let mut items = rows("a b");
+    assert!(items.next().unwrap().is_ok());
+    let error = items.next().unwrap().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 1:2
+  |
+1 | a b
+  |  ^---
+  |
+  = a^---
+    Expected [EOI, newline]."
+            .trim_start_matches('\n')
+    );
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn unexpected_row() {
+    let mut items = rows("a\n,");
+    assert!(items.next().unwrap().is_ok());
+    let error = items.next().unwrap().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 2:1
+  |
+2 | ,
+  | ^---
+  |
+  = ^---
+    Expected [EOI, field]."
+            .trim_start_matches('\n')
+    );
+}
+
+#[test]
+fn shared_stack() {
+    let items = Items::<_, _, rules::cell<_>>::new("''a''b'", Rule::EOI);
+    let cells = items
+        .map(|cell| cell.unwrap().span.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(cells, ["'", "'a'", "'b'"]);
+    let mut items = Items::<_, _, rules::cell<_>>::new("'a\"", Rule::EOI);
+    assert!(items.next().unwrap().is_ok());
+    assert_eq!(items.stack().len(), 1);
+    assert!(items.next().unwrap().is_err());
+}
+
+#[test]
+fn empty_item() {
+    let mut items = Items::<_, _, rules::maybe<_>>::new("a,", Rule::EOI);
+    assert_eq!(items.next().unwrap().unwrap().span.as_str(), "a");
+    let error = items.next().unwrap().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 1:2
+  |
+1 | a,
+  |  ^---
+  |
+  = a^---
+    Expected [EOI, field]."
+            .trim_start_matches('\n')
+    );
+    assert!(items.next().is_none());
+}
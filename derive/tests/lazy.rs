@@ -0,0 +1,117 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    iterators::{Pair as _, PairTree as _, ThinToken},
+    ParsableTypedNode as _, Span,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+ident      = @{ ASCII_ALPHA+ }
+stmt       =  { ident ~ ";" }
+body       =  { "{" ~ stmt* ~ "}" }
+function   =  { "fn" ~ ident ~ body }
+file       =  { SOI ~ function* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[lazy_rule = "body"]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn force() {
+    let input = "fn f { a; b; }\nfn g { }";
+    let file = rules::file::try_parse(input).unwrap();
+    let functions = file.function();
+    assert_eq!(functions.len(), 2);
+    let body = functions[0].body();
+    assert_eq!(body.span.as_str(), "{ a; b; }");
+    let body = body.force().unwrap();
+    let stmts: Vec<_> = body
+        .stmt()
+        .iter()
+        .map(|s| s.ident().span.as_str())
+        .collect();
+    assert_eq!(stmts, ["a", "b"]);
+    assert_eq!(body.span.start(), 5);
+    assert!(functions[1].body().force().unwrap().stmt().is_empty());
+
+    // The rule must end at the end of the span.
+    let mut body = functions[0].body().clone();
+    body.span = Span::new(input, 5, 15).unwrap();
+    let error = body.force().unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 1:15
+  |
+1 | fn f { a; b; }␊
+  |               ^---
+  |
+  = Rule ended at position 14 rather than the end of the lazy span at position 15."
+            .trim_start_matches('\n')
+    );
+}
+
+#[test]
+fn error() {
+    let input = "fn f { a; b }";
+    let error = rules::file::try_parse(input).unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 1:11
+  |
+1 | fn f { a; b }
+  |           ^---
+  |
+  = fn f { a; ^---"
+            .trim_start_matches('\n')
+    );
+}
+
+#[test]
+fn pairs() {
+    let input = "fn f { a; }";
+    let function = rules::function::try_parse(input).unwrap();
+    let ident = ThinToken {
+        rule: Rule::ident,
+        start: 3,
+        end: 4,
+        children: vec![],
+    };
+    // Lazy rules are leaves.
+    let body = ThinToken {
+        rule: Rule::body,
+        start: 5,
+        end: 11,
+        children: vec![],
+    };
+    assert_eq!(function.as_thin_token().children, [ident, body]);
+
+    let body = function.children_iter().nth(1).unwrap();
+    assert_eq!(body.children_iter().count(), 0);
+
+    let chain: Vec<_> = function
+        .node_at(7)
+        .into_iter()
+        .map(|view| view.rule)
+        .collect();
+    assert_eq!(chain, [Rule::function, Rule::body]);
+
+    let body = function.body().force().unwrap();
+    let stmts: Vec<_> = body.children_iter().collect();
+    assert_eq!(stmts.len(), 1);
+    assert_eq!(stmts[0].span().as_str(), "a;");
+    let ident = stmts[0].children_iter().next().unwrap();
+    assert_eq!((ident.rule(), ident.span().start()), (Rule::ident, 7));
+}
@@ -0,0 +1,153 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    leaves::{Leaf, LeafKind},
+    RefStr, RuleType,
+};
+
+fn describe<R: RuleType>(leaves: &[Leaf<&str, R>]) -> Vec<(String, String)> {
+    leaves
+        .iter()
+        .map(|leaf| {
+            let kind = match leaf.kind {
+                LeafKind::Rule(rule) => rule.name().to_owned(),
+                kind => format!("{kind:?}"),
+            };
+            (kind, leaf.span.as_str().to_owned())
+        })
+        .collect()
+}
+
+fn concat<S: RefStr, R: RuleType>(leaves: &[Leaf<S, R>]) -> String {
+    leaves
+        .iter()
+        .map(|leaf| leaf.span.as_str().to_text().into_owned())
+        .collect()
+}
+
+mod shapes {
+    use super::{concat, describe};
+    use pest_typed::{iterators::PairTree, ParsableTypedNode as _};
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " | NEWLINE }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+value      = _{ ^"null" | ident | number }
+item       =  { ident ~ "=" ~ value }
+list       =  { item ~ ("," ~ item)* }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    struct Parser;
+
+    #[test]
+    fn lossless() {
+        let input = "a = 1, // two\nb=NULL";
+        let list = rules::list::try_parse(input).unwrap();
+        let leaves = list.leaves();
+        assert_eq!(concat(&leaves), input);
+        let expected = [
+            ("ident", "a"),
+            ("Whitespace", " "),
+            ("Literal", "="),
+            ("Whitespace", " "),
+            ("number", "1"),
+            ("Literal", ","),
+            ("Whitespace", " "),
+            ("Comment", "// two"),
+            ("Whitespace", "\n"),
+            ("ident", "b"),
+            ("Literal", "="),
+            ("Literal", "NULL"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(kind, text)| (kind.to_string(), text.to_string()))
+            .collect();
+        assert_eq!(describe(&leaves), expected);
+    }
+}
+
+mod preserved {
+    use super::{concat, describe};
+    use pest_typed::{iterators::PairTree, ParsableTypedNode as _};
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+COMMENT    = { "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+main       = { ('a'..'z' ~ "!"?)* }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    struct Parser;
+
+    #[test]
+    fn lossless() {
+        let input = "x! /* y */ z";
+        let main = rules::main::try_parse(input).unwrap();
+        let leaves = main.leaves();
+        assert_eq!(concat(&leaves), input);
+        let expected = [
+            ("Char", "x"),
+            ("Literal", "!"),
+            ("Whitespace", " "),
+            ("Comment", "/* y */"),
+            ("Whitespace", " "),
+            ("Char", "z"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(kind, text)| (kind.to_string(), text.to_string()))
+            .collect();
+        assert_eq!(describe(&leaves), expected);
+    }
+}
+
+mod stack {
+    use super::{concat, describe};
+    use pest_typed::{iterators::PairTree, ParsableTypedNode as _};
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+main = { PUSH("a" | "b") ~ PUSH("c") ~ PEEK[0..1] ~ "d" ~ PEEK ~ "e" }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    struct Parser;
+
+    #[test]
+    fn peeked_positions() {
+        let input = "bcbdce";
+        let main = rules::main::try_parse(input).unwrap();
+        let leaves = main.leaves();
+        assert_eq!(concat(&leaves), input);
+        let expected = [
+            ("Literal", "b"),
+            ("Literal", "c"),
+            // `PEEK[..]` keeps the span it matched.
+            ("Other", "b"),
+            ("Literal", "d"),
+            ("Literal", "c"),
+            ("Literal", "e"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(kind, text)| (kind.to_string(), text.to_string()))
+            .collect();
+        assert_eq!(describe(&leaves), expected);
+    }
+}
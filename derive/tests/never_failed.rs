@@ -0,0 +1,90 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{NeverFailedParsableTypedNode, TypedParser as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+item       =  { "a" | "b" }
+items      =  { item* }
+sign       = _{ "-"? }
+signed     =  { sign ~ items }
+number     = @{ ('0'..'9'){, 3} }
+pair       =  { items ~ ("," ~ items)? }
+either     =  { "c" | item? }
+"#]
+#[emit_rule_reference]
+struct Parser;
+
+#[test]
+fn repetition() {
+    let items: pairs::items<&str> = Parser::parse("a b a").unwrap();
+    assert_eq!(items.span.as_str(), "a b a");
+    assert_eq!(items.item().len(), 3);
+
+    let items: pairs::items<&str> = Parser::parse("").unwrap();
+    assert_eq!(items.span.as_str(), "");
+    assert!(items.item().is_empty());
+}
+
+#[test]
+fn rest() {
+    assert!(Parser::parse::<_, pairs::items<&str>>("a b c").is_none());
+
+    let items: pairs::items<&str> = Parser::parse("a b ").unwrap();
+    assert_eq!(items.span.as_str(), "a b");
+
+    let (pos, items) = pairs::items::parse_partial("a b c");
+    assert_eq!(items.span.as_str(), "a b");
+    assert_eq!(pos.pos(), 3);
+
+    let (pos, items): (_, pairs::items<&str>) = Parser::parse_partial("c");
+    assert_eq!(items.span.as_str(), "");
+    assert_eq!(pos.pos(), 0);
+}
+
+#[test]
+fn choice() {
+    let either: pairs::either<&str> = Parser::parse("a").unwrap();
+    assert_eq!(either.span.as_str(), "a");
+
+    let either: pairs::either<&str> = Parser::parse("c").unwrap();
+    assert_eq!(either.span.as_str(), "c");
+
+    let (pos, either) = pairs::either::parse_partial("d");
+    assert_eq!(either.span.as_str(), "");
+    assert_eq!(pos.pos(), 0);
+}
+
+#[test]
+fn sequence() {
+    let signed: pairs::signed<&str> = Parser::parse("-a b").unwrap();
+    assert_eq!(signed.span.as_str(), "-a b");
+
+    let signed: pairs::signed<&str> = Parser::parse("b").unwrap();
+    assert_eq!(signed.span.as_str(), "b");
+
+    let pair: pairs::pair<&str> = Parser::parse("a, b b").unwrap();
+    assert_eq!(pair.span.as_str(), "a, b b");
+}
+
+#[test]
+fn atomic() {
+    let (_, number): (_, pairs::number<&str>) = Parser::parse_partial("1234");
+    assert_eq!(number.span.as_str(), "123");
+
+    let (_, number): (_, pairs::number<&str>) = Parser::parse_partial("x");
+    assert_eq!(number.span.as_str(), "");
+
+    let number: pairs::number<&str> = Parser::parse("12").unwrap();
+    assert_eq!(number.span.as_str(), "12");
+    assert!(Parser::parse::<_, pairs::number<&str>>("12 ").is_none());
+}
@@ -0,0 +1,116 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    flat_tree::FlatTree,
+    iterators::PairTree as _,
+    query::{Query, QueryError},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+member     =  { ident ~ "." }
+args       =  { "(" ~ (ident ~ ("," ~ ident)*)? ~ ")" }
+call       =  { member? ~ ident ~ args }
+stmt       =  { call ~ ";" }
+file       =  { SOI ~ stmt* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = "a.f(x, y); g(z); b.h();";
+
+fn texts(query: &str) -> Vec<String> {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let query = Query::new(query).unwrap();
+    query
+        .matches(&file)
+        .into_iter()
+        .map(|m| m.token.span.as_str().to_owned())
+        .collect()
+}
+
+#[test]
+fn combinators() {
+    assert_eq!(texts("call > ident"), ["f", "g", "h"]);
+    assert_eq!(
+        texts("call ident"),
+        ["a", "f", "x", "y", "g", "z", "b", "h"]
+    );
+    assert_eq!(texts("stmt > call > args > ident"), ["x", "y", "z"]);
+    assert_eq!(texts("file member"), ["a.", "b."]);
+    assert_eq!(texts("member, args"), ["a.", "(x, y)", "(z)", "b.", "()"]);
+}
+
+#[test]
+fn pseudo_classes() {
+    assert_eq!(texts("call:has(> member:first-child) > ident"), ["f", "h"]);
+    assert_eq!(texts("call:has(ident[text = \"z\"])"), ["g(z)"]);
+    assert_eq!(texts("args > ident:first-child"), ["x", "z"]);
+    assert_eq!(texts("args > ident:last-child"), ["y", "z"]);
+    assert!(texts("stmt:last-child").is_empty());
+    assert_eq!(
+        texts("*:first-child > *:first-child > ident"),
+        ["a", "f", "b"]
+    );
+}
+
+#[test]
+fn predicates() {
+    assert_eq!(texts("ident[text = \"g\"]"), ["g"]);
+    assert_eq!(texts("ident[text != \"g\"][text ^= \"a\"]"), ["a"]);
+    assert_eq!(texts("call[text $= \")\"][text *= \"y\"]"), ["a.f(x, y)"]);
+    assert_eq!(texts("args[text=\"()\"]"), ["()"]);
+}
+
+#[test]
+fn captures() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let query = Query::new("call:has(> member > ident@object) > args > ident@arg").unwrap();
+    assert_eq!(query.capture_names(), ["object", "arg"]);
+    let matches = query.matches(&file);
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[1].capture("object").unwrap().span.as_str(), "a");
+    assert_eq!(matches[1].capture("arg").unwrap().span.as_str(), "y");
+    assert_eq!(matches[1].captures[1].token, matches[1].token);
+    assert_eq!(matches[1].token.rule, Rule::ident);
+
+    let tree = FlatTree::from_pair(&file);
+    assert_eq!(query.matches_in(&tree, INPUT), matches);
+    assert_eq!(file.query(&query), matches);
+}
+
+#[test]
+fn errors() {
+    let error = |query| Query::new(query).unwrap_err();
+    assert_eq!(
+        error("call >"),
+        QueryError {
+            offset: 6,
+            message: "Expected a rule name or `*`."
+        }
+    );
+    assert_eq!(error("call:hover").message, "Unknown pseudo-class.");
+    assert_eq!(error("call:has(ident").message, "Expected `)`.");
+    assert_eq!(
+        error("ident[text ~ \"a\"]").message,
+        "Expected an operator."
+    );
+    assert_eq!(error("ident[text = \"a]").message, "Unterminated string.");
+    assert_eq!(error("ident@").message, "Expected a capture name.");
+    assert_eq!(
+        error("ident )").to_string(),
+        "Unexpected character. (at offset 6)"
+    );
+}
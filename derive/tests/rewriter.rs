@@ -0,0 +1,123 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    iterators::PairTree,
+    rewriter::{RewriteError, Rewriter},
+    ParsableTypedNode as _, Span,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+value      = _{ ident | number }
+item       =  { ident ~ "=" ~ value }
+list       =  { item ~ ("," ~ item)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn rename() {
+    let input = "a = 1, b = a, c = 2";
+    let list = rules::list::try_parse(input).unwrap();
+    let mut rewriter = Rewriter::new(input);
+    for ident in list.find_all::<rules::ident<&str>>() {
+        if ident.span.as_str() == "a" {
+            rewriter.replace(&ident.span, "alpha").unwrap();
+        }
+    }
+    assert_eq!(rewriter.edits().len(), 2);
+    assert_eq!(rewriter.apply(), "alpha = 1, b = alpha, c = 2");
+    // The input is not changed.
+    assert_eq!(list.span.as_str(), input);
+}
+
+#[test]
+fn insert_and_delete() {
+    let input = "a = 1, b = 2, c = 3";
+    let list = rules::list::try_parse(input).unwrap();
+    let items = list.find_all::<rules::item<&str>>();
+    let mut rewriter = Rewriter::new(input);
+    rewriter
+        .insert_before(&items[0].span, "z = 0, ")
+        .unwrap()
+        .delete(&Span::new(input, 5, 12).unwrap())
+        .unwrap()
+        // Insertions at ends of a deleted span are kept.
+        .insert_after(&items[0].span, ", y = 9")
+        .unwrap()
+        .insert_after(&items[2].span, " ")
+        .unwrap()
+        .insert_after(&items[2].span, "// end")
+        .unwrap();
+    assert_eq!(rewriter.apply(), "z = 0, a = 1, y = 9, c = 3 // end");
+}
+
+#[test]
+fn overlap() {
+    let input = "a = 1, b = 2";
+    let list = rules::list::try_parse(input).unwrap();
+    let items = list.find_all::<rules::item<&str>>();
+    let idents = list.find_all::<rules::ident<&str>>();
+    let mut rewriter = Rewriter::new(input);
+    rewriter.replace(&items[0].span, "a = 2").unwrap();
+    assert_eq!(
+        rewriter.replace(&idents[0].span, "x").unwrap_err(),
+        RewriteError::Overlap(items[0].span, idents[0].span)
+    );
+    let inside = Span::new(input, 2, 2).unwrap();
+    assert!(rewriter.insert_before(&inside, "!").is_err());
+    assert_eq!(rewriter.edits().len(), 1);
+
+    let other = String::from(input);
+    let foreign = Span::new_full(other.as_str());
+    assert_eq!(
+        rewriter.delete(&foreign).unwrap_err(),
+        RewriteError::ForeignSpan(foreign)
+    );
+}
+
+#[test]
+fn validate() {
+    let input = "a = 1, b = 2";
+    let list = rules::list::try_parse(input).unwrap();
+    let items = list.find_all::<rules::item<&str>>();
+
+    let mut rewriter = Rewriter::new(input);
+    rewriter.replace(&items[1].span, "c = d").unwrap();
+    let mut output = String::new();
+    let list = rewriter
+        .apply_parse::<Rule, rules::list<&str>>(&mut output)
+        .unwrap();
+    assert_eq!(list.span.as_str(), "a = 1, c = d");
+
+    let mut rewriter = Rewriter::new(input);
+    rewriter.delete(&items[1].span).unwrap();
+    let mut output = String::new();
+    assert!(rewriter
+        .apply_parse::<Rule, rules::list<&str>>(&mut output)
+        .is_err());
+    assert_eq!(output, "a = 1, ");
+}
+
+#[test]
+fn non_ascii() {
+    let input = "α = 1, b = 2 // β";
+    let mut rewriter = Rewriter::new(input);
+    rewriter
+        .replace(&Span::new(input, 5, 6).unwrap(), "γ")
+        .unwrap();
+    // Unchanged texts are copied as they are.
+    assert_eq!(rewriter.apply(), "α = γ, b = 2 // β");
+}
@@ -0,0 +1,55 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{rope::Rope, ParsableTypedNode as _, RefStr as _};
+use pest_typed_derive::TypedParser;
+use std::borrow::Cow;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+ident      =  @{ ASCII_ALPHA+ }
+call       =  { ident ~ "(" ~ (ident ~ ("," ~ ident)*)? ~ ")" }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn across_chunks() {
+    let rope: Rope = ["pri", "nt(fo", "o, ", "bar", ")"].into_iter().collect();
+    let call = rules::call::try_parse(&rope).unwrap();
+    let (function, args) = call.ident();
+    assert!(matches!(function.span.as_str().to_text(), Cow::Owned(s) if s == "print"));
+    let (_, rest) = args.unwrap();
+    assert_eq!(rest.len(), 1);
+    assert!(matches!(
+        rest[0].span.as_str().to_text(),
+        Cow::Borrowed("bar")
+    ));
+    assert_eq!(rest[0].span.as_str().range(), 11..14);
+}
+
+#[test]
+fn error() {
+    let rope: Rope = ["f(a,\n", " ", ")"].into_iter().collect();
+    let error = rules::call::try_parse(&rope).unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 2:2
+  |
+2 |  )
+  |  ^---
+  |
+  =  ^---
+    Expected [ident], by call."
+            .trim_start_matches('\n')
+    );
+}
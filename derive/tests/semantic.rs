@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    choices::Choice2,
+    semantic::{Semantic, SemanticEq, SemanticHash},
+    sequence::Seq2,
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::Hasher,
+};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = { " " }
+COMMENT    = { "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+op         = { "+" | "-" }
+expr       = { (ident | number) ~ (op ~ (ident | number))* }
+list       = { expr ~ (";" ~ expr)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn hash(value: &impl SemanticHash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.semantic_hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn trivia() {
+    let a = rules::expr::try_parse("a + 1").unwrap();
+    let b = rules::expr::try_parse("a/* one */+1").unwrap();
+    assert_ne!(a, b);
+    assert!(a.semantic_eq(&b));
+    assert_eq!(hash(&a), hash(&b));
+
+    let c = rules::expr::try_parse("a - 1").unwrap();
+    assert!(!a.semantic_eq(&c));
+    let d = rules::expr::try_parse("a + 1 + 2").unwrap();
+    assert!(!a.semantic_eq(&d));
+}
+
+#[test]
+fn positions() {
+    let list = rules::list::try_parse("x + y; a + 1; x+y").unwrap();
+    let (first, rest) = list.expr();
+    let (second, third) = (rest[0], rest[1]);
+    assert_ne!(first, third);
+    assert!(first.semantic_eq(third));
+    assert!(!first.semantic_eq(second));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(Semantic(first)));
+    assert!(set.insert(Semantic(second)));
+    assert!(!set.insert(Semantic(third)));
+    assert_eq!(set.len(), 2);
+}
+
+/// Compared by equality only, without hashing.
+struct Parity(u8);
+
+impl SemanticEq for Parity {
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.0 % 2 == other.0 % 2
+    }
+}
+
+#[test]
+fn eq_only() {
+    let a = Choice2::<Parity, Parity>::_0(Parity(1));
+    assert!(a.semantic_eq(&Choice2::_0(Parity(3))));
+    assert!(!a.semantic_eq(&Choice2::_0(Parity(2))));
+    assert!(!a.semantic_eq(&Choice2::_1(Parity(1))));
+
+    let b = Seq2::<Parity, Parity>::from((Parity(1), Parity(2)));
+    assert!(b.semantic_eq(&Seq2::<Parity, Parity>::from((Parity(3), Parity(4)))));
+    assert!(!b.semantic_eq(&Seq2::<Parity, Parity>::from((Parity(3), Parity(3)))));
+}
@@ -57,3 +57,56 @@ fn pre_skip() -> Result<(), Error> {
     assert_eq!(main.iter_matched().len(), 2);
     Ok(())
 }
+
+mod trivia {
+    use anyhow::Error;
+    use pest_typed::{predefined_node::SkipTrivia, ParsableTypedNode as _};
+    use pest_typed_derive::TypedParser;
+
+    #[allow(dead_code)]
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " | "\t" | NEWLINE }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* | "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+main       =  { "x"* }
+"#]
+    #[fast_trivia]
+    struct Parser;
+
+    #[test]
+    fn fast_path() -> Result<(), Error> {
+        let _: generics::Skipped<&str> = SkipTrivia::<&str, _>::default();
+        let main = pairs::main::try_parse("x\t/* x */ x // x\r\n x\n// x")?;
+        assert_eq!(main.iter_matched().len(), 3);
+        pairs::main::try_parse("x /* x").unwrap_err();
+        Ok(())
+    }
+
+    mod preserved {
+        use anyhow::Error;
+        use pest_typed::ParsableTypedNode as _;
+        use pest_typed_derive::TypedParser;
+
+        #[allow(dead_code)]
+        #[derive(TypedParser)]
+        #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+COMMENT    = _{ "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+main       =  { "x"* }
+"#]
+        struct Parser;
+
+        /// Without `#[fast_trivia]`, skipped trivia are kept.
+        #[test]
+        fn skipped() -> Result<(), Error> {
+            let main = pairs::main::try_parse("x /* x */ x")?;
+            assert_eq!(main.iter_matched().len(), 2);
+            let skipped: usize = main
+                .iter_all()
+                .map(|item| item.skipped[0].content.len())
+                .sum();
+            assert_eq!(skipped, 3);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, Span, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+digits     =  { ASCII_DIGIT ~ ASCII_DIGIT }
+value      = _{ ident | number }
+both       = _{ ident ~ number }
+ahead      = _{ &ident ~ ident }
+maybe      = _{ ident? }
+item       =  { ident ~ "=" ~ value }
+list       =  { item ~ ("," ~ item)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn span<'i, T: Spanned<&'i str, Rule>>(node: &T) -> Span<&'i str> {
+    node.span()
+}
+
+#[test]
+fn silent() {
+    let value = rules::value::try_parse("abc").unwrap();
+    assert_eq!(span(&value).as_str(), "abc");
+    assert_eq!(span(&value.content).as_str(), "abc");
+    assert_eq!(value.to_string(), "abc");
+
+    let value = rules::value::try_parse("42").unwrap();
+    assert_eq!(span(&value).as_str(), "42");
+    assert_eq!(value.to_string(), "42");
+}
+
+#[test]
+fn sequence() {
+    let both = rules::both::try_parse("ab  12").unwrap();
+    assert_eq!(span(&both).as_str(), "ab  12");
+    assert_eq!((span(&both).start(), span(&both).end()), (0, 6));
+    // Sequences print skipped trivia as they are.
+    assert_eq!(both.to_string(), "ab  12");
+    assert_eq!(both.content.to_string(), "ab  12");
+}
+
+#[test]
+fn consume_nothing() {
+    let ahead = rules::ahead::try_parse("abc").unwrap();
+    let (positive, ident) = ahead.content.get_matched();
+    let empty = span(positive);
+    assert_eq!((empty.start(), empty.end()), (0, 0));
+    assert_eq!(positive.to_string(), "");
+    assert_eq!(span(ident).as_str(), "abc");
+    assert_eq!(span(&ahead).as_str(), "abc");
+
+    let maybe = rules::maybe::try_parse("").unwrap();
+    let empty = span(&maybe.content);
+    assert_eq!((empty.start(), empty.end()), (0, 0));
+    assert_eq!(maybe.to_string(), "");
+    let maybe = rules::maybe::try_parse("abc").unwrap();
+    assert_eq!(span(&maybe.content).as_str(), "abc");
+}
+
+#[test]
+fn leaves() {
+    let item = rules::item::try_parse("a =  1").unwrap();
+    let (ident, eq, value) = item.content.get_matched();
+    assert_eq!(span(ident).as_str(), "a");
+    assert_eq!((span(eq).start(), span(eq).end()), (2, 3));
+    assert_eq!(eq.to_string(), "=");
+    assert_eq!(span(value).as_str(), "1");
+
+    let digits = rules::digits::try_parse("1 2").unwrap();
+    assert_eq!(span(&digits.content).as_str(), "1 2");
+    let (_, digit) = digits.content.get_matched();
+    assert_eq!((span(digit).start(), span(digit).end()), (2, 3));
+    assert_eq!(digit.to_string(), "2");
+}
+
+#[test]
+fn display() {
+    let list = rules::list::try_parse("a = 1 ,b=2, c = d").unwrap();
+    assert_eq!(list.to_string(), "a = 1 ,b=2, c = d");
+    // Sequences print their items with trivia between them.
+    assert_eq!(list.content.to_string(), "a = 1 ,b=2, c = d");
+    let items: Vec<String> = list
+        .item()
+        .1
+        .iter()
+        .map(|item| item.content.to_string())
+        .collect();
+    assert_eq!(items, ["b=2", "c = d"]);
+    let spans: Vec<(usize, usize)> = list
+        .item()
+        .1
+        .iter()
+        .map(|item| span(&item.content))
+        .map(|span| (span.start(), span.end()))
+        .collect();
+    assert_eq!(spans, [(7, 10), (12, 17)]);
+}
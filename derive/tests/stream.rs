@@ -0,0 +1,75 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{stream::ReadStr, Cursor as _, Input as _, ParsableTypedNode as _, RefStr as _};
+use pest_typed_derive::TypedParser;
+use std::io::Cursor;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+level = { "INFO" | "WARN" }
+entry = { level ~ " " ~ message ~ "\n" }
+message = { (!"\n" ~ ANY)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn log(lines: usize) -> String {
+    (0..lines).map(|i| format!("INFO message {i}\n")).collect()
+}
+
+#[test]
+fn entries() {
+    let input = ReadStr::with_chunk_size(Cursor::new(log(1000)), 64);
+    let mut cursor = input.as_cursor();
+    let mut count = 0;
+    while !cursor.at_end() {
+        let (next, entry) = rules::entry::try_parse_partial(cursor).unwrap();
+        assert_eq!(
+            entry.message().span.as_str().to_text(),
+            format!("message {count}")
+        );
+        input.release(next.byte_offset());
+        cursor = next.as_position();
+        count += 1;
+    }
+    assert_eq!(count, 1000);
+    assert_eq!(input.released(), log(1000).len());
+}
+
+#[test]
+fn error() {
+    let text = log(100) + "DEBUG message\n" + &log(1);
+    let input = ReadStr::with_chunk_size(Cursor::new(text), 64);
+    let mut cursor = input.as_cursor();
+    let error = loop {
+        match rules::entry::try_parse_partial(cursor) {
+            Ok((next, _)) => {
+                input.release(next.byte_offset());
+                cursor = next.as_position();
+            }
+            Err(error) => break error,
+        }
+    };
+    // Released lines are still counted.
+    assert_eq!(input.released_lines(), 100);
+    assert_eq!(
+        format!("{error}"),
+        "
+   --> 101:1
+    |
+101 | DEBUG message
+    | ^---
+    |
+    = ^---
+      Expected [level]."
+            .trim_start_matches('\n')
+    );
+}
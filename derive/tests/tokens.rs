@@ -0,0 +1,115 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use core::ops::Range;
+use pest_typed::{
+    iterators::Pair as _,
+    token::{TokenKind, Tokens},
+    RefStr as _, TypedParser as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+column = { IDENT }
+select = { SOI ~ "SELECT" ~ column ~ ("," ~ column)* ~ ("FROM" ~ IDENT)? ~ EOI }
+"#]
+#[token_kind = "IDENT"]
+#[emit_rule_reference]
+struct Parser;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum Kind {
+    Select,
+    From,
+    Comma,
+    Ident,
+}
+
+impl TokenKind for Kind {
+    fn matches(self, literal: &str) -> bool {
+        let name = match self {
+            Self::Select => "SELECT",
+            Self::From => "FROM",
+            Self::Comma => ",",
+            Self::Ident => "IDENT",
+        };
+        name == literal
+    }
+}
+
+fn lex(source: &str) -> Vec<(Kind, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == ',' {
+            tokens.push((Kind::Comma, start..start + 1));
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek() {
+            if !c.is_alphanumeric() {
+                break;
+            }
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        let kind = match source[start..end].to_ascii_uppercase().as_str() {
+            "SELECT" => Kind::Select,
+            "FROM" => Kind::From,
+            _ => Kind::Ident,
+        };
+        tokens.push((kind, start..end));
+    }
+    tokens
+}
+
+#[test]
+fn select() {
+    let source = "select a,\n  b from t";
+    let tokens = lex(source);
+    let select: rules::select<_> = Parser::try_parse(Tokens::new(source, &tokens)).unwrap();
+
+    let (first, rest) = select.column();
+    assert_eq!(first.span.as_str().to_text(), "a");
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].span.as_str().source_range(), 12..13);
+    let table = select.IDENT().unwrap();
+    assert_eq!(table.span.start(), 5);
+    assert_eq!(table.span.as_str().source_range(), 19..20);
+
+    let columns: Vec<_> = select
+        .children()
+        .into_iter()
+        .map(|token| token.span.as_str().to_text().into_owned())
+        .collect();
+    assert_eq!(columns, ["a", "b", "t", ""]);
+}
+
+#[test]
+fn error() {
+    let source = "SELECT a,\n  FROM t";
+    let tokens = lex(source);
+    let error = Parser::try_parse::<_, rules::select<_>>(Tokens::new(source, &tokens)).unwrap_err();
+    assert_eq!(
+        format!("{error}"),
+        "
+ --> 2:3
+  |
+2 |   FROM t
+  |   ^---
+  |
+  =   ^---
+    Expected [IDENT], by select."
+            .trim_start_matches('\n')
+    );
+}
@@ -7,13 +7,19 @@
 // option. All files in the project carrying such notice may not be copied,
 // modified, or distributed except according to those terms.
 
-use pest_typed::{iterators::PairTree, ParsableTypedNode};
+use core::ops::ControlFlow;
+use pest_typed::{
+    iterators::{Pair, PairTree, PairView, PairVisitor},
+    ParsableTypedNode, RefStr,
+};
 use pest_typed_derive::TypedParser;
 
 #[allow(dead_code)]
 #[derive(TypedParser)]
 #[grammar_inline = r#"
 main = { (pre? ~ val) ~ (mid ~ pre? ~ val)* }
+expr = { term ~ (mid ~ term)* }
+term = { val | "(" ~ expr ~ ")" }
 pre  = { "-" }
 val  = { ('0'..'9')+ }
 mid  = { "+" | "-" | "*" | "/" }
@@ -74,3 +80,137 @@ fn tree() {
 "#
     );
 }
+
+fn describe(view: &PairView<&str, Rule>, depth: usize) -> String {
+    format!("{depth} {:?} {:?}", view.rule, view.span.as_str())
+}
+
+#[test]
+fn traversal() {
+    let tree = pairs::expr::try_parse("1+(2*3)").unwrap();
+    let mut pre_order = vec![];
+    tree.iterate_pre_order_views(|view, depth| {
+        pre_order.push(describe(view, depth));
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(
+        pre_order,
+        [
+            r#"0 expr "1+(2*3)""#,
+            r#"1 term "1""#,
+            r#"2 val "1""#,
+            r#"1 mid "+""#,
+            r#"1 term "(2*3)""#,
+            r#"2 expr "2*3""#,
+            r#"3 term "2""#,
+            r#"4 val "2""#,
+            r#"3 mid "*""#,
+            r#"3 term "3""#,
+            r#"4 val "3""#,
+        ]
+    );
+
+    let mut level_order = vec![];
+    tree.iterate_level_order_views(|view, depth| {
+        level_order.push(describe(view, depth));
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(
+        level_order,
+        [
+            r#"0 expr "1+(2*3)""#,
+            r#"1 term "1""#,
+            r#"1 mid "+""#,
+            r#"1 term "(2*3)""#,
+            r#"2 val "1""#,
+            r#"2 expr "2*3""#,
+            r#"3 term "2""#,
+            r#"3 mid "*""#,
+            r#"3 term "3""#,
+            r#"4 val "2""#,
+            r#"4 val "3""#,
+        ]
+    );
+
+    let mut count = 0;
+    let res = tree.iterate_pre_order_views(|view, _| {
+        count += 1;
+        match view.rule {
+            Rule::mid => Err(view.span.start()),
+            _ => Ok(()),
+        }
+    });
+    assert_eq!(res, Err(1));
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn children() {
+    let tree = pairs::expr::try_parse("1+2").unwrap();
+    let mut views = vec![];
+    tree.for_each_child_view(|view| views.push(view));
+    let rules: Vec<_> = views.iter().map(|view| view.rule).collect();
+    assert_eq!(rules, [Rule::term, Rule::mid, Rule::term]);
+    assert_eq!(views[2].span.as_str(), "2");
+    assert_eq!(
+        views,
+        tree.children()
+            .iter()
+            .map(|token| PairView {
+                rule: token.rule,
+                span: token.span,
+            })
+            .collect::<Vec<_>>()
+    );
+    assert!(tree.has_children());
+    assert!(!pairs::val::try_parse("12").unwrap().has_children());
+}
+
+#[test]
+fn children_iter() {
+    let tree = pairs::expr::try_parse("1+(2*3)").unwrap();
+    let children: Vec<_> = tree.children_iter().collect();
+    let rules: Vec<_> = children.iter().map(|child| child.rule()).collect();
+    assert_eq!(rules, [Rule::term, Rule::mid, Rule::term]);
+    let expr = children[2].children_iter().next().unwrap();
+    assert_eq!(expr.as_view().rule, Rule::expr);
+    assert_eq!(expr.span().as_str(), "2*3");
+    let spans: Vec<_> = expr
+        .children_iter()
+        .rev()
+        .map(|child| child.span().as_str())
+        .collect();
+    assert_eq!(spans, ["3", "*", "2"]);
+    assert_eq!(
+        format!("{:?}", children[1]),
+        format!("{:?}", children[1].as_view())
+    );
+}
+
+/// Count pairs by visiting them with their own types.
+struct Counter(usize);
+impl<'t, S, R: pest_typed::RuleType> PairVisitor<'t, S, R> for Counter {
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        self.0 += 1;
+        pair.visit_children(self)
+    }
+}
+
+#[test]
+fn visitor() {
+    let tree = pairs::expr::try_parse("(1+(2*3))/4").unwrap();
+    let mut counter = Counter(0);
+    let _ = counter.visit(&tree);
+    let mut count = 0;
+    tree.iterate_pre_order_views(|_, _| {
+        count += 1;
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(counter.0, count);
+}
@@ -0,0 +1,90 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{
+    flat_tree::TreeCursor,
+    iterators::{Pair, PairTree},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+ident      = @{ ASCII_ALPHA+ }
+call       =  { ident ~ "(" ~ ")" }
+stmt       =  { call ~ ";" }
+function   =  { "fn" ~ ident ~ "{" ~ stmt* ~ "}" }
+file       =  { SOI ~ function* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = "fn a { b(); }\nfn c { d(); e(); }";
+
+#[test]
+fn navigation() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let mut cursor = file.cursor();
+    assert_eq!(cursor.rule(), Rule::file);
+    assert_eq!(cursor.depth(), 0);
+    assert!(!cursor.goto_parent());
+    assert!(!cursor.goto_next_sibling());
+
+    assert!(cursor.goto_first_child());
+    assert_eq!((cursor.rule(), cursor.depth()), (Rule::function, 1));
+    assert_eq!(cursor.span().as_str(), "fn a { b(); }");
+    assert!(cursor.goto_next_sibling());
+    assert_eq!(cursor.span().as_str(), "fn c { d(); e(); }");
+    assert!(cursor.goto_next_sibling());
+    assert_eq!(cursor.rule(), Rule::EOI);
+    assert!(!cursor.goto_next_sibling());
+    assert!(!cursor.goto_first_child());
+    assert!(cursor.goto_parent());
+    assert_eq!(cursor.rule(), Rule::file);
+
+    cursor.reset();
+    assert_eq!(cursor.index(), 0);
+}
+
+#[test]
+fn enclosing() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let mut cursor = file.cursor();
+    // Find identifier `e` in pre-order.
+    let mut found = false;
+    while !found {
+        if cursor.rule() == Rule::ident && cursor.span().as_str() == "e" {
+            found = true;
+        } else if !cursor.goto_first_child() {
+            while !cursor.goto_next_sibling() {
+                assert!(cursor.goto_parent());
+            }
+        }
+    }
+    assert_eq!(cursor.depth(), 4);
+    while cursor.rule() != Rule::function {
+        assert!(cursor.goto_parent());
+    }
+    assert_eq!(cursor.span().start(), INPUT.find("fn c").unwrap());
+    assert!(cursor.goto_first_child());
+    assert_eq!(cursor.span().as_str(), "c");
+}
+
+#[test]
+fn token() {
+    let file = rules::file::try_parse(INPUT).unwrap();
+    let token = file.as_token();
+    let mut from_token = TreeCursor::from_token(&token);
+    let mut from_pair = file.cursor();
+    assert_eq!(from_token.tree(), from_pair.tree());
+    assert!(from_token.goto_first_child() && from_pair.goto_first_child());
+    assert_eq!(from_token.span(), from_pair.span());
+}
@@ -7,7 +7,9 @@
 // option. All files in the project carrying such notice may not be copied,
 // modified, or distributed except according to those terms.
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct Config {
     pub emit_rule_reference: bool,
     pub emit_tagged_node_reference: bool,
@@ -17,6 +19,10 @@ pub(crate) struct Config {
     pub simulate_pair_api: bool,
     pub box_only_if_needed: bool,
     pub no_warnings: bool,
+    pub fast_trivia: bool,
+    pub byte_input: bool,
+    /// Names of rules that are only checked when referenced.
+    pub lazy_rules: BTreeSet<String>,
 }
 impl Default for Config {
     fn default() -> Self {
@@ -29,6 +35,9 @@ impl Default for Config {
             simulate_pair_api: false,
             box_only_if_needed: false,
             no_warnings: false,
+            fast_trivia: false,
+            byte_input: false,
+            lazy_rules: BTreeSet::new(),
         }
     }
 }
@@ -38,6 +38,8 @@ pub(crate) struct DocComment {
 /// grammar_doc = "This is a grammar doc"
 /// line_docs = { "foo": "line doc 1\nline doc 2", "bar": "line doc 3" }
 /// ```
+// Kept in the same shape as pest.
+#[allow(clippy::collapsible_match)]
 pub(crate) fn consume(pairs: Pairs<'_, Rule>) -> DocComment {
     let mut grammar_doc = String::new();
 
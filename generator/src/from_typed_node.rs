@@ -0,0 +1,262 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::types::{option_type, result_type};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned as _, Attribute, Data, DeriveInput, Error, Expr, Fields, Path,
+    PathArguments,
+};
+
+/// Arguments in `#[from(...)]`.
+#[derive(Default)]
+struct Args {
+    /// Source node type of the container, or the getter of a field or a variant.
+    rule: Option<Path>,
+    /// Getter of a tag.
+    tag: Option<Ident>,
+    /// Fallible conversion from text.
+    with: Option<Expr>,
+    /// Infallible conversion from text.
+    map: Option<Expr>,
+}
+
+impl Args {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut args = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("from")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rule") {
+                    args.rule = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("tag") {
+                    args.tag = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("with") {
+                    args.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("map") {
+                    args.map = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `rule`, `tag`, `with` or `map`"));
+                }
+                Ok(())
+            })?;
+        }
+        if args.with.is_some() && args.map.is_some() {
+            return Err(Error::new(
+                attrs[0].span(),
+                "`with` and `map` can't be used together",
+            ));
+        }
+        Ok(args)
+    }
+    /// Getter of the child node, which is a rule or a tag.
+    fn getter(&self, span: &impl ToTokens) -> syn::Result<Option<Ident>> {
+        match (&self.rule, &self.tag) {
+            (Some(_), Some(_)) => Err(Error::new_spanned(
+                span,
+                "`rule` and `tag` can't be used together",
+            )),
+            (Some(rule), None) => match rule.get_ident() {
+                Some(rule) => Ok(Some(rule.clone())),
+                None => Err(Error::new_spanned(rule, "expected a rule name")),
+            },
+            (None, Some(tag)) => Ok(Some(tag.clone())),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// Expression that converts a field from `node`.
+fn field(node: &TokenStream, args: &Args, getter: Option<&Ident>) -> TokenStream {
+    let convert = match (&args.with, &args.map) {
+        (Some(with), _) => quote! { |node| ::pest_typed::convert::with(node, #with) },
+        (_, Some(map)) => quote! { |node| ::pest_typed::convert::map(node, #map) },
+        (None, None) => {
+            quote! { |node| ::pest_typed::convert::FromTypedNode::from_typed_node(node) }
+        }
+    };
+    let nodes = match getter {
+        Some(getter) => quote! { #node.#getter() },
+        None => node.clone(),
+    };
+    quote! {
+        ::pest_typed::convert::ConvertNodes::convert_nodes(#nodes, #convert)?
+    }
+}
+
+/// Expression that constructs `path` with `fields` converted from `node`.
+///
+/// Hooks on a variant apply to its only field.
+fn construct(
+    path: TokenStream,
+    node: &TokenStream,
+    fields: &Fields,
+    hooks: Option<&Args>,
+) -> syn::Result<TokenStream> {
+    if hooks.is_some() && fields.len() != 1 {
+        return Err(Error::new_spanned(
+            fields,
+            "`with` and `map` on a variant require exactly one field",
+        ));
+    }
+    let mut values = Vec::with_capacity(fields.len());
+    for field_ in fields {
+        let mut args = Args::parse(&field_.attrs)?;
+        if let Some(hooks) = hooks {
+            if args.with.is_none() && args.map.is_none() {
+                args.with = hooks.with.clone();
+                args.map = hooks.map.clone();
+            }
+        }
+        let getter = args.getter(field_)?;
+        let value = field(node, &args, getter.as_ref());
+        values.push(match &field_.ident {
+            Some(ident) => quote! { #ident: #value },
+            None => value,
+        });
+    }
+    Ok(match fields {
+        Fields::Named(_) => quote! { #path { #(#values,)* } },
+        Fields::Unnamed(_) => quote! { #path ( #(#values,)* ) },
+        Fields::Unit => path,
+    })
+}
+
+fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let args = Args::parse(&input.attrs)?;
+    let mut rule = match args.rule {
+        Some(rule) => rule,
+        None => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "expected `#[from(rule = path::to::rule)]`",
+            ))
+        }
+    };
+    if let Some(last) = rule.segments.last_mut() {
+        last.arguments = PathArguments::AngleBracketed(parse_quote!(<__S>));
+    }
+
+    let node = quote! { node };
+    let result = result_type();
+    let option = option_type();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let value = construct(quote! { Self }, &node, &data.fields, None)?;
+            quote! { #result::Ok(#value) }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::with_capacity(data.variants.len());
+            let mut fallback = None;
+            for variant in &data.variants {
+                if let Some(fallback) = &fallback {
+                    return Err(Error::new_spanned(
+                        fallback,
+                        "variant without `rule` or `tag` should be the last one",
+                    ));
+                }
+                let args = Args::parse(&variant.attrs)?;
+                let hooks = (args.with.is_some() || args.map.is_some()).then_some(&args);
+                let ident = &variant.ident;
+                let path = quote! { Self::#ident };
+                match args.getter(variant)? {
+                    Some(getter) => {
+                        let child = quote! { child };
+                        let value = construct(path, &child, &variant.fields, hooks)?;
+                        arms.push(quote! {
+                            if let #option::Some(child) =
+                                ::pest_typed::convert::OptionalNode::into_option(node.#getter())
+                            {
+                                let __span = ::pest_typed::convert::span_of(child);
+                                return #result::Ok(#value);
+                            }
+                        });
+                    }
+                    None => {
+                        let value = construct(path, &node, &variant.fields, hooks)?;
+                        arms.push(quote! { return #result::Ok(#value); });
+                        fallback = Some(ident);
+                    }
+                }
+            }
+            let rest = match fallback {
+                Some(_) => quote! {},
+                None => {
+                    let message = format!("No variant of `{}` matches.", input.ident);
+                    quote! {
+                        #result::Err(::pest_typed::convert::ConvertError::new(__span, #message))
+                    }
+                }
+            };
+            quote! { #(#arms)* #rest }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "unions are not supported",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let result = result_type();
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(__S: ::pest_typed::RefStr));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::pest_typed::convert::FromTypedNode<__S, #rule> for #name #ty_generics #where_clause {
+            #[allow(unused_variables, unreachable_code)]
+            fn from_typed_node(
+                node: &#rule,
+            ) -> #result<Self, ::pest_typed::convert::ConvertError<__S>> {
+                let __span = ::pest_typed::convert::span_of(node);
+                #body
+            }
+        }
+    })
+}
+
+/// Implement `pest_typed::convert::FromTypedNode` for a struct or an enum.
+///
+/// See `pest_typed_derive::FromTypedNode` for attributes.
+pub fn derive_from_typed_node(input: TokenStream) -> TokenStream {
+    match syn::parse2(input).and_then(derive) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn missing_rule() {
+        let input = quote! {
+            struct A {}
+        };
+        let tokens = derive_from_typed_node(input).to_string();
+        assert!(tokens.contains("expected `#[from(rule = path::to::rule)]`"));
+    }
+    #[test]
+    fn fallback_not_last() {
+        let input = quote! {
+            #[from(rule = rules::a)]
+            enum A {
+                B,
+                #[from(rule = c)]
+                C,
+            }
+        };
+        let tokens = derive_from_typed_node(input).to_string();
+        assert!(tokens.contains("variant without `rule` or `tag` should be the last one"));
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
     types::{option_type, vec_type},
 };
 use pest::unicode::unicode_property_names;
+use pest_meta::ast::RuleType;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::collections::{btree_map, BTreeMap, BTreeSet};
@@ -22,6 +23,7 @@ pub(crate) use traits::Generate;
 mod optimized_rule;
 mod rule;
 mod traits;
+mod trivia;
 
 pub(crate) fn pest_typed() -> TokenStream {
     quote! {::pest_typed}
@@ -87,7 +89,7 @@ enum Edge {
 enum Node<'g> {
     /// - Type: `&#ident`
     /// - Path: `.content.deref()`
-    Rule(&'g str, bool, bool),
+    Rule(&'g str, bool),
     /// - Type: `&#ident`
     /// - Path: `.content`
     #[cfg(feature = "grammar-extras")]
@@ -104,7 +106,7 @@ enum Node<'g> {
     /// - Path: `._#index().and_then(|res| Some(#inner)) #flat`
     ChoiceI(usize, bool, Box<Self>),
     /// - Type: `#opt::<#inner>`
-    /// - Path: `.as_ref().and_then(|res| Some(#inner)) #flat`
+    /// - Path: `.content.as_ref().and_then(|res| Some(#inner)) #flat`
     Optional(bool, Box<Self>),
     // Type wrapped by Vec.
     /// - Type: `#vec::<#inner>`
@@ -117,8 +119,8 @@ enum Node<'g> {
 }
 
 impl<'g> Node<'g> {
-    const fn from_rule(value: &'g str, has_lifetime: bool, has_skip: bool) -> Self {
-        Self::Rule(value, has_lifetime, has_skip)
+    const fn from_rule(value: &'g str, has_skip: bool) -> Self {
+        Self::Rule(value, has_skip)
     }
     #[cfg(feature = "grammar-extras")]
     const fn from_tag(rule_name: &'g str, tag_name: &'g str, tokens: Vec<TokenStream>) -> Self {
@@ -126,7 +128,7 @@ impl<'g> Node<'g> {
     }
     fn flattenable(&self) -> bool {
         match self {
-            Node::Rule(_, _, _) => false,
+            Node::Rule(_, _) => false,
             #[cfg(feature = "grammar-extras")]
             Node::Tag(_, _, _) => false,
             Node::Content(inner) | Node::SequenceI(_, inner) => inner.flattenable(),
@@ -187,20 +189,25 @@ impl<'g> Node<'g> {
         let vec = vec_type();
         let rules_mod = rules_mod();
         match self {
-            Node::Rule(t, has_life_time, has_skip) => {
+            Node::Rule(t, has_skip) => {
                 let skip = match config.atomicity {
                     Some(true) => quote! {0},
                     Some(false) => quote! {1},
                     None => quote! {INHERITED},
                 };
-                let generics = match (has_life_time, has_skip) {
-                    (true, true) => quote! {::<S, #skip>},
-                    (true, false) => quote! {::<S>},
-                    (false, true) => quote! {::<#skip>},
-                    (false, false) => quote! {},
+                let generics_mod = generics();
+                let generics = match has_skip {
+                    true => quote! {::<S, #skip>},
+                    false => quote! {::<S>},
                 };
-                let t = ident(t);
-                (quote! {res}, quote! {&'s #root::#rules_mod::#t #generics})
+                let id = ident(t);
+                let ty = quote! {#root::#rules_mod::#id #generics};
+                let ty = if config.lazy.contains(t) {
+                    quote! {#root::#generics_mod::Lazy::<S, #ty>}
+                } else {
+                    ty
+                };
+                (quote! {res}, quote! {&'s #ty})
             }
             #[cfg(feature = "grammar-extras")]
             Node::Tag(_rule, _tag, inner) => {
@@ -224,7 +231,7 @@ impl<'g> Node<'g> {
                 let (pa, ty) = inner.expand(root, config);
                 let flat = flat(flatten);
                 (
-                    quote! {{let res = res.as_ref().map(|res| #pa) #flat; res}},
+                    quote! {{let res = res.content.as_ref().map(|res| #pa) #flat; res}},
                     opt(flatten, ty),
                 )
             }
@@ -270,8 +277,8 @@ impl<'g> Getter<'g> {
             getters: BTreeMap::new(),
         }
     }
-    fn from_rule(name: &'g str, id: &'g str, has_life_time: bool, has_skip: bool) -> Self {
-        let res = BTreeMap::from([(name, Node::from_rule(id, has_life_time, has_skip))]);
+    fn from_rule(name: &'g str, id: &'g str, has_skip: bool) -> Self {
+        let res = BTreeMap::from([(name, Node::from_rule(id, has_skip))]);
         Self { getters: res }
     }
     #[cfg(feature = "grammar-extras")]
@@ -401,12 +408,15 @@ struct RuleConfig<'g> {
     pub atomicity: Option<bool>,
     pub rule_id: Ident,
     pub boxed: bool,
+    pub never_failed: bool,
     #[allow(dead_code)]
     pub rule_name: &'g str,
     pub rule_desc: String,
     pub rule_doc: Option<&'g str>,
     pub defined: &'g BTreeSet<&'g str>,
-    pub builtins_without_lifetime: &'g BTreeSet<&'g str>,
+    pub lazy: &'g BTreeSet<&'g str>,
+    /// Non-silent rules that may be among descendants.
+    pub descendants: &'g BTreeSet<&'g str>,
 }
 impl<'g> RuleConfig<'g> {
     fn get_doc<'s>(&'s self) -> impl Iterator<Item = &'s str>
@@ -420,11 +430,76 @@ impl<'g> RuleConfig<'g> {
     }
 }
 
+/// An item in the sequence at the top of a rule.
+struct Item {
+    /// Type of the item.
+    ty: TokenStream,
+    /// Name of the parameter that takes the item, or [`None`] for string literals, which fill themselves in.
+    param: Option<Ident>,
+}
+
+/// Parameters of the constructor of a rule struct.
+enum Constructor {
+    /// Take nothing, as the content is a string literal.
+    Literal,
+    /// Take the content.
+    Content,
+    /// Take items in the sequence at the top, except string literals.
+    Sequence(Vec<Item>),
+}
+
+/// Constructor of a rule struct, whose spans are placeholders until the root is built.
+fn constructor(
+    inner_type: &TokenStream,
+    emission: Emission,
+    constructor: &Constructor,
+) -> TokenStream {
+    let pest_typed = pest_typed();
+    let from_content = quote! {<Self as #pest_typed::builder::BuildRule<S>>::from_content};
+    match (emission, constructor) {
+        (Emission::Span, _) => quote! {
+            /// Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span).
+            pub fn from_children(span: #pest_typed::Span<S>) -> Self {
+                #from_content(span)
+            }
+        },
+        (_, Constructor::Literal) => quote! {
+            /// Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build).
+            pub fn from_children() -> Self {
+                #from_content(::core::default::Default::default())
+            }
+        },
+        (_, Constructor::Content) => quote! {
+            /// Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build).
+            pub fn from_children(content: #inner_type) -> Self {
+                #from_content(content)
+            }
+        },
+        (_, Constructor::Sequence(items)) => {
+            let params = items
+                .iter()
+                .filter_map(|Item { ty, param }| param.as_ref().map(|param| quote! {#param: #ty}));
+            let args = items.iter().map(|Item { ty, param }| match param {
+                Some(param) => quote! {#param},
+                None => quote! {<#ty as ::core::default::Default>::default()},
+            });
+            quote! {
+                /// Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build).
+                #[allow(clippy::too_many_arguments)]
+                pub fn from_children(#(#params),*) -> Self {
+                    #from_content(::core::convert::From::from((#(#args, )*)))
+                }
+            }
+        }
+    }
+}
+
 fn rule<'g>(
     rule_config: &RuleConfig<'g>,
     type_name: TokenStream,
     getters: &Getter<'g>,
     emission: Emission,
+    constructor: &Constructor,
 ) -> TokenStream {
     let root = quote! {super::super};
     let _bool = _bool();
@@ -438,6 +513,7 @@ fn rule<'g>(
         getter_impl: TokenStream,
         inner_type: TokenStream,
         emission: Emission,
+        constructor: TokenStream,
     ) -> TokenStream {
         let root = quote! {super::super};
         let pest_typed = pest_typed();
@@ -451,14 +527,28 @@ fn rule<'g>(
         let ignore = ignore(&root);
         let boxed = rule_config.boxed;
         let usize = _usize();
+        let never_failed = rule_config.never_failed.then(|| {
+            quote! {
+                #pest_typed::impl_never_failed!(#name, #root::Rule, #ignore, #atomicity);
+            }
+        });
+        let descendants = rule_config.descendants.iter().map(|name| ident(name));
         quote! {
-            #pest_typed::rule!(pub #name, #(#docs)*, #root::Rule, #root::Rule::#name, #inner_type, #ignore, #atomicity, #emission, #boxed);
+            #pest_typed::rule!(pub #name, #(#docs)*, #root::Rule, #root::Rule::#name, #inner_type, #ignore, #atomicity, #emission, #boxed, [#(#root::Rule::#descendants),*]);
+            #never_failed
             impl<S, const INHERITED: #usize> #name<S, INHERITED> {
                 #getter_impl
             }
+            #[allow(non_snake_case)]
+            #[allow(clippy::type_complexity)]
+            #[allow(clippy::use_self)]
+            impl<S: #pest_typed::RefStr, const INHERITED: #usize> #name<S, INHERITED> {
+                #constructor
+            }
         }
     }
-    create(rule_config, getters, type_name, emission)
+    let constructor = self::constructor(&type_name, emission, constructor);
+    create(rule_config, getters, type_name, emission, constructor)
 }
 
 struct Output<'g> {
@@ -568,6 +658,71 @@ impl<'g> Output<'g> {
         self.wrappers.push(wrapper);
         quote! {#wrapper_mod::#s}
     }
+    /// Insert a character set wrapper to corresponding module.
+    /// Return the module path relative to module root.
+    fn insert_char_set_wrapper(
+        &mut self,
+        ranges: &[(char, char)],
+        properties: &[&str],
+    ) -> TokenStream {
+        let s = ident(&format!("w_{}", self.wrapper_counter));
+        self.wrapper_counter += 1;
+        let doc = format!(
+            "A wrapper for character set `{}`.",
+            ranges
+                .iter()
+                .map(|(min, max)| format!("{:?}..{:?}", min, max))
+                .chain(properties.iter().map(|property| property.to_string()))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        );
+        let wrapper_mod = constant_wrappers();
+        let pest_typed = pest_typed();
+        let ranges = ranges.iter().map(|(min, max)| quote! {(#min, #max)});
+        let properties = if properties.is_empty() {
+            quote! {}
+        } else {
+            let properties = properties.iter().map(|property| ident(property));
+            quote! {
+                const PROPERTIES: &'static [fn(char) -> bool] = &[ #(#pest_typed::unicode::#properties),* ];
+            }
+        };
+        let wrapper = quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #[derive(Clone, Hash, PartialEq, Eq)]
+            pub struct #s;
+            impl #pest_typed::CharSetWrapper for #s {
+                const RANGES: &'static [(char, char)] = &[ #(#ranges),* ];
+                #properties
+            }
+        };
+        self.wrappers.push(wrapper);
+        quote! {#wrapper_mod::#s}
+    }
+    /// Insert a trivia wrapper to corresponding module.
+    /// Return the module path relative to module root.
+    fn insert_trivia_wrapper(
+        &mut self,
+        (trivia, whitespace): &(Vec<trivia::Trivia>, usize),
+    ) -> TokenStream {
+        let s = ident(&format!("w_{}", self.wrapper_counter));
+        self.wrapper_counter += 1;
+        let wrapper_mod = constant_wrappers();
+        let pest_typed = pest_typed();
+        let wrapper = quote! {
+            #[doc = "A wrapper for trivia in `WHITESPACE` and `COMMENT`."]
+            #[allow(non_camel_case_types)]
+            #[derive(Clone, Hash, PartialEq, Eq)]
+            pub struct #s;
+            impl #pest_typed::TriviaWrapper for #s {
+                const TRIVIA: &'static [#pest_typed::predefined_node::Trivia] = &[ #(#trivia),* ];
+                const WHITESPACE: ::core::primitive::usize = #whitespace;
+            }
+        };
+        self.wrappers.push(wrapper);
+        quote! {#wrapper_mod::#s}
+    }
     /// (nodes, wrappers)
     fn collect(&self) -> TokenStream {
         let content = &self.content;
@@ -663,6 +818,116 @@ fn collect_used_rules<'s, R: Generate>(rules: &'s [R], implicit: Implicit) -> BT
     res
 }
 
+/// Collect trivia shapes of `WHITESPACE` and `COMMENT` in the order they are tried,
+/// and the number of those from `WHITESPACE`.
+///
+/// Returns [`None`] if neither is defined, or either is not a silent rule made up of known shapes,
+/// as skipping them directly would lose their tokens.
+fn collect_trivia<R: Generate>(rules: &[R]) -> Option<(Vec<trivia::Trivia>, usize)> {
+    let mut res = Vec::new();
+    let mut whitespace = 0;
+    for name in ["WHITESPACE", "COMMENT"] {
+        if let Some(rule) = rules.iter().find(|rule| rule.name() == name) {
+            if rule.ty() != RuleType::Silent {
+                return None;
+            }
+            res.extend(traits::as_trivia::<R>(rule.expr())?);
+        }
+        if name == "WHITESPACE" {
+            whitespace = res.len();
+        }
+    }
+    (!res.is_empty()).then_some((res, whitespace))
+}
+
+/// Collect rules marked as lazy.
+fn collect_lazy<'g, R: Generate>(
+    rules: &'g [R],
+    lazy_rules: &BTreeSet<String>,
+) -> BTreeSet<&'g str> {
+    let res: BTreeSet<&str> = rules
+        .iter()
+        .filter(|rule| lazy_rules.contains(rule.name()))
+        .map(|rule| {
+            if rule.ty() == RuleType::Silent {
+                panic!("Silent rule `{}` can not be lazy.", rule.name());
+            }
+            rule.name()
+        })
+        .collect();
+    if let Some(name) = lazy_rules.iter().find(|name| !res.contains(name.as_str())) {
+        panic!("Lazy rule `{}` is not defined.", name);
+    }
+    res
+}
+
+/// Collect rules that always match, which can be parsed without failure.
+fn collect_never_failed<R: Generate>(rules: &[R]) -> BTreeSet<&str> {
+    let mut res = BTreeSet::new();
+    loop {
+        let old_len = res.len();
+        for rule in rules {
+            if !res.contains(rule.name()) && R::never_fails(rule.expr(), &res) {
+                res.insert(rule.name());
+            }
+        }
+        if res.len() == old_len {
+            return res;
+        }
+    }
+}
+
+/// Collect non-silent rules that may be among descendants of each rule.
+///
+/// Atomic rules have no descendants, and children of lazy rules are not searched.
+fn collect_descendants<'g, R: Generate>(
+    rules: &'g [R],
+    implicit: Implicit,
+    lazy: &BTreeSet<&'g str>,
+) -> BTreeMap<&'g str, BTreeSet<&'g str>> {
+    let by_name: BTreeMap<&str, &R> = rules.iter().map(|rule| (rule.name(), rule)).collect();
+    let mut res = BTreeMap::new();
+    for rule in rules {
+        let mut descendants = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![rule];
+        while let Some(cur) = stack.pop() {
+            if cur.ty() == RuleType::Atomic {
+                continue;
+            }
+            let mut used = BTreeSet::new();
+            R::collect_used_rule(cur, implicit, &mut used);
+            if cur.ty() == RuleType::NonAtomic {
+                if implicit.comment {
+                    used.insert("COMMENT");
+                }
+                if implicit.whitespace {
+                    used.insert("WHITESPACE");
+                }
+            }
+            for name in used {
+                if name == "EOI" {
+                    descendants.insert(name);
+                }
+                let Some(&child) = by_name.get(name) else {
+                    continue;
+                };
+                if lazy.contains(name) {
+                    continue;
+                }
+                if child.ty() != RuleType::Silent {
+                    descendants.insert(name);
+                }
+                if visited.insert(name) {
+                    stack.push(child);
+                }
+            }
+        }
+        res.insert(rule.name(), descendants);
+    }
+    res
+}
+
 fn collect_reachability<'g, R: Generate>(
     rules: &'g [R],
     implicit: Implicit,
@@ -701,41 +966,84 @@ fn collect_reachability<'g, R: Generate>(
 pub(crate) fn generate_typed_pair_from_rule<R: Generate>(
     rules: &[R],
     doc: &DocComment,
-    config: Config,
+    config: &Config,
 ) -> TokenStream {
     let pest_typed = pest_typed();
 
     let defined_rules: BTreeSet<&str> = rules.iter().map(|rule| rule.name()).collect();
 
+    let lazy_rules = collect_lazy(rules, &config.lazy_rules);
+
     let implicit = Implicit::from(rules);
 
     let referenced_rules = collect_used_rules(rules, implicit);
 
-    let (builtin, mut builtins_without_lifetime) =
-        generate_builtin(&defined_rules, &referenced_rules);
+    let builtin = generate_builtin(&defined_rules, &referenced_rules);
 
-    let unicode_rule = generate_unicode(
-        &defined_rules,
-        &referenced_rules,
-        &mut builtins_without_lifetime,
-    );
+    let unicode_rule = generate_unicode(&defined_rules, &referenced_rules);
 
-    let not_boxed = collect_reachability(rules, implicit)
+    let not_boxed: BTreeSet<&str> = collect_reachability(rules, implicit)
         .keys()
         .cloned()
         .collect();
 
-    let mut graph = R::generate_graph(
-        rules,
-        &defined_rules,
-        &not_boxed,
-        &builtins_without_lifetime,
-        config,
-        doc,
-    );
+    let descendants = collect_descendants(rules, implicit, &lazy_rules);
+
+    let never_failed = collect_never_failed(rules);
+
+    let mut graph = Output::new();
+    for rule in rules.iter() {
+        let rule_name = rule.name();
+        let (atomicity, emission) = match rule.ty() {
+            RuleType::Normal => (None, Emission::Both),
+            RuleType::Silent => (None, Emission::Expression),
+            RuleType::NonAtomic => (Some(false), Emission::Both),
+            RuleType::CompoundAtomic => (Some(true), Emission::Both),
+            RuleType::Atomic => (Some(true), Emission::Span),
+        };
+        let atomicity_doc = match atomicity {
+            Some(true) => "Atomic rule.",
+            Some(false) => "Non-atomic rule.",
+            None => "Normal rule.",
+        };
+        let rule_desc = format!(
+            "Corresponds to expression: `{}`. {}",
+            rule.expr(),
+            atomicity_doc
+        );
+        let boxed = !config.box_only_if_needed || !not_boxed.contains(rule_name);
+        let rule_doc = doc.line_docs.get(rule_name).map(|s| s.as_str());
+        let rule_config = RuleConfig {
+            atomicity,
+            boxed,
+            never_failed: never_failed.contains(rule_name),
+            rule_id: ident(rule_name),
+            rule_name,
+            rule_desc,
+            rule_doc,
+            defined: &defined_rules,
+            lazy: &lazy_rules,
+            descendants: &descendants[rule_name],
+        };
+        R::generate_graph_node(
+            rule.expr(),
+            &rule_config,
+            &mut graph,
+            true,
+            emission,
+            config,
+            &quote! {super::super},
+        );
+    }
 
     graph.insert_builtin(quote! {#builtin});
 
+    let trivia = if config.fast_trivia {
+        collect_trivia(rules).map(|trivia| graph.insert_trivia_wrapper(&trivia))
+    } else {
+        None
+    };
+
     let mods = graph.collect();
     let unicode = unicode_mod();
     let generics = {
@@ -805,26 +1113,32 @@ pub(crate) fn generate_typed_pair_from_rule<R: Generate>(
 
         let has_white_space = defined_rules.contains("WHITESPACE");
         let has_comment = defined_rules.contains("COMMENT");
-        let skip = match (has_white_space, has_comment) {
-            (true, true) => quote! {
+        let skip = match (trivia, has_white_space, has_comment) {
+            (Some(wrapper), _, _) => quote! {
+                predefined_node::SkipTrivia<S, #root::#wrapper>
+            },
+            (None, true, true) => quote! {
                 predefined_node::AtomicRepeat<
+                    S,
                     #pest_typed::choices::Choice2<
                         #root::#rules_mod::WHITESPACE<S, 0>,
                         #root::#rules_mod::COMMENT<S, 0>,
                     >,
                 >
             },
-            (true, false) => quote! {
+            (None, true, false) => quote! {
                 predefined_node::AtomicRepeat<
+                    S,
                     #root::#rules_mod::WHITESPACE<S, 0>,
                 >
             },
-            (false, true) => quote! {
+            (None, false, true) => quote! {
                 predefined_node::AtomicRepeat<
+                    S,
                     #root::#rules_mod::COMMENT<S, 0>,
                 >
             },
-            (false, false) => quote! {
+            (None, false, false) => quote! {
                 predefined_node::Empty<S>
             },
         };
@@ -835,21 +1149,21 @@ pub(crate) fn generate_typed_pair_from_rule<R: Generate>(
                 use #pest_typed::predefined_node;
                 /// Skipped content.
                 pub type Skipped<S> = #skip;
-                pub use predefined_node::{CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, Skip, Str};
+                pub use predefined_node::{CharRange, CharSet, Insens, Lazy, Negative, Optional, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, Skip, Str};
                 #(#seq)*
                 #(#chs)*
                 /// Repeat arbitrary times.
-                pub type Rep<T, S, const SKIP: #usize> = predefined_node::Rep<T, Skipped<S>, SKIP>;
+                pub type Rep<T, S, const SKIP: #usize> = predefined_node::Rep<S, T, Skipped<S>, SKIP>;
                 /// Repeat at least once.
-                pub type RepOnce<T, S, const SKIP: #usize> = predefined_node::RepOnce<T, Skipped<S>, SKIP>;
+                pub type RepOnce<T, S, const SKIP: #usize> = predefined_node::RepOnce<S, T, Skipped<S>, SKIP>;
                 /// Repeat at least `MIN` times.
-                pub type RepMin<T, S, const SKIP: #usize, const MIN: #usize> = predefined_node::RepMin<T, Skipped<S>, SKIP, MIN>;
+                pub type RepMin<T, S, const SKIP: #usize, const MIN: #usize> = predefined_node::RepMin<S, T, Skipped<S>, SKIP, MIN>;
                 /// Repeat at most `MAX` times.
-                pub type RepMax<T, S, const SKIP: #usize, const MAX: #usize> = predefined_node::RepMax<T, Skipped<S>, SKIP, MAX>;
+                pub type RepMax<T, S, const SKIP: #usize, const MAX: #usize> = predefined_node::RepMax<S, T, Skipped<S>, SKIP, MAX>;
                 /// Repeat between `MIN` and `MAX` times.
-                pub type RepMinMax<T, S, const SKIP: #usize, const MIN: #usize, const MAX: #usize> = predefined_node::RepMinMax<T, Skipped<S>, SKIP, MIN, MAX>;
+                pub type RepMinMax<T, S, const SKIP: #usize, const MIN: #usize, const MAX: #usize> = predefined_node::RepMinMax<S, T, Skipped<S>, SKIP, MIN, MAX>;
                 /// Repeat exactly `TIMES` times.
-                pub type RepExact<T, S, const SKIP: #usize, const TIMES: #usize> = predefined_node::RepExact<T, Skipped<S>, SKIP, TIMES>;
+                pub type RepExact<T, S, const SKIP: #usize, const TIMES: #usize> = predefined_node::RepExact<S, T, Skipped<S>, SKIP, TIMES>;
             }
         }
     };
@@ -865,6 +1179,25 @@ pub(crate) fn generate_typed_pair_from_rule<R: Generate>(
             pub use #rules_mod as #pairs_mod;
         }
     };
+    let bytes = config.byte_input.then(|| {
+        let rules_mod = rules_mod();
+        let usize = _usize();
+        let aliases = rules.iter().map(|rule| {
+            let name = ident(rule.name());
+            let doc = format!("Rule [`{}`](super::{}::{}) on bytes.", rule.name(), rules_mod, rule.name());
+            quote! {
+                #[doc = #doc]
+                #[allow(non_camel_case_types)]
+                pub type #name<'i, const INHERITED: #usize = 1> = super::#rules_mod::#name<&'i [::core::primitive::u8], INHERITED>;
+            }
+        });
+        quote! {
+            #[doc = "Aliases of rule structs for byte input."]
+            pub mod bytes {
+                #(#aliases)*
+            }
+        }
+    });
     let res = quote! {
         #[doc = "Unicode rules."]
         pub mod #unicode {
@@ -873,15 +1206,12 @@ pub(crate) fn generate_typed_pair_from_rule<R: Generate>(
         #mods
         #generics
         #pairs
+        #bytes
     };
     res
 }
 
-fn generate_unicode(
-    rule_names: &BTreeSet<&str>,
-    referenced: &BTreeSet<&str>,
-    without_lifetime: &mut BTreeSet<&'static str>,
-) -> TokenStream {
+fn generate_unicode(rule_names: &BTreeSet<&str>, referenced: &BTreeSet<&str>) -> TokenStream {
     let mut results = vec![];
     let pest_unicode = pest_unicode();
 
@@ -892,7 +1222,6 @@ fn generate_unicode(
         // insert manually for #property substitution
 
         if !rule_names.contains(property) && referenced.contains(property) {
-            without_lifetime.insert(property);
             used_unicode.insert(property_ident);
         }
     }
@@ -908,10 +1237,7 @@ fn generate_unicode(
     }
 }
 
-fn generate_builtin(
-    defined: &BTreeSet<&str>,
-    referenced: &BTreeSet<&str>,
-) -> (TokenStream, BTreeSet<&'static str>) {
+fn generate_builtin(defined: &BTreeSet<&str>, referenced: &BTreeSet<&str>) -> TokenStream {
     let root = quote! {super::super};
     let pest_typed = pest_typed();
     let unicode = unicode_mod();
@@ -919,19 +1245,7 @@ fn generate_builtin(
         #[allow(unused_imports)]
         use #root::#unicode::*;
     }];
-    let mut builtins_without_lifetime = BTreeSet::new();
     macro_rules! insert_builtin {
-        ($name:expr, $def:path) => {
-            if !defined.contains($name) && referenced.contains($name) {
-                let id = ident($name);
-                builtins_without_lifetime.insert($name);
-                results.push(quote! {
-                    pub use #pest_typed::predefined_node::$def as #id;
-                });
-            }
-        };
-    }
-    macro_rules! insert_builtin_with_lifetime {
         ($name:expr, $def:path) => {
             if !defined.contains($name) && referenced.contains($name) {
                 let id = ident($name);
@@ -948,10 +1262,10 @@ fn generate_builtin(
 
     insert_builtin!("ANY", ANY);
     insert_builtin!("SOI", SOI);
-    insert_builtin_with_lifetime!("PEEK", PEEK);
-    insert_builtin_with_lifetime!("PEEK_ALL", PEEK_ALL);
-    insert_builtin_with_lifetime!("POP", POP);
-    insert_builtin_with_lifetime!("POP_ALL", POP_ALL);
+    insert_builtin!("PEEK", PEEK);
+    insert_builtin!("PEEK_ALL", PEEK_ALL);
+    insert_builtin!("POP", POP);
+    insert_builtin!("POP_ALL", POP_ALL);
     insert_builtin!("DROP", DROP);
 
     insert_builtin!("ASCII_DIGIT", ASCII_DIGIT);
@@ -966,10 +1280,10 @@ fn generate_builtin(
     insert_builtin!("ASCII", ASCII);
     insert_builtin!("NEWLINE", NEWLINE);
 
-    insert_builtin_with_lifetime!("WHITESPACE", AlwaysFail);
-    insert_builtin_with_lifetime!("COMMENT", AlwaysFail);
+    insert_builtin!("WHITESPACE", AlwaysFail);
+    insert_builtin!("COMMENT", AlwaysFail);
 
-    (quote! { #(#results)*}, builtins_without_lifetime)
+    quote! { #(#results)*}
 }
 
 #[cfg(test)]
@@ -1012,6 +1326,36 @@ mod tests {
         assert_eq!(used, expected);
     }
     #[test]
+    fn trivia() {
+        use pest_meta::parser::consume_rules;
+        use trivia::Trivia;
+
+        let grammar = r#"
+            WHITESPACE = _{ " " | "\t" | NEWLINE }
+            COMMENT = _{ "//" ~ (!NEWLINE ~ ANY)* | "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+        "#;
+        let expected = vec![
+            Trivia::Chars(vec![(' ', ' ')]),
+            Trivia::Chars(vec![('\t', '\t')]),
+            Trivia::newline(),
+            Trivia::until("//", trivia::NEWLINE.map(String::from).to_vec(), false).unwrap(),
+            Trivia::until("/*", vec!["*/".to_owned()], true).unwrap(),
+        ];
+        let (_, optimized) = parse_and_optimize(grammar).unwrap();
+        assert_eq!(collect_trivia(&optimized), Some((expected.clone(), 3)));
+        let rules = consume_rules(parse(Rule::grammar_rules, grammar).unwrap()).unwrap();
+        assert_eq!(collect_trivia(&rules), Some((expected.clone(), 3)));
+        let (_, rules) = parse_and_optimize(r#"COMMENT = _{ "//" ~ (!NEWLINE ~ ANY)* }"#).unwrap();
+        assert_eq!(collect_trivia(&rules), Some((expected[3..4].to_vec(), 0)));
+
+        let (_, rules) = parse_and_optimize(r#"WHITESPACE = @{ " " }"#).unwrap();
+        assert_eq!(collect_trivia(&rules), None);
+        let (_, rules) = parse_and_optimize(r#"COMMENT = _{ "%" ~ ANY }"#).unwrap();
+        assert_eq!(collect_trivia(&rules), None);
+        let (_, rules) = parse_and_optimize(r#"x = { "x" }"#).unwrap();
+        assert_eq!(collect_trivia(&rules), None);
+    }
+    #[test]
     /// Check we can actually break the cycles.
     fn inter_reference() {
         let (_, rules) =
@@ -1,9 +1,12 @@
 use super::{
     generics, ident, pest_typed, rules_mod,
-    traits::{process_single_alias, Generate},
-    Emission, Getter, Implicit, Output, RuleConfig,
+    traits::{
+        merge_char_branches, process_alias, process_sequence_alias, process_single_alias, Branch,
+        Generate, View,
+    },
+    Constructor, Emission, Getter, Implicit, Output, RuleConfig,
 };
-use crate::{config::Config, docs::DocComment, types::option_type};
+use crate::config::Config;
 use pest_meta::{
     ast::RuleType,
     optimizer::{OptimizedExpr, OptimizedRule},
@@ -23,6 +26,10 @@ impl Generate for OptimizedRule {
         self.ty
     }
 
+    fn expr(&self) -> &Self::Expr {
+        &self.expr
+    }
+
     /// Returns type name.
     #[allow(private_interfaces)]
     fn generate_graph_node<'g>(
@@ -32,7 +39,7 @@ impl Generate for OptimizedRule {
         map: &mut Output<'g>,
         explicit: bool,
         emission: Emission,
-        config: Config,
+        config: &Config,
         root: &TokenStream,
     ) -> (TokenStream, Getter<'g>) {
         let generics = generics();
@@ -57,16 +64,17 @@ impl Generate for OptimizedRule {
         match expr {
             OptimizedExpr::Str(content) => {
                 let wrapper = map.insert_string_wrapper(content.as_str());
-                process_single_alias(
+                process_alias(
                     map,
                     rule_config,
                     quote! {
-                        #root::#generics::Str::<#root::#wrapper>
+                        #root::#generics::Str::<S, #root::#wrapper>
                     },
                     Getter::new(),
                     root,
                     emission,
                     explicit,
+                    Constructor::Literal,
                 )
             }
             OptimizedExpr::Insens(content) => {
@@ -88,10 +96,10 @@ impl Generate for OptimizedRule {
                 rule_config,
                 match end {
                     Some(end) => quote! {
-                        #root::#generics::PeekSlice2::<#start, #end>
+                        #root::#generics::PeekSlice2::<S, #start, #end>
                     },
                     None => quote! {
-                        #root::#generics::PeekSlice1::<#start>
+                        #root::#generics::PeekSlice1::<S, #start>
                     },
                 },
                 Getter::new(),
@@ -142,7 +150,7 @@ impl Generate for OptimizedRule {
                     map,
                     rule_config,
                     quote! {
-                        #root::#generics::CharRange::<#start, #end>
+                        #root::#generics::CharRange::<S, #start, #end>
                     },
                     Getter::new(),
                     root,
@@ -153,21 +161,22 @@ impl Generate for OptimizedRule {
             OptimizedExpr::Ident(id) => {
                 let inner = ident(id);
                 let rules = rules_mod();
-                let has_generic_s = rule_config.defined.contains(id.as_str())
-                    || !rule_config.builtins_without_lifetime.contains(id.as_str());
                 let has_skip = rule_config.defined.contains(id.as_str());
-                let generics = match (has_generic_s, has_skip) {
-                    (true, true) => quote! {::<S, #skip>},
-                    (true, false) => quote! {::<S>},
-                    (false, true) => quote! {::<#skip>},
-                    (false, false) => quote! {},
+                let lazy = quote! {#root::#generics::Lazy};
+                let generics = match has_skip {
+                    true => quote! {::<S, #skip>},
+                    false => quote! {::<S>},
                 };
                 let getters = if config.emit_rule_reference {
-                    Getter::from_rule(id, id.as_str(), has_generic_s, has_skip)
+                    Getter::from_rule(id, id.as_str(), has_skip)
                 } else {
                     Getter::new()
                 };
-                let type_name = quote! {#root::#rules::#inner #generics};
+                let type_name = if rule_config.lazy.contains(id.as_str()) {
+                    quote! {#lazy::<S, #root::#rules::#inner #generics>}
+                } else {
+                    quote! {#root::#rules::#inner #generics}
+                };
                 process_single_alias(
                     map,
                     rule_config,
@@ -215,7 +224,7 @@ impl Generate for OptimizedRule {
                     map,
                     rule_config,
                     quote! {
-                        #root::#generics::Negative::<#inner>
+                        #root::#generics::Negative::<S, #inner>
                     },
                     Getter::new(),
                     root,
@@ -230,7 +239,7 @@ impl Generate for OptimizedRule {
                 let vec = walk!(expr, Seq);
                 let mut types = Vec::<TokenStream>::with_capacity(vec.len());
                 let mut getter = Getter::new();
-                for (i, expr) in vec.into_iter().enumerate() {
+                for (i, expr) in vec.iter().copied().enumerate() {
                     let (child, acc) = Self::generate_graph_node(
                         expr,
                         rule_config,
@@ -250,7 +259,7 @@ impl Generate for OptimizedRule {
                 let args = types.iter().map(
                 |t| quote! {(#pest_typed::predefined_node::Skipped<#t, #root::generics::Skipped<S>, #skip>)},
             );
-                process_single_alias(
+                process_sequence_alias::<Self>(
                     map,
                     rule_config,
                     quote! { #root::#generics::#seq::<#(#args, )*> },
@@ -258,31 +267,47 @@ impl Generate for OptimizedRule {
                     root,
                     emission,
                     explicit,
+                    &vec,
+                    types,
                 )
             }
             OptimizedExpr::Choice(_, _) => {
-                let vec = walk!(expr, Choice);
+                let vec = merge_char_branches::<Self>(walk!(expr, Choice), rule_config.defined);
                 let mut types = Vec::<TokenStream>::with_capacity(vec.len());
                 let mut getter = Getter::new();
-                for (i, expr) in vec.into_iter().enumerate() {
-                    let (child, acc) = Self::generate_graph_node(
-                        expr,
-                        rule_config,
-                        map,
-                        false,
-                        emission,
-                        config,
-                        root,
-                    );
-                    types.push(child);
-                    getter = getter.join(acc.choice(i));
+                for (i, branch) in vec.into_iter().enumerate() {
+                    match branch {
+                        Branch::Expr(expr) => {
+                            let (child, acc) = Self::generate_graph_node(
+                                expr,
+                                rule_config,
+                                map,
+                                false,
+                                emission,
+                                config,
+                                root,
+                            );
+                            types.push(child);
+                            getter = getter.join(acc.choice(i));
+                        }
+                        Branch::CharSet { ranges, properties } => {
+                            let wrapper = map.insert_char_set_wrapper(&ranges, &properties);
+                            types.push(quote! { #root::#generics::CharSet::<S, #root::#wrapper> });
+                        }
+                    }
                 }
-                let choice = format_ident!("Choice{}", types.len());
-                map.record_choice(types.len());
+                let type_name = match types.as_slice() {
+                    [single] => single.clone(),
+                    _ => {
+                        let choice = format_ident!("Choice{}", types.len());
+                        map.record_choice(types.len());
+                        quote! { #root::#generics::#choice::<#(#types, )*> }
+                    }
+                };
                 process_single_alias(
                     map,
                     rule_config,
-                    quote! { #root::#generics::#choice::<#(#types, )*> },
+                    type_name,
                     getter,
                     root,
                     emission,
@@ -300,11 +325,10 @@ impl Generate for OptimizedRule {
                     root,
                 );
                 let getters = getters.optional();
-                let option = option_type();
                 process_single_alias(
                     map,
                     rule_config,
-                    quote! {#option::<#inner_name>},
+                    quote! {#root::#generics::Optional::<S, #inner_name>},
                     getters,
                     root,
                     emission,
@@ -359,7 +383,7 @@ impl Generate for OptimizedRule {
                     map,
                     rule_config,
                     quote! {
-                        #root::#generics::PushLiteral::<#root::#wrapper>
+                        #root::#generics::PushLiteral::<S, #root::#wrapper>
                     },
                     Getter::new(),
                     root,
@@ -405,56 +429,38 @@ impl Generate for OptimizedRule {
     }
 
     #[allow(private_interfaces)]
-    fn generate_graph<'g: 'f, 'f>(
-        rules: &'g [Self],
-        defined: &'g BTreeSet<&'g str>,
-        not_boxed: &'f BTreeSet<&'g str>,
-        builtins_without_lifetime: &'g BTreeSet<&'g str>,
-        config: Config,
-        doc: &'g DocComment,
-    ) -> Output<'g> {
-        let mut res = Output::new();
-        for rule in rules.iter() {
-            let rule_name = rule.name.as_str();
-            let (atomicity, emission) = match rule.ty {
-                RuleType::Normal => (None, Emission::Both),
-                RuleType::Silent => (None, Emission::Expression),
-                RuleType::NonAtomic => (Some(false), Emission::Both),
-                RuleType::CompoundAtomic => (Some(true), Emission::Both),
-                RuleType::Atomic => (Some(true), Emission::Span),
-            };
-            let atomicity_doc = match atomicity {
-                Some(true) => "Atomic rule.",
-                Some(false) => "Non-atomic rule.",
-                None => "Normal rule.",
-            };
-            let rule_desc = format!(
-                "Corresponds to expression: `{}`. {}",
-                rule.expr, atomicity_doc
-            );
-            let boxed = !config.box_only_if_needed || !not_boxed.contains(rule_name);
-            let rule_doc = doc.line_docs.get(rule_name).map(|s| s.as_str());
-            let rule_config = RuleConfig {
-                atomicity,
-                boxed,
-                rule_id: ident(rule_name),
-                rule_name,
-                rule_desc,
-                rule_doc,
-                defined,
-                builtins_without_lifetime,
-            };
-            Self::generate_graph_node(
-                &rule.expr,
-                &rule_config,
-                &mut res,
-                true,
-                emission,
-                config,
-                &quote! {super::super},
-            );
+    fn view(expr: &OptimizedExpr) -> View<'_, OptimizedExpr> {
+        match expr {
+            OptimizedExpr::Str(content) => View::Str(content),
+            OptimizedExpr::Range(start, end) => View::Range(start, end),
+            OptimizedExpr::Ident(id) => View::Ident(id),
+            OptimizedExpr::Choice(lhs, rhs) => View::Choice(lhs, rhs),
+            OptimizedExpr::Seq(lhs, rhs) => View::Seq(lhs, rhs),
+            OptimizedExpr::Rep(inner) => View::Rep(inner),
+            #[cfg(feature = "grammar-extras")]
+            OptimizedExpr::RepOnce(inner) => View::RepOnce(inner),
+            OptimizedExpr::RestoreOnErr(inner) => Self::view(inner),
+            OptimizedExpr::NegPred(inner) => View::NegPred(inner),
+            OptimizedExpr::Skip(strings) => View::Skip(strings),
+            _ => View::Other,
+        }
+    }
+
+    fn never_fails(expr: &OptimizedExpr, never_failed: &BTreeSet<&str>) -> bool {
+        match expr {
+            OptimizedExpr::Opt(_) | OptimizedExpr::Rep(_) => true,
+            OptimizedExpr::Ident(id) => never_failed.contains(id.as_str()),
+            OptimizedExpr::RestoreOnErr(inner) => Self::never_fails(inner, never_failed),
+            OptimizedExpr::Seq(lhs, rhs) => {
+                Self::never_fails(lhs, never_failed) && Self::never_fails(rhs, never_failed)
+            }
+            OptimizedExpr::Choice(lhs, rhs) => {
+                Self::never_fails(lhs, never_failed) || Self::never_fails(rhs, never_failed)
+            }
+            #[cfg(feature = "grammar-extras")]
+            OptimizedExpr::NodeTag(inner, _) => Self::never_fails(inner, never_failed),
+            _ => false,
         }
-        res
     }
 
     #[allow(private_interfaces)]
@@ -1,9 +1,14 @@
-use super::{rule, rules_mod, Emission, Getter, Implicit, Output, RuleConfig};
-use crate::{config::Config, docs::DocComment};
+use super::{
+    ident, rule, rules_mod,
+    trivia::{Trivia, NEWLINE},
+    Constructor, Emission, Getter, Implicit, Item, Output, RuleConfig,
+};
+use crate::config::Config;
+use pest::unicode::unicode_property_names;
 use pest_meta::ast::RuleType;
 use proc_macro2::TokenStream;
-use quote::quote;
-use std::collections::BTreeSet;
+use quote::{format_ident, quote};
+use std::{collections::BTreeSet, fmt::Display};
 
 /// Returns (type name, getter).
 pub(super) fn process_single_alias<'g>(
@@ -14,10 +19,81 @@ pub(super) fn process_single_alias<'g>(
     root: &TokenStream,
     emission: Emission,
     explicit: bool,
+) -> (TokenStream, Getter<'g>) {
+    process_alias(
+        map,
+        rule_config,
+        type_name,
+        getters,
+        root,
+        emission,
+        explicit,
+        Constructor::Content,
+    )
+}
+
+/// Returns (type name, getter) of a sequence of `exprs`, whose types are `types`.
+///
+/// Rule structs of explicit sequences are constructed from their items except string literals.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_sequence_alias<'g, G: Generate>(
+    map: &mut Output<'g>,
+    rule_config: &RuleConfig<'g>,
+    type_name: TokenStream,
+    getters: Getter<'g>,
+    root: &TokenStream,
+    emission: Emission,
+    explicit: bool,
+    exprs: &[&G::Expr],
+    types: Vec<TokenStream>,
+) -> (TokenStream, Getter<'g>) {
+    let views: Vec<_> = exprs.iter().map(|expr| G::view(expr)).collect();
+    let referenced = |name: &str| {
+        views
+            .iter()
+            .filter(|view| matches!(view, View::Ident(id) if *id == name))
+            .count()
+    };
+    let items = views
+        .iter()
+        .zip(types)
+        .enumerate()
+        .map(|(i, (view, ty))| {
+            let param = match view {
+                View::Str(_) => None,
+                View::Ident(id) if referenced(id) == 1 => Some(ident(id)),
+                _ => Some(format_ident!("item_{}", i)),
+            };
+            Item { ty, param }
+        })
+        .collect();
+    process_alias(
+        map,
+        rule_config,
+        type_name,
+        getters,
+        root,
+        emission,
+        explicit,
+        Constructor::Sequence(items),
+    )
+}
+
+/// Returns (type name, getter), and defines the rule struct with `constructor` if `explicit`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_alias<'g>(
+    map: &mut Output<'g>,
+    rule_config: &RuleConfig<'g>,
+    type_name: TokenStream,
+    getters: Getter<'g>,
+    root: &TokenStream,
+    emission: Emission,
+    explicit: bool,
+    constructor: Constructor,
 ) -> (TokenStream, Getter<'g>) {
     if explicit {
         let rule_id = &rule_config.rule_id;
-        let def = rule(rule_config, type_name, &getters, emission);
+        let def = rule(rule_config, type_name, &getters, emission, &constructor);
         map.insert(def, rule_config.clone());
         let rules = rules_mod();
         (quote! {#root::#rules::#rule_id::<S>}, getters)
@@ -26,14 +102,246 @@ pub(super) fn process_single_alias<'g>(
     }
 }
 
+/// A view of expressions shared by [`Expr`](pest_meta::ast::Expr)
+/// and [`OptimizedExpr`](pest_meta::optimizer::OptimizedExpr).
+///
+/// Expressions that don't change what is matched, such as `RestoreOnErr`, are seen through.
+pub(super) enum View<'e, E> {
+    /// A string literal.
+    Str(&'e str),
+    /// A character range.
+    Range(&'e str, &'e str),
+    /// A rule reference.
+    Ident(&'e str),
+    /// `lhs | rhs`.
+    Choice(&'e E, &'e E),
+    /// `lhs ~ rhs`.
+    Seq(&'e E, &'e E),
+    /// `inner*`.
+    Rep(&'e E),
+    /// `inner+`.
+    RepOnce(&'e E),
+    /// `!inner`.
+    NegPred(&'e E),
+    /// `(!(s0 | s1 | ...) ~ ANY)*`.
+    Skip(&'e [String]),
+    /// Other expressions.
+    Other,
+}
+
+/// Characters matched by an expression that always matches exactly one character.
+struct CharClass {
+    ranges: Vec<(char, char)>,
+    properties: Vec<&'static str>,
+    /// Whether it's a string literal.
+    literal: bool,
+}
+
+/// Ranges of built-in rules that match one ASCII character.
+fn ascii_builtin(name: &str) -> Option<&'static [(char, char)]> {
+    let ranges: &'static [(char, char)] = match name {
+        "ASCII_DIGIT" => &[('0', '9')],
+        "ASCII_NONZERO_DIGIT" => &[('1', '9')],
+        "ASCII_BIN_DIGIT" => &[('0', '1')],
+        "ASCII_OCT_DIGIT" => &[('0', '7')],
+        "ASCII_HEX_DIGIT" => &[('0', '9'), ('A', 'F'), ('a', 'f')],
+        "ASCII_ALPHA_LOWER" => &[('a', 'z')],
+        "ASCII_ALPHA_UPPER" => &[('A', 'Z')],
+        "ASCII_ALPHA" => &[('A', 'Z'), ('a', 'z')],
+        "ASCII_ALPHANUMERIC" => &[('0', '9'), ('A', 'Z'), ('a', 'z')],
+        "ASCII" => &[('\0', '\x7f')],
+        _ => return None,
+    };
+    Some(ranges)
+}
+
+/// Returns the character class if `expr` always matches exactly one character.
+///
+/// Built-in rules and unicode properties are only recognized if not in `defined`.
+fn as_char_class<R: Generate>(expr: &R::Expr, defined: &BTreeSet<&str>) -> Option<CharClass> {
+    let single = |s: &str| {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    };
+    let (ranges, properties, literal) = match R::view(expr) {
+        View::Str(content) => {
+            let c = single(content)?;
+            (vec![(c, c)], vec![], true)
+        }
+        View::Range(start, end) => match (single(start), single(end)) {
+            (Some(start), Some(end)) if start <= end => (vec![(start, end)], vec![], false),
+            _ => return None,
+        },
+        View::Ident(id) if !defined.contains(id) => match ascii_builtin(id) {
+            Some(ranges) => (ranges.to_vec(), vec![], false),
+            None => {
+                let property = unicode_property_names().find(|name| *name == id)?;
+                (vec![], vec![property], false)
+            }
+        },
+        _ => return None,
+    };
+    Some(CharClass {
+        ranges,
+        properties,
+        literal,
+    })
+}
+
+/// A branch of choices after merging.
+pub(super) enum Branch<'g, E> {
+    /// An expression that is kept as is.
+    Expr(&'g E),
+    /// Consecutive single-character branches merged into a character set.
+    CharSet {
+        /// Sorted, non-overlapping and non-adjacent ranges.
+        ranges: Vec<(char, char)>,
+        /// Names of unicode properties.
+        properties: Vec<&'static str>,
+    },
+}
+
+/// Sort ranges and merge those overlapping or adjacent.
+fn normalize_ranges(mut ranges: Vec<(char, char)>) -> Vec<(char, char)> {
+    ranges.sort_unstable();
+    let mut merged = Vec::<(char, char)>::with_capacity(ranges.len());
+    for (min, max) in ranges {
+        match merged.last_mut() {
+            Some((_, last)) if min as u32 <= *last as u32 + 1 => *last = max.max(*last),
+            _ => merged.push((min, max)),
+        }
+    }
+    merged
+}
+
+/// Merge consecutive branches that match a single character into character sets.
+///
+/// Branches are character literals, character ranges, built-in rules such as `ASCII_DIGIT`,
+/// and unicode properties such as `XID_START`.
+/// Runs only of string literals are kept as is, so their typed branches stay accessible.
+/// The order of other branches is kept, so the semantics of ordered choices stays the same.
+pub(super) fn merge_char_branches<'g, R: Generate>(
+    exprs: Vec<&'g R::Expr>,
+    defined: &BTreeSet<&str>,
+) -> Vec<Branch<'g, R::Expr>> {
+    let mut res = Vec::with_capacity(exprs.len());
+    let mut run = Vec::<(&'g R::Expr, CharClass)>::new();
+    let flush = |run: &mut Vec<(&'g R::Expr, CharClass)>, res: &mut Vec<Branch<'g, R::Expr>>| {
+        if run.len() > 1 && run.iter().any(|(_, class)| !class.literal) {
+            let mut ranges = vec![];
+            let mut properties = vec![];
+            for (_, class) in run.drain(..) {
+                ranges.extend(class.ranges);
+                properties.extend(class.properties);
+            }
+            properties.sort_unstable();
+            properties.dedup();
+            res.push(Branch::CharSet {
+                ranges: normalize_ranges(ranges),
+                properties,
+            });
+        } else {
+            res.extend(run.drain(..).map(|(expr, _)| Branch::Expr(expr)));
+        }
+    };
+    for expr in exprs {
+        match as_char_class::<R>(expr, defined) {
+            Some(class) => run.push((expr, class)),
+            None => {
+                flush(&mut run, &mut res);
+                res.push(Branch::Expr(expr));
+            }
+        }
+    }
+    flush(&mut run, &mut res);
+    res
+}
+
+/// Returns shapes of trivia, which are tried in order, if `expr` only consists of them.
+pub(super) fn as_trivia<R: Generate>(expr: &R::Expr) -> Option<Vec<Trivia>> {
+    /// Strings matched by a choice of strings.
+    fn strings<R: Generate>(expr: &R::Expr) -> Option<Vec<String>> {
+        match R::view(expr) {
+            View::Str(content) => Some(vec![content.to_owned()]),
+            View::Ident("NEWLINE") => Some(NEWLINE.map(String::from).to_vec()),
+            View::Choice(lhs, rhs) => {
+                let mut res = strings::<R>(lhs)?;
+                res.extend(strings::<R>(rhs)?);
+                Some(res)
+            }
+            _ => None,
+        }
+    }
+    /// Strings that end `(!end ~ ANY)*`.
+    fn until<R: Generate>(expr: &R::Expr) -> Option<Vec<String>> {
+        match R::view(expr) {
+            View::Rep(inner) => match R::view(inner) {
+                View::Seq(lhs, rhs) => match (R::view(lhs), R::view(rhs)) {
+                    (View::NegPred(end), View::Ident("ANY")) => strings::<R>(end),
+                    _ => None,
+                },
+                _ => None,
+            },
+            View::Skip(strings) => Some(strings.to_vec()),
+            _ => None,
+        }
+    }
+    /// Elements of a sequence.
+    fn flatten<R: Generate>(expr: &R::Expr) -> Vec<&R::Expr> {
+        match R::view(expr) {
+            View::Seq(lhs, rhs) => {
+                let mut res = flatten::<R>(lhs);
+                res.extend(flatten::<R>(rhs));
+                res
+            }
+            _ => vec![expr],
+        }
+    }
+    match R::view(expr) {
+        View::Str(content) => Trivia::string(content).map(|trivia| vec![trivia]),
+        View::Range(start, end) => Trivia::range(start, end).map(|trivia| vec![trivia]),
+        View::Ident("NEWLINE") => Some(vec![Trivia::newline()]),
+        View::Choice(lhs, rhs) => {
+            let mut res = as_trivia::<R>(lhs)?;
+            res.extend(as_trivia::<R>(rhs)?);
+            Some(res)
+        }
+        // Each trivia consumes something, so repeating it is the same as skipping it repeatedly.
+        View::Rep(inner) | View::RepOnce(inner) => as_trivia::<R>(inner),
+        View::Seq(_, _) => {
+            let elements = flatten::<R>(expr);
+            let views: Vec<_> = elements.iter().map(|element| R::view(element)).collect();
+            match (elements.as_slice(), views.as_slice()) {
+                ([lhs, _], [_, View::Rep(inner)]) if *lhs == *inner => as_trivia::<R>(lhs),
+                ([_, body], [View::Str(start), _]) => {
+                    Trivia::until(start, until::<R>(body)?, false).map(|trivia| vec![trivia])
+                }
+                ([_, body, _], [View::Str(start), _, View::Str(end)])
+                    if until::<R>(body)? == [end.to_string()] =>
+                {
+                    Trivia::until(start, vec![end.to_string()], true).map(|trivia| vec![trivia])
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub(crate) trait Generate: Sized {
-    type Expr;
+    type Expr: PartialEq + Display;
 
     /// Returns rule name.
     fn name(&self) -> &str;
 
     fn ty(&self) -> RuleType;
 
+    /// Returns the expression of the rule.
+    fn expr(&self) -> &Self::Expr;
+
     /// Returns (type name, getter).
     #[allow(private_interfaces)]
     fn generate_graph_node<'g>(
@@ -43,19 +351,16 @@ pub(crate) trait Generate: Sized {
         map: &mut Output<'g>,
         explicit: bool,
         emission: Emission,
-        config: Config,
+        config: &Config,
         root: &TokenStream,
     ) -> (TokenStream, Getter<'g>);
 
+    /// Returns a view of `expr`.
     #[allow(private_interfaces)]
-    fn generate_graph<'g: 'f, 'f>(
-        rules: &'g [Self],
-        defined: &'g BTreeSet<&'g str>,
-        not_boxed: &'f BTreeSet<&'g str>,
-        builtins_without_lifetime: &'g BTreeSet<&'g str>,
-        config: Config,
-        doc: &'g DocComment,
-    ) -> Output<'g>;
+    fn view(expr: &Self::Expr) -> View<'_, Self::Expr>;
+
+    /// Returns whether `expr` always matches, given names of rules that never fail.
+    fn never_fails(expr: &Self::Expr, never_failed: &BTreeSet<&str>) -> bool;
 
     #[allow(private_interfaces)]
     fn collect_used_rule<'s>(rule: &'s Self, implicit: Implicit, res: &mut BTreeSet<&'s str>);
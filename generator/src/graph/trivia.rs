@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Common shapes of `WHITESPACE` and `COMMENT` that can be skipped without typed nodes.
+
+use super::pest_typed;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Strings matched by built-in rule `NEWLINE`.
+pub(super) const NEWLINE: [&str; 3] = ["\n", "\r\n", "\r"];
+
+/// Corresponds to `pest_typed::predefined_node::Trivia`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Trivia {
+    Chars(Vec<(char, char)>),
+    Str(String),
+    Until {
+        start: String,
+        end: Vec<String>,
+        consume_end: bool,
+    },
+}
+
+impl Trivia {
+    /// An exact string. Empty strings are not trivia as they consume nothing.
+    pub(super) fn string(string: &str) -> Option<Self> {
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => None,
+            (Some(c), None) => Some(Self::Chars(vec![(c, c)])),
+            (Some(_), Some(_)) => Some(Self::Str(string.to_owned())),
+        }
+    }
+    /// A character range with both ends being single characters.
+    pub(super) fn range(start: &str, end: &str) -> Option<Self> {
+        let single = |s: &str| {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        };
+        match (single(start), single(end)) {
+            (Some(start), Some(end)) if start <= end => Some(Self::Chars(vec![(start, end)])),
+            _ => None,
+        }
+    }
+    /// Characters in `NEWLINE`. `"\r\n"` is covered by repeated skipping.
+    pub(super) fn newline() -> Self {
+        Self::Chars(vec![('\n', '\n'), ('\r', '\r')])
+    }
+    /// `start ~ (!end ~ ANY)*`, optionally followed by `end`.
+    pub(super) fn until(start: &str, end: Vec<String>, consume_end: bool) -> Option<Self> {
+        if start.is_empty() || end.is_empty() || end.iter().any(String::is_empty) {
+            return None;
+        }
+        Some(Self::Until {
+            start: start.to_owned(),
+            end,
+            consume_end,
+        })
+    }
+}
+
+impl ToTokens for Trivia {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let pest_typed = pest_typed();
+        let trivia = quote! {#pest_typed::predefined_node::Trivia};
+        tokens.extend(match self {
+            Self::Chars(ranges) => {
+                let ranges = ranges.iter().map(|(min, max)| quote! {(#min, #max)});
+                quote! {#trivia::Chars(&[ #(#ranges),* ])}
+            }
+            Self::Str(string) => quote! {#trivia::Str(#string)},
+            Self::Until {
+                start,
+                end,
+                consume_end,
+            } => quote! {
+                #trivia::Until { start: #start, end: &[ #(#end),* ], consume_end: #consume_end }
+            },
+        })
+    }
+}
@@ -28,9 +28,11 @@
 )]
 
 mod config;
+mod from_typed_node;
 mod graph;
 mod match_choices;
 mod typed;
+pub use from_typed_node::derive_from_typed_node;
 pub use match_choices::match_choices;
 pub use typed::derive_typed_parser;
 
@@ -29,6 +29,7 @@ use crate::{
     helper::get_bool,
 };
 use pest_meta::{
+    ast::{self, Expr},
     optimizer::optimize,
     parser::{consume_rules, parse, rename_meta_rule, Rule},
     unwrap_or_report,
@@ -59,6 +60,9 @@ pub fn derive_typed_parser(
 
     let doc_comment = consume(pairs.clone());
     let ast = unwrap_or_report(consume_rules(pairs));
+    if config.byte_input && !config.no_warnings {
+        check_byte_input(&ast);
+    }
     if config.pest_optimizer {
         let optimized = optimize(ast);
         let input = Input::new(optimized, doc_comment);
@@ -70,7 +74,7 @@ pub fn derive_typed_parser(
             &input,
             include_grammar,
             include_derive,
-            config,
+            &config,
         )
     } else {
         let input = Input::new(ast, doc_comment);
@@ -81,11 +85,43 @@ pub fn derive_typed_parser(
             &input,
             include_grammar,
             include_derive,
-            config,
+            &config,
         )
     }
 }
 
+/// Warn about characters that can never match a single Latin-1 byte.
+fn check_byte_input(rules: &[ast::Rule]) {
+    for rule in rules {
+        for expr in rule.expr.iter_top_down() {
+            match expr {
+                Expr::Range(start, end) if start.chars().next().is_some_and(|c| c > '\u{ff}') => {
+                    eprintln!(
+                        "Range '{}'..'{}' in rule `{}` never matches a single byte.",
+                        start, end, rule.name
+                    );
+                }
+                Expr::Str(string) | Expr::Insens(string)
+                    if string.chars().any(|c| c > '\u{ff}') =>
+                {
+                    eprintln!(
+                        "String {:?} in rule `{}` never matches Latin-1 bytes.",
+                        string, rule.name
+                    );
+                }
+                #[cfg(feature = "grammar-extras")]
+                Expr::PushLiteral(string) if !string.is_ascii() => {
+                    eprintln!(
+                        "Literal {:?} pushed in rule `{}` should be ASCII for byte input.",
+                        string, rule.name
+                    );
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
 pub(crate) struct Input<R> {
     rules: Vec<R>,
     doc_comment: DocComment,
@@ -103,6 +139,7 @@ fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>,
 
     let mut grammar_sources = vec![];
     let mut config = Config::default();
+    let mut token_kinds = vec![];
     for attr in ast.attrs.iter() {
         let path = attr.meta.path();
         if path.is_ident("grammar") {
@@ -128,12 +165,26 @@ fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>,
             config.box_only_if_needed = get_bool(attr);
         } else if path.is_ident("no_warnings") {
             config.no_warnings = get_bool(attr);
+        } else if path.is_ident("fast_trivia") {
+            config.fast_trivia = get_bool(attr);
+        } else if path.is_ident("byte_input") {
+            config.byte_input = get_bool(attr);
+        } else if path.is_ident("lazy_rule") {
+            config.lazy_rules.insert(get_string(attr));
+        } else if path.is_ident("token_kind") {
+            token_kinds.push(get_string(attr));
         }
     }
 
     if grammar_sources.is_empty() {
         panic!("A grammar file needs to be provided with the #[grammar = \"PATH\"] or #[grammar_inline = \"GRAMMAR CONTENTS\"] attribute.");
     }
+    // Each token kind is a rule matching a single token of that kind.
+    for kind in token_kinds {
+        grammar_sources.push(GrammarSource::Inline(format!(
+            "\n/// A token of kind `{kind}`.\n{kind} = @{{ \"{kind}\" }}\n"
+        )));
+    }
 
     (name, generics, grammar_sources, config)
 }
@@ -146,7 +197,7 @@ fn generate_typed<R: Generate>(
     input: &Input<R>,
     include_grammar: bool,
     include_derive: bool,
-    config: Config,
+    config: &Config,
 ) -> TokenStream {
     let include_fix = if include_grammar {
         generate_include(&name, paths)
@@ -181,6 +232,7 @@ fn generate_typed<R: Generate>(
 mod tests {
     use super::*;
     use quote::format_ident;
+    use std::collections::BTreeSet;
     #[test]
     fn test_default_config() {
         let ast: DeriveInput = syn::parse2(quote! {
@@ -195,10 +247,8 @@ mod tests {
             vec![GrammarSource::Inline(r#"x = { "x" }"#.to_owned())]
         );
         assert_eq!(config, Config::default());
-        assert_eq!(
-            format!("{:?}", config.clone()),
-            format!("{:?}", Config::default())
-        );
+        assert_eq!(format!("{:?}", config), format!("{:?}", Config::default()));
+        assert!(config.lazy_rules.is_empty());
     }
 
     #[test]
@@ -208,10 +258,21 @@ mod tests {
             #[emit_rule_reference]
             #[no_warnings = true]
             #[truncate_getter_at_node_tag = false]
+            #[fast_trivia]
+            #[byte_input]
+            #[lazy_rule = "x"]
+            #[lazy_rule = "y"]
+            #[token_kind = "IDENT"]
             struct x;
         })
         .unwrap();
-        let (_, _, _, config) = parse_typed_derive(ast);
+        let (_, _, contents, config) = parse_typed_derive(ast);
+        assert_eq!(
+            contents[1],
+            GrammarSource::Inline(
+                "\n/// A token of kind `IDENT`.\nIDENT = @{ \"IDENT\" }\n".to_owned()
+            )
+        );
         assert_eq!(
             config,
             Config {
@@ -223,6 +284,9 @@ mod tests {
                 simulate_pair_api: false,
                 box_only_if_needed: false,
                 no_warnings: true,
+                fast_trivia: true,
+                byte_input: true,
+                lazy_rules: BTreeSet::from(["x".to_owned(), "y".to_owned()]),
             }
         );
     }
@@ -279,6 +343,34 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic]
+    fn undefined_lazy_rule() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[lazy_rule = "y"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn silent_lazy_rule() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = _{ \"x\" }"]
+                #[lazy_rule = "x"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
     #[test]
     #[should_panic]
     fn bool_attribute_format_error() {
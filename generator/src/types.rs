@@ -24,3 +24,8 @@ pub(crate) fn option_type() -> TokenStream {
 pub(crate) fn vec_type() -> TokenStream {
     quote! { ::pest_typed::re_exported::Vec }
 }
+
+/// `Result` in corresponding context.
+pub(crate) fn result_type() -> TokenStream {
+    quote! { ::pest_typed::re_exported::Result }
+}
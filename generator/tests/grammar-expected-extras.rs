@@ -251,13 +251,46 @@ pub mod tags {
 pub mod rules_impl {
     #[doc = "Definitions of statically typed nodes generated by pest-generator."]
     pub mod rules {
-        :: pest_typed :: rule ! (pub r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        :: pest_typed :: rule ! (pub r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#string<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#string<S, INHERITED> {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#insensitive<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#insensitive<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Insens<S, super::super::constant_wrappers::r#w_1>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: generics :: CharRange :: < S , '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#range<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#ident , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#range<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::CharRange<S, '0', '9'>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ident , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#ident<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -273,7 +306,16 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#ident<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#string<S, INHERITED>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pos_pred<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -292,11 +334,57 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pos_pred<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Positive<
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#neg_pred<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: generics :: Negative :: < super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < S , INHERITED > > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#neg_pred<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Negative<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: generics :: Negative :: < S , super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#double_neg_pred<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#double_neg_pred<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Negative<
+                    S,
+                    super::super::generics::Negative<
+                        S,
+                        super::super::rules::r#string<S, INHERITED>,
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -329,7 +417,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#sequence<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, 1>,
+                item_1: super::super::rules::r#string<S, 1>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_compound<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -362,13 +465,63 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_compound<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, 0>,
+                item_1: super::super::rules::r#string<S, 0>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules :: r#sequence :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules :: r#sequence :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_non_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules :: r#sequence_compound :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_non_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules :: r#sequence_compound :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_atomic_compound<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_atomic_compound<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_nested<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -401,7 +554,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules :: r#sequence_nested :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_nested<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules :: r#sequence_nested :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#sequence_nested , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_compound_nested<S, INHERITED> {
             #[doc = "A helper function to access [`sequence_nested`]."]
             #[allow(non_snake_case)]
@@ -419,7 +589,18 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#node_tag , "Corresponds to expression: `(#string = string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_compound_nested<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#sequence_nested<S, 0>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#node_tag , "Corresponds to expression: `(#string = string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#node_tag<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -435,7 +616,16 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#node_tag<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#string<S, INHERITED>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#choice<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -476,7 +666,21 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#choice<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#string<S, INHERITED>,
+                    super::super::rules::r#range<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#choice_prefix<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -517,7 +721,29 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#optional , "Corresponds to expression: `string?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#optional , :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#choice_prefix<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#string<S, INHERITED>,
+                    super::super::rules::r#range<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#optional , "Corresponds to expression: `string?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#optional , super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#optional,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#optional<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -534,12 +760,32 @@ pub mod rules_impl {
             {
                 let res = &*self.content;
                 {
-                    let res = res.as_ref().map(|res| res);
+                    let res = res.content.as_ref().map(|res| res);
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat , "Corresponds to expression: `string*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#optional<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat , "Corresponds to expression: `string*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#repeat,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -568,9 +814,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#repeat<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Rep<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_atomic,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            true
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_once , "Corresponds to expression: `string+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_once , "Corresponds to expression: `string+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_once<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -599,9 +877,37 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_once_atomic , "Corresponds to expression: `string+`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_once<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_once_atomic , "Corresponds to expression: `string+`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_once_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_once_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_max<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -633,7 +939,7 @@ pub mod rules_impl {
                         {
                             let res = &res.content.2.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -642,9 +948,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_max<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+                item_2: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_max_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_max_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_exact<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -677,7 +1015,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_exact<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -725,9 +1080,48 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+                item_2: super::super::generics::Rep<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_max,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_max<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -750,14 +1144,14 @@ pub mod rules_impl {
                         {
                             let res = &res.content.0.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -766,9 +1160,49 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_max<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_max_atomic,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            true
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_max_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_max_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#soi_at_start<S, INHERITED> {
             #[doc = "A helper function to access [`SOI`]."]
             #[allow(non_snake_case)]
@@ -779,7 +1213,7 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
+            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI<S> {
                 let res = &*self.content;
                 {
                     let res = &res.content.0.matched;
@@ -803,7 +1237,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#soi_at_start<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#SOI: super::super::rules::r#SOI<S>,
+                r#string: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#SOI, r#string)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < S , 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -841,7 +1292,43 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_mutate_stack<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::CharRange<S, 'a', 'c'>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_2>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    S,
+                    INHERITED,
+                >,
+                item_1: super::super::rules::r#POP<S>,
+                item_2: super::super::rules::r#POP<S>,
+                item_3: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < S , 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack_pop_all<S, INHERITED> {
             #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
@@ -860,7 +1347,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_mutate_stack_pop_all<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::CharRange<S, 'a', 'c'>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_3>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    S,
+                    INHERITED,
+                >,
+                r#POP_ALL: super::super::rules::r#POP_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, r#POP_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#repeat_mutate_stack_pop_all]);
         impl<S, const INHERITED: ::core::primitive::usize> r#will_fail<S, INHERITED> {
             #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
             #[allow(non_snake_case)]
@@ -881,7 +1402,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < S , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#will_fail<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#repeat_mutate_stack_pop_all: super::super::rules::r#repeat_mutate_stack_pop_all<
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((r#repeat_mutate_stack_pop_all , < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_4 > as :: core :: default :: Default > :: default () ,)))
+            }
+        }
+        :: pest_typed :: rule ! (pub r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < S , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: Rule :: r#will_fail]);
         impl<S, const INHERITED: ::core::primitive::usize> r#stack_resume_after_fail<S, INHERITED> {
             #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
             #[allow(non_snake_case)]
@@ -923,7 +1459,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#stack_resume_after_fail<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#will_fail<S, INHERITED>,
+                    super::super::rules::r#repeat_mutate_stack_pop_all<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_<S, INHERITED> {
             #[doc = "A helper function to access [`PEEK`]."]
             #[allow(non_snake_case)]
@@ -992,7 +1544,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#peek_<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::rules::r#PEEK<S>,
+                item_3: super::super::rules::r#PEEK<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_all<S, INHERITED> {
             #[doc = "A helper function to access [`PEEK_ALL`]."]
             #[allow(non_snake_case)]
@@ -1047,7 +1616,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: generics :: Seq6 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < 1i32 , - 2i32 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#peek_all<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                r#PEEK_ALL: super::super::rules::r#PEEK_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#PEEK_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: generics :: Seq6 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < S , 1i32 , - 2i32 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_slice_23<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -1110,7 +1695,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#peek_slice_23<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_3: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_4: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_5: super::super::generics::PeekSlice2<S, 1i32, -2i32>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3, item_4, item_5)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1179,7 +1785,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::rules::r#POP<S>,
+                item_3: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_all<S, INHERITED> {
             #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
@@ -1234,7 +1857,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#POP :: < S > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_all<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                r#POP_ALL: super::super::rules::r#POP_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#POP_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#POP :: < S > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_fail<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1286,7 +1925,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_9 > , super :: super :: generics :: Skipped < S > , 0 >) , > , > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_fail<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Negative<S, super::super::rules::r#POP<S>>,
+                r#range: super::super::rules::r#range<S, INHERITED>,
+                r#POP: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#range, r#POP)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_6 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_9 > , super :: super :: generics :: Skipped < S > , 0 >) , > , > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#EOI]);
         impl<S, const INHERITED: ::core::primitive::usize> r#checkpoint_restore<S, INHERITED> {
             #[doc = "A helper function to access [`DROP`]."]
             #[allow(non_snake_case)]
@@ -1299,7 +1955,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#DROP<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP> {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP<S>> {
                 let res = &*self.content;
                 {
                     let res = &res.content.1.matched;
@@ -1368,7 +2024,74 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_digits , "Corresponds to expression: `ASCII_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#checkpoint_restore<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_5>,
+                >,
+                item_1: super::super::generics::Choice3<
+                    super::super::generics::Seq3<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::Str<
+                                    S,
+                                    super::super::constant_wrappers::r#w_6,
+                                >,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_7>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#POP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#DROP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_8>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#POP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_9>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                >,
+                r#EOI: super::super::rules::r#EOI<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#EOI)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_digits , "Corresponds to expression: `ASCII_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1381,7 +2104,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_DIGIT<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT<S>>
             {
                 let res = &*self.content;
                 {
@@ -1397,7 +2120,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_nonzero_digits , "Corresponds to expression: `ASCII_NONZERO_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_NONZERO_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_digits<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_nonzero_digits , "Corresponds to expression: `ASCII_NONZERO_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_NONZERO_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_nonzero_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_NONZERO_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1410,7 +2150,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_NONZERO_DIGIT<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT<S>>
             {
                 let res = &*self.content;
                 {
@@ -1426,7 +2166,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_bin_digits , "Corresponds to expression: `ASCII_BIN_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_BIN_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_nonzero_digits<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_NONZERO_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_bin_digits , "Corresponds to expression: `ASCII_BIN_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_BIN_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_bin_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_BIN_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1439,7 +2196,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_BIN_DIGIT<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT<S>>
             {
                 let res = &*self.content;
                 {
@@ -1455,7 +2212,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_oct_digits , "Corresponds to expression: `ASCII_OCT_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_OCT_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_bin_digits<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_BIN_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_oct_digits , "Corresponds to expression: `ASCII_OCT_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_OCT_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_oct_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_OCT_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1468,7 +2242,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_OCT_DIGIT<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT<S>>
             {
                 let res = &*self.content;
                 {
@@ -1484,7 +2258,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_hex_digits , "Corresponds to expression: `ASCII_HEX_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_HEX_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_oct_digits<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_OCT_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_hex_digits , "Corresponds to expression: `ASCII_HEX_DIGIT+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_HEX_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_hex_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_HEX_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1497,7 +2288,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_HEX_DIGIT<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT<S>>
             {
                 let res = &*self.content;
                 {
@@ -1513,7 +2304,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alpha_lowers , "Corresponds to expression: `ASCII_ALPHA_LOWER+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA_LOWER , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_hex_digits<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_HEX_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alpha_lowers , "Corresponds to expression: `ASCII_ALPHA_LOWER+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA_LOWER :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alpha_lowers<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA_LOWER`]."]
             #[allow(non_snake_case)]
@@ -1526,7 +2334,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_ALPHA_LOWER<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER<S>>
             {
                 let res = &*self.content;
                 {
@@ -1542,7 +2350,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alpha_uppers , "Corresponds to expression: `ASCII_ALPHA_UPPER+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA_UPPER , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alpha_lowers<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_ALPHA_LOWER<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alpha_uppers , "Corresponds to expression: `ASCII_ALPHA_UPPER+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA_UPPER :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alpha_uppers<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA_UPPER`]."]
             #[allow(non_snake_case)]
@@ -1555,7 +2380,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_ALPHA_UPPER<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER<S>>
             {
                 let res = &*self.content;
                 {
@@ -1571,7 +2396,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alphas , "Corresponds to expression: `ASCII_ALPHA+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alpha_uppers<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_ALPHA_UPPER<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alphas , "Corresponds to expression: `ASCII_ALPHA+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHA :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alphas<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA`]."]
             #[allow(non_snake_case)]
@@ -1584,7 +2426,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_ALPHA<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA<S>>
             {
                 let res = &*self.content;
                 {
@@ -1600,7 +2442,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alphanumerics , "Corresponds to expression: `ASCII_ALPHANUMERIC+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alphas<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_ALPHA<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alphanumerics , "Corresponds to expression: `ASCII_ALPHANUMERIC+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alphanumerics<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHANUMERIC`]."]
             #[allow(non_snake_case)]
@@ -1613,7 +2472,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII_ALPHANUMERIC<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC<S>>
             {
                 let res = &*self.content;
                 {
@@ -1629,7 +2488,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#asciis , "Corresponds to expression: `ASCII+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alphanumerics<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII_ALPHANUMERIC<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#asciis , "Corresponds to expression: `ASCII+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ASCII :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#asciis<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII`]."]
             #[allow(non_snake_case)]
@@ -1642,7 +2518,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ASCII<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1657,7 +2533,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#newline , "Corresponds to expression: `NEWLINE+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#NEWLINE , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#asciis<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ASCII<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#newline , "Corresponds to expression: `NEWLINE+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#NEWLINE :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#newline<S, INHERITED> {
             #[doc = "A helper function to access [`NEWLINE`]."]
             #[allow(non_snake_case)]
@@ -1670,7 +2561,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#NEWLINE<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1685,7 +2576,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#XID_CONTINUE , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#newline<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#NEWLINE<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#XID_CONTINUE :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#unicode<S, INHERITED> {
             #[doc = "A helper function to access [`XID_CONTINUE`]."]
             #[allow(non_snake_case)]
@@ -1698,7 +2604,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#XID_CONTINUE<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE<S>>
             {
                 let res = &*self.content;
                 {
@@ -1725,7 +2631,7 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START {
+            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START<S> {
                 let res = &*self.content;
                 {
                     let res = &res.content.0.matched;
@@ -1733,9 +2639,39 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#unicode<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#XID_START: super::super::rules::r#XID_START<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#XID_CONTINUE<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#XID_START, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#SYMBOL<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#han , "Corresponds to expression: `HAN+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HAN , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#SYMBOL<S, INHERITED> {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#han , "Corresponds to expression: `HAN+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HAN :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#han<S, INHERITED> {
             #[doc = "A helper function to access [`HAN`]."]
             #[allow(non_snake_case)]
@@ -1748,7 +2684,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#HAN<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1763,7 +2699,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#hangul , "Corresponds to expression: `HANGUL+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HANGUL , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#han<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#HAN<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#hangul , "Corresponds to expression: `HANGUL+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HANGUL :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#hangul<S, INHERITED> {
             #[doc = "A helper function to access [`HANGUL`]."]
             #[allow(non_snake_case)]
@@ -1776,7 +2727,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#HANGUL<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1791,7 +2742,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#hiragana , "Corresponds to expression: `HIRAGANA+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HIRAGANA , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#hangul<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#HANGUL<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#hiragana , "Corresponds to expression: `HIRAGANA+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#HIRAGANA :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#hiragana<S, INHERITED> {
             #[doc = "A helper function to access [`HIRAGANA`]."]
             #[allow(non_snake_case)]
@@ -1804,7 +2770,8 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#HIRAGANA<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA<S>>
+            {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1819,7 +2786,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#arabic , "Corresponds to expression: `ARABIC+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ARABIC , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#hiragana<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#HIRAGANA<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#arabic , "Corresponds to expression: `ARABIC+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#ARABIC :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#arabic<S, INHERITED> {
             #[doc = "A helper function to access [`ARABIC`]."]
             #[allow(non_snake_case)]
@@ -1832,7 +2814,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ARABIC<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1847,7 +2829,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#emoji , "Corresponds to expression: `EMOJI+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#EMOJI , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#arabic<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#ARABIC<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#emoji , "Corresponds to expression: `EMOJI+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: generics :: RepOnce :: < super :: super :: rules :: r#EMOJI :: < S > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#emoji<S, INHERITED> {
             #[doc = "A helper function to access [`EMOJI`]."]
             #[allow(non_snake_case)]
@@ -1860,7 +2857,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#EMOJI<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI<S>> {
                 let res = &*self.content;
                 {
                     let res = res
@@ -1875,10 +2872,53 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#emoji<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::rules::r#EMOJI<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#WHITESPACE<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#COMMENT , "Corresponds to expression: `\"$\"+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: RepOnce :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#WHITESPACE<S, INHERITED>
+        {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#COMMENT , "Corresponds to expression: `\"$\"+`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: RepOnce :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_12 > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#COMMENT<S, INHERITED> {}
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#COMMENT<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::RepOnce<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_12>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
         #[allow(unused_imports)]
         use super::super::unicode::*;
         :: pest_typed :: rule_eoi ! (pub EOI , super :: super :: Rule);
@@ -1907,6 +2947,7 @@ pub mod generics {
     use ::pest_typed::predefined_node;
     #[doc = r" Skipped content."]
     pub type Skipped<S> = predefined_node::AtomicRepeat<
+        S,
         ::pest_typed::choices::Choice2<super::rules::WHITESPACE<S, 0>, super::rules::COMMENT<S, 0>>,
     >;
     pub use pest_typed::choices::Choice2;
@@ -1916,28 +2957,29 @@ pub mod generics {
     pub use pest_typed::sequence::Seq4;
     pub use pest_typed::sequence::Seq6;
     pub use predefined_node::{
-        CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, Skip, Str,
+        CharRange, CharSet, Insens, Lazy, Negative, Optional, PeekSlice1, PeekSlice2, Positive,
+        Push, PushLiteral, Skip, Str,
     };
     #[doc = r" Repeat arbitrary times."]
     pub type Rep<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::Rep<T, Skipped<S>, SKIP>;
+        predefined_node::Rep<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least once."]
     pub type RepOnce<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::RepOnce<T, Skipped<S>, SKIP>;
+        predefined_node::RepOnce<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least `MIN` times."]
     pub type RepMin<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
-    > = predefined_node::RepMin<T, Skipped<S>, SKIP, MIN>;
+    > = predefined_node::RepMin<S, T, Skipped<S>, SKIP, MIN>;
     #[doc = r" Repeat at most `MAX` times."]
     pub type RepMax<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMax<T, Skipped<S>, SKIP, MAX>;
+    > = predefined_node::RepMax<S, T, Skipped<S>, SKIP, MAX>;
     #[doc = r" Repeat between `MIN` and `MAX` times."]
     pub type RepMinMax<
         T,
@@ -1945,14 +2987,14 @@ pub mod generics {
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMinMax<T, Skipped<S>, SKIP, MIN, MAX>;
+    > = predefined_node::RepMinMax<S, T, Skipped<S>, SKIP, MIN, MAX>;
     #[doc = r" Repeat exactly `TIMES` times."]
     pub type RepExact<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const TIMES: ::core::primitive::usize,
-    > = predefined_node::RepExact<T, Skipped<S>, SKIP, TIMES>;
+    > = predefined_node::RepExact<S, T, Skipped<S>, SKIP, TIMES>;
 }
 #[doc = "Re-export some types from rules to simplify the usage."]
 pub use rules as pairs;
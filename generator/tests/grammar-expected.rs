@@ -247,13 +247,46 @@ mod constant_wrappers {
 pub mod rules_impl {
     #[doc = "Definitions of statically typed nodes generated by pest-generator."]
     pub mod rules {
-        :: pest_typed :: rule ! (pub r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        :: pest_typed :: rule ! (pub r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#string<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#string<S, INHERITED> {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#insensitive<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#insensitive<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Insens<S, super::super::constant_wrappers::r#w_1>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: generics :: CharRange :: < S , '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#range<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#ident , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#range<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::CharRange<S, '0', '9'>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ident , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#ident<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -269,7 +302,16 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#ident<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#string<S, INHERITED>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pos_pred<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -288,11 +330,57 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pos_pred<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Positive<
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#neg_pred<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: generics :: Negative :: < super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < S , INHERITED > > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#neg_pred<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Negative<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: generics :: Negative :: < S , super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#double_neg_pred<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#double_neg_pred<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Negative<
+                    S,
+                    super::super::generics::Negative<
+                        S,
+                        super::super::rules::r#string<S, INHERITED>,
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -325,7 +413,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#sequence<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, 1>,
+                item_1: super::super::rules::r#string<S, 1>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_compound<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -358,13 +461,63 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_compound<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, 0>,
+                item_1: super::super::rules::r#string<S, 0>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules :: r#sequence :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules :: r#sequence :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_non_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules :: r#sequence_compound :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_non_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules :: r#sequence_compound :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_atomic_compound<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_atomic_compound<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_nested<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -397,7 +550,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules :: r#sequence_nested :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_nested<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules :: r#sequence_nested :: < S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#sequence_nested , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#sequence_compound_nested<S, INHERITED> {
             #[doc = "A helper function to access [`sequence_nested`]."]
             #[allow(non_snake_case)]
@@ -415,7 +585,18 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#node_tag , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#sequence_compound_nested<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#sequence_nested<S, 0>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#node_tag , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#node_tag<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -431,7 +612,16 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#node_tag<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#string<S, INHERITED>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#choice<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -472,7 +662,21 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#choice<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#string<S, INHERITED>,
+                    super::super::rules::r#range<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: rules :: r#range :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range , super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#choice_prefix<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -513,7 +717,29 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#optional , "Corresponds to expression: `string?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#optional , :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#choice_prefix<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#string<S, INHERITED>,
+                    super::super::rules::r#range<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#optional , "Corresponds to expression: `string?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#optional , super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#optional,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#optional<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -530,12 +756,32 @@ pub mod rules_impl {
             {
                 let res = &*self.content;
                 {
-                    let res = res.as_ref().map(|res| res);
+                    let res = res.content.as_ref().map(|res| res);
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat , "Corresponds to expression: `string*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#optional<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat , "Corresponds to expression: `string*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#repeat,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -564,9 +810,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#repeat<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Rep<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_atomic,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            true
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_once , "Corresponds to expression: `(string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_once , "Corresponds to expression: `(string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_once<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -609,9 +887,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_once_atomic , "Corresponds to expression: `(string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_once<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#string: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#string, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_once_atomic , "Corresponds to expression: `(string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_once_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_once_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_max<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -643,7 +953,7 @@ pub mod rules_impl {
                         {
                             let res = &res.content.2.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -652,9 +962,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_max<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+                item_2: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_max_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_max_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_exact<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -687,7 +1029,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_exact<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -735,9 +1094,48 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#string<S, INHERITED>,
+                item_1: super::super::rules::r#string<S, INHERITED>,
+                item_2: super::super::generics::Rep<
+                    super::super::rules::r#string<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#string :: < S , 0 > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_min_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_min_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_max,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_max<S, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
@@ -760,14 +1158,14 @@ pub mod rules_impl {
                         {
                             let res = &res.content.0.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -776,9 +1174,49 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_max<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#string<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#string :: < S , 0 > > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#repeat_max_atomic,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            true
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_max_atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_max_atomic<S, INHERITED>
+        {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#string]);
         impl<S, const INHERITED: ::core::primitive::usize> r#soi_at_start<S, INHERITED> {
             #[doc = "A helper function to access [`SOI`]."]
             #[allow(non_snake_case)]
@@ -789,7 +1227,7 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
+            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI<S> {
                 let res = &*self.content;
                 {
                     let res = &res.content.0.matched;
@@ -813,7 +1251,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#soi_at_start<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#SOI: super::super::rules::r#SOI<S>,
+                r#string: super::super::rules::r#string<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#SOI, r#string)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < S , 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -851,7 +1306,43 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_mutate_stack<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::CharRange<S, 'a', 'c'>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_2>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    S,
+                    INHERITED,
+                >,
+                item_1: super::super::rules::r#POP<S>,
+                item_2: super::super::rules::r#POP<S>,
+                item_3: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < S , 'a' , 'c' > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack_pop_all<S, INHERITED> {
             #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
@@ -870,7 +1361,41 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#repeat_mutate_stack_pop_all<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::CharRange<S, 'a', 'c'>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_3>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    S,
+                    INHERITED,
+                >,
+                r#POP_ALL: super::super::rules::r#POP_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, r#POP_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#repeat_mutate_stack_pop_all]);
         impl<S, const INHERITED: ::core::primitive::usize> r#will_fail<S, INHERITED> {
             #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
             #[allow(non_snake_case)]
@@ -891,7 +1416,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < S , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#will_fail<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#repeat_mutate_stack_pop_all: super::super::rules::r#repeat_mutate_stack_pop_all<
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((r#repeat_mutate_stack_pop_all , < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_4 > as :: core :: default :: Default > :: default () ,)))
+            }
+        }
+        :: pest_typed :: rule ! (pub r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < S , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: Rule :: r#will_fail]);
         impl<S, const INHERITED: ::core::primitive::usize> r#stack_resume_after_fail<S, INHERITED> {
             #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
             #[allow(non_snake_case)]
@@ -933,7 +1473,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#stack_resume_after_fail<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::rules::r#will_fail<S, INHERITED>,
+                    super::super::rules::r#repeat_mutate_stack_pop_all<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_<S, INHERITED> {
             #[doc = "A helper function to access [`PEEK`]."]
             #[allow(non_snake_case)]
@@ -1002,7 +1558,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#peek_<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::rules::r#PEEK<S>,
+                item_3: super::super::rules::r#PEEK<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_all<S, INHERITED> {
             #[doc = "A helper function to access [`PEEK_ALL`]."]
             #[allow(non_snake_case)]
@@ -1057,7 +1630,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: generics :: Seq6 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < 1i32 , - 2i32 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#peek_all<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                r#PEEK_ALL: super::super::rules::r#PEEK_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#PEEK_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: generics :: Seq6 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < S , 1i32 , - 2i32 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#peek_slice_23<S, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -1120,7 +1709,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#peek_slice_23<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_3: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_4: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_5: super::super::generics::PeekSlice2<S, 1i32, -2i32>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3, item_4, item_5)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1189,7 +1799,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_2: super::super::rules::r#POP<S>,
+                item_3: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_all<S, INHERITED> {
             #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
@@ -1244,7 +1871,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#POP :: < S > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_all<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                r#POP_ALL: super::super::rules::r#POP_ALL<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#POP_ALL)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#POP :: < S > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#range]);
         impl<S, const INHERITED: ::core::primitive::usize> r#pop_fail<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1296,7 +1939,24 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_9 > , super :: super :: generics :: Skipped < S > , 0 >) , > , > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#pop_fail<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<super::super::rules::r#range<S, INHERITED>>,
+                item_1: super::super::generics::Negative<S, super::super::rules::r#POP<S>>,
+                r#range: super::super::rules::r#range<S, INHERITED>,
+                r#POP: super::super::rules::r#POP<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#range, r#POP)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_6 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_9 > , super :: super :: generics :: Skipped < S > , 0 >) , > , > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true , [super :: super :: Rule :: r#EOI]);
         impl<S, const INHERITED: ::core::primitive::usize> r#checkpoint_restore<S, INHERITED> {
             #[doc = "A helper function to access [`DROP`]."]
             #[allow(non_snake_case)]
@@ -1309,7 +1969,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#DROP<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP> {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP<S>> {
                 let res = &*self.content;
                 {
                     let res = &res.content.1.matched;
@@ -1378,7 +2038,74 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_digits , "Corresponds to expression: `(ASCII_DIGIT ~ ASCII_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_DIGIT , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#checkpoint_restore<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Push<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_5>,
+                >,
+                item_1: super::super::generics::Choice3<
+                    super::super::generics::Seq3<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::generics::Str<
+                                    S,
+                                    super::super::constant_wrappers::r#w_6,
+                                >,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_7>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#POP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#DROP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_8>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#POP<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_9>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                >,
+                r#EOI: super::super::rules::r#EOI<S>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, r#EOI)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_digits , "Corresponds to expression: `(ASCII_DIGIT ~ ASCII_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_DIGIT :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1392,8 +2119,8 @@ pub mod rules_impl {
             pub fn r#ASCII_DIGIT<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT>,
+                &'s super::super::rules::r#ASCII_DIGIT<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1421,7 +2148,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_nonzero_digits , "Corresponds to expression: `(ASCII_NONZERO_DIGIT ~ ASCII_NONZERO_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_NONZERO_DIGIT , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_NONZERO_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_digits<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_DIGIT: super::super::rules::r#ASCII_DIGIT<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_DIGIT, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_nonzero_digits , "Corresponds to expression: `(ASCII_NONZERO_DIGIT ~ ASCII_NONZERO_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_NONZERO_DIGIT :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_NONZERO_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_nonzero_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_NONZERO_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1435,8 +2183,8 @@ pub mod rules_impl {
             pub fn r#ASCII_NONZERO_DIGIT<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_NONZERO_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT>,
+                &'s super::super::rules::r#ASCII_NONZERO_DIGIT<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1464,7 +2212,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_bin_digits , "Corresponds to expression: `(ASCII_BIN_DIGIT ~ ASCII_BIN_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_BIN_DIGIT , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_BIN_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_nonzero_digits<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_NONZERO_DIGIT: super::super::rules::r#ASCII_NONZERO_DIGIT<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_NONZERO_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_NONZERO_DIGIT, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_bin_digits , "Corresponds to expression: `(ASCII_BIN_DIGIT ~ ASCII_BIN_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_BIN_DIGIT :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_BIN_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_bin_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_BIN_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1478,8 +2247,8 @@ pub mod rules_impl {
             pub fn r#ASCII_BIN_DIGIT<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_BIN_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT>,
+                &'s super::super::rules::r#ASCII_BIN_DIGIT<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1507,7 +2276,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_oct_digits , "Corresponds to expression: `(ASCII_OCT_DIGIT ~ ASCII_OCT_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_OCT_DIGIT , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_OCT_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_bin_digits<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_BIN_DIGIT: super::super::rules::r#ASCII_BIN_DIGIT<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_BIN_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_BIN_DIGIT, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_oct_digits , "Corresponds to expression: `(ASCII_OCT_DIGIT ~ ASCII_OCT_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_OCT_DIGIT :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_OCT_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_oct_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_OCT_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1521,8 +2311,8 @@ pub mod rules_impl {
             pub fn r#ASCII_OCT_DIGIT<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_OCT_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT>,
+                &'s super::super::rules::r#ASCII_OCT_DIGIT<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1550,7 +2340,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_hex_digits , "Corresponds to expression: `(ASCII_HEX_DIGIT ~ ASCII_HEX_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_HEX_DIGIT , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_HEX_DIGIT , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_oct_digits<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_OCT_DIGIT: super::super::rules::r#ASCII_OCT_DIGIT<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_OCT_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_OCT_DIGIT, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_hex_digits , "Corresponds to expression: `(ASCII_HEX_DIGIT ~ ASCII_HEX_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_HEX_DIGIT :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_HEX_DIGIT :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_hex_digits<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_HEX_DIGIT`]."]
             #[allow(non_snake_case)]
@@ -1564,8 +2375,8 @@ pub mod rules_impl {
             pub fn r#ASCII_HEX_DIGIT<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_HEX_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT>,
+                &'s super::super::rules::r#ASCII_HEX_DIGIT<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1593,7 +2404,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alpha_lowers , "Corresponds to expression: `(ASCII_ALPHA_LOWER ~ ASCII_ALPHA_LOWER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_LOWER , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA_LOWER , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_hex_digits<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_HEX_DIGIT: super::super::rules::r#ASCII_HEX_DIGIT<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_HEX_DIGIT<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_HEX_DIGIT, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alpha_lowers , "Corresponds to expression: `(ASCII_ALPHA_LOWER ~ ASCII_ALPHA_LOWER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_LOWER :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA_LOWER :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alpha_lowers<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA_LOWER`]."]
             #[allow(non_snake_case)]
@@ -1607,8 +2439,8 @@ pub mod rules_impl {
             pub fn r#ASCII_ALPHA_LOWER<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA_LOWER,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER>,
+                &'s super::super::rules::r#ASCII_ALPHA_LOWER<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1636,7 +2468,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alpha_uppers , "Corresponds to expression: `(ASCII_ALPHA_UPPER ~ ASCII_ALPHA_UPPER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_UPPER , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA_UPPER , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alpha_lowers<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_ALPHA_LOWER: super::super::rules::r#ASCII_ALPHA_LOWER<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_ALPHA_LOWER<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_ALPHA_LOWER, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alpha_uppers , "Corresponds to expression: `(ASCII_ALPHA_UPPER ~ ASCII_ALPHA_UPPER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_UPPER :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA_UPPER :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alpha_uppers<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA_UPPER`]."]
             #[allow(non_snake_case)]
@@ -1650,8 +2503,8 @@ pub mod rules_impl {
             pub fn r#ASCII_ALPHA_UPPER<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA_UPPER,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER>,
+                &'s super::super::rules::r#ASCII_ALPHA_UPPER<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1679,7 +2532,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alphas , "Corresponds to expression: `(ASCII_ALPHA ~ ASCII_ALPHA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alpha_uppers<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_ALPHA_UPPER: super::super::rules::r#ASCII_ALPHA_UPPER<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_ALPHA_UPPER<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_ALPHA_UPPER, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alphas , "Corresponds to expression: `(ASCII_ALPHA ~ ASCII_ALPHA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHA :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alphas<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHA`]."]
             #[allow(non_snake_case)]
@@ -1693,8 +2567,8 @@ pub mod rules_impl {
             pub fn r#ASCII_ALPHA<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA>,
+                &'s super::super::rules::r#ASCII_ALPHA<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1722,7 +2596,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ascii_alphanumerics , "Corresponds to expression: `(ASCII_ALPHANUMERIC ~ ASCII_ALPHANUMERIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alphas<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_ALPHA: super::super::rules::r#ASCII_ALPHA<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_ALPHA<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_ALPHA, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ascii_alphanumerics , "Corresponds to expression: `(ASCII_ALPHANUMERIC ~ ASCII_ALPHANUMERIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHANUMERIC :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ascii_alphanumerics<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII_ALPHANUMERIC`]."]
             #[allow(non_snake_case)]
@@ -1736,8 +2631,8 @@ pub mod rules_impl {
             pub fn r#ASCII_ALPHANUMERIC<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHANUMERIC,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC>,
+                &'s super::super::rules::r#ASCII_ALPHANUMERIC<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1765,7 +2660,28 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#asciis , "Corresponds to expression: `(ASCII ~ ASCII*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ascii_alphanumerics<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII_ALPHANUMERIC: super::super::rules::r#ASCII_ALPHANUMERIC<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#ASCII_ALPHANUMERIC<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII_ALPHANUMERIC, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#asciis , "Corresponds to expression: `(ASCII ~ ASCII*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ASCII :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#asciis<S, INHERITED> {
             #[doc = "A helper function to access [`ASCII`]."]
             #[allow(non_snake_case)]
@@ -1779,8 +2695,8 @@ pub mod rules_impl {
             pub fn r#ASCII<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII>,
+                &'s super::super::rules::r#ASCII<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1808,7 +2724,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#newline , "Corresponds to expression: `(NEWLINE ~ NEWLINE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#NEWLINE , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#NEWLINE , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#asciis<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ASCII: super::super::rules::r#ASCII<S>,
+                item_1: super::super::generics::Rep<super::super::rules::r#ASCII<S>, S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ASCII, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#newline , "Corresponds to expression: `(NEWLINE ~ NEWLINE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#NEWLINE :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#NEWLINE :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#newline<S, INHERITED> {
             #[doc = "A helper function to access [`NEWLINE`]."]
             #[allow(non_snake_case)]
@@ -1822,8 +2753,8 @@ pub mod rules_impl {
             pub fn r#NEWLINE<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#NEWLINE,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE>,
+                &'s super::super::rules::r#NEWLINE<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1851,7 +2782,26 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#XID_CONTINUE , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#newline<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#NEWLINE: super::super::rules::r#NEWLINE<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#NEWLINE<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#NEWLINE, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#XID_CONTINUE :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#unicode<S, INHERITED> {
             #[doc = "A helper function to access [`XID_CONTINUE`]."]
             #[allow(non_snake_case)]
@@ -1864,7 +2814,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#XID_CONTINUE<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE>
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE<S>>
             {
                 let res = &*self.content;
                 {
@@ -1891,7 +2841,7 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START {
+            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START<S> {
                 let res = &*self.content;
                 {
                     let res = &res.content.0.matched;
@@ -1899,9 +2849,39 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#unicode<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#XID_START: super::super::rules::r#XID_START<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#XID_CONTINUE<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#XID_START, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#SYMBOL<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#han , "Corresponds to expression: `(HAN ~ HAN*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HAN , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HAN , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#SYMBOL<S, INHERITED> {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#han , "Corresponds to expression: `(HAN ~ HAN*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HAN :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HAN :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#han<S, INHERITED> {
             #[doc = "A helper function to access [`HAN`]."]
             #[allow(non_snake_case)]
@@ -1915,8 +2895,8 @@ pub mod rules_impl {
             pub fn r#HAN<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HAN,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN>,
+                &'s super::super::rules::r#HAN<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1944,7 +2924,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#hangul , "Corresponds to expression: `(HANGUL ~ HANGUL*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HANGUL , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HANGUL , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#han<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#HAN: super::super::rules::r#HAN<S>,
+                item_1: super::super::generics::Rep<super::super::rules::r#HAN<S>, S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#HAN, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#hangul , "Corresponds to expression: `(HANGUL ~ HANGUL*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HANGUL :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HANGUL :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#hangul<S, INHERITED> {
             #[doc = "A helper function to access [`HANGUL`]."]
             #[allow(non_snake_case)]
@@ -1958,8 +2953,8 @@ pub mod rules_impl {
             pub fn r#HANGUL<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HANGUL,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL>,
+                &'s super::super::rules::r#HANGUL<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -1987,7 +2982,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#hiragana , "Corresponds to expression: `(HIRAGANA ~ HIRAGANA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HIRAGANA , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HIRAGANA , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#hangul<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#HANGUL: super::super::rules::r#HANGUL<S>,
+                item_1: super::super::generics::Rep<super::super::rules::r#HANGUL<S>, S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#HANGUL, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#hiragana , "Corresponds to expression: `(HIRAGANA ~ HIRAGANA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HIRAGANA :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#HIRAGANA :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#hiragana<S, INHERITED> {
             #[doc = "A helper function to access [`HIRAGANA`]."]
             #[allow(non_snake_case)]
@@ -2001,8 +3011,8 @@ pub mod rules_impl {
             pub fn r#HIRAGANA<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HIRAGANA,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA>,
+                &'s super::super::rules::r#HIRAGANA<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -2030,7 +3040,26 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#arabic , "Corresponds to expression: `(ARABIC ~ ARABIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ARABIC , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ARABIC , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#hiragana<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#HIRAGANA: super::super::rules::r#HIRAGANA<S>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#HIRAGANA<S>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#HIRAGANA, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#arabic , "Corresponds to expression: `(ARABIC ~ ARABIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ARABIC :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ARABIC :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#arabic<S, INHERITED> {
             #[doc = "A helper function to access [`ARABIC`]."]
             #[allow(non_snake_case)]
@@ -2044,8 +3073,8 @@ pub mod rules_impl {
             pub fn r#ARABIC<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ARABIC,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC>,
+                &'s super::super::rules::r#ARABIC<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -2073,7 +3102,22 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#emoji , "Corresponds to expression: `(EMOJI ~ EMOJI*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EMOJI , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#EMOJI , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#arabic<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#ARABIC: super::super::rules::r#ARABIC<S>,
+                item_1: super::super::generics::Rep<super::super::rules::r#ARABIC<S>, S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#ARABIC, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#emoji , "Corresponds to expression: `(EMOJI ~ EMOJI*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EMOJI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#EMOJI :: < S > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#emoji<S, INHERITED> {
             #[doc = "A helper function to access [`EMOJI`]."]
             #[allow(non_snake_case)]
@@ -2087,8 +3131,8 @@ pub mod rules_impl {
             pub fn r#EMOJI<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#EMOJI,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI>,
+                &'s super::super::rules::r#EMOJI<S>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI<S>>,
             ) {
                 let res = &*self.content;
                 {
@@ -2116,10 +3160,54 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#emoji<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#EMOJI: super::super::rules::r#EMOJI<S>,
+                item_1: super::super::generics::Rep<super::super::rules::r#EMOJI<S>, S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#EMOJI, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#WHITESPACE<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#COMMENT , "Corresponds to expression: `(\"$\" ~ \"$\"*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_13 > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#WHITESPACE<S, INHERITED>
+        {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#COMMENT , "Corresponds to expression: `(\"$\" ~ \"$\"*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_13 > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#COMMENT<S, INHERITED> {}
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#COMMENT<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_1: super::super::generics::Rep<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_13>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((< super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_12 > as :: core :: default :: Default > :: default () , item_1 ,)))
+            }
+        }
         #[allow(unused_imports)]
         use super::super::unicode::*;
         :: pest_typed :: rule_eoi ! (pub EOI , super :: super :: Rule);
@@ -2148,6 +3236,7 @@ pub mod generics {
     use ::pest_typed::predefined_node;
     #[doc = r" Skipped content."]
     pub type Skipped<S> = predefined_node::AtomicRepeat<
+        S,
         ::pest_typed::choices::Choice2<super::rules::WHITESPACE<S, 0>, super::rules::COMMENT<S, 0>>,
     >;
     pub use pest_typed::choices::Choice2;
@@ -2157,28 +3246,29 @@ pub mod generics {
     pub use pest_typed::sequence::Seq4;
     pub use pest_typed::sequence::Seq6;
     pub use predefined_node::{
-        CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, Skip, Str,
+        CharRange, CharSet, Insens, Lazy, Negative, Optional, PeekSlice1, PeekSlice2, Positive,
+        Push, PushLiteral, Skip, Str,
     };
     #[doc = r" Repeat arbitrary times."]
     pub type Rep<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::Rep<T, Skipped<S>, SKIP>;
+        predefined_node::Rep<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least once."]
     pub type RepOnce<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::RepOnce<T, Skipped<S>, SKIP>;
+        predefined_node::RepOnce<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least `MIN` times."]
     pub type RepMin<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
-    > = predefined_node::RepMin<T, Skipped<S>, SKIP, MIN>;
+    > = predefined_node::RepMin<S, T, Skipped<S>, SKIP, MIN>;
     #[doc = r" Repeat at most `MAX` times."]
     pub type RepMax<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMax<T, Skipped<S>, SKIP, MAX>;
+    > = predefined_node::RepMax<S, T, Skipped<S>, SKIP, MAX>;
     #[doc = r" Repeat between `MIN` and `MAX` times."]
     pub type RepMinMax<
         T,
@@ -2186,14 +3276,14 @@ pub mod generics {
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMinMax<T, Skipped<S>, SKIP, MIN, MAX>;
+    > = predefined_node::RepMinMax<S, T, Skipped<S>, SKIP, MIN, MAX>;
     #[doc = r" Repeat exactly `TIMES` times."]
     pub type RepExact<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const TIMES: ::core::primitive::usize,
-    > = predefined_node::RepExact<T, Skipped<S>, SKIP, TIMES>;
+    > = predefined_node::RepExact<S, T, Skipped<S>, SKIP, TIMES>;
 }
 #[doc = "Re-export some types from rules to simplify the usage."]
 pub use rules as pairs;
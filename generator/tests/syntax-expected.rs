@@ -235,7 +235,7 @@ mod constant_wrappers {
 pub mod rules_impl {
     #[doc = "Definitions of statically typed nodes generated by pest-generator."]
     pub mod rules {
-        :: pest_typed :: rule ! (pub r#Regular , "Corresponds to expression: `((CharRange ~ CharRange*) ~ \"+\" ~ CharRange ~ CharRange*)`. Normal rule." "" "Regular rule." , super :: super :: Rule , super :: super :: Rule :: r#Regular , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        :: pest_typed :: rule ! (pub r#Regular , "Corresponds to expression: `((CharRange ~ CharRange*) ~ \"+\" ~ CharRange ~ CharRange*)`. Normal rule." "" "Regular rule." , super :: super :: Rule , super :: super :: Rule :: r#Regular , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#CharRange]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Regular<S, INHERITED> {
             #[doc = "A helper function to access [`CharRange`]."]
             #[allow(non_snake_case)]
@@ -310,11 +310,67 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Atomic , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Atomic rule." "" "Atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#Atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: CharRange :: < '0' , '9' > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Regular<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Seq2<
+                    (::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#CharRange<S, INHERITED>,
+                        super::super::generics::Skipped<S>,
+                        INHERITED,
+                    >),
+                    (::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Rep<
+                            super::super::rules::r#CharRange<S, INHERITED>,
+                            S,
+                            INHERITED,
+                        >,
+                        super::super::generics::Skipped<S>,
+                        INHERITED,
+                    >),
+                >,
+                r#CharRange: super::super::rules::r#CharRange<S, INHERITED>,
+                item_3: super::super::generics::Rep<
+                    super::super::rules::r#CharRange<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((item_0 , < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_0 > as :: core :: default :: Default > :: default () , r#CharRange , item_3 ,)))
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Atomic , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Atomic rule." "" "Atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#Atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < S , '0' , '9' > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: CharRange :: < S , '0' , '9' > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Span , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Atomic<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Silent , "Corresponds to expression: `(\"(\" | \")\")`. Normal rule." "" "Silent rule." , super :: super :: Rule , super :: super :: Rule :: r#Silent , super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Atomic<S, INHERITED> {
+            #[doc = r" Create from the span of its text, such as one from [`Builder::span`](pest_typed::builder::Builder::span)."]
+            pub fn from_children(span: ::pest_typed::Span<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(span)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Silent , "Corresponds to expression: `(\"(\" | \")\")`. Normal rule." "" "Silent rule." , super :: super :: Rule , super :: super :: Rule :: r#Silent , super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_2 > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Expression , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Silent<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#CompoundAtomic , "Corresponds to expression: `(\"\\\"\" ~ (!\"\\\"\" ~ ANY)* ~ \"\\\"\")`. Atomic rule." "" "Compound atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#CompoundAtomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ANY , super :: super :: generics :: Skipped < S > , 0 >) , > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Silent<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice2<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_1>,
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_2>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#CompoundAtomic , "Corresponds to expression: `(\"\\\"\" ~ (!\"\\\"\" ~ ANY)* ~ \"\\\"\")`. Atomic rule." "" "Compound atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#CompoundAtomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_4 > > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ANY :: < S > , super :: super :: generics :: Skipped < S > , 0 >) , > , S , 0 > , super :: super :: generics :: Skipped < S > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < S > , 0 >) , > , super :: super :: generics :: Skipped :: < S > , true , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#CompoundAtomic<S, INHERITED> {
             #[doc = "A helper function to access [`ANY`]."]
             #[allow(non_snake_case)]
@@ -327,7 +383,7 @@ pub mod rules_impl {
             #[allow(clippy::use_self)]
             pub fn r#ANY<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ANY> {
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ANY<S>> {
                 let res = &*self.content;
                 {
                     let res = &res.content.1.matched;
@@ -348,7 +404,42 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Tag , "Corresponds to expression: `(Any ~ (PUSH(CompoundAtomic) ~ Any ~ &Silent ~ !Atomic)? ~ Any ~ (NonAtomic* ~ Regular ~ Regular*)?)`. Normal rule." "" "Tagged rule." , super :: super :: Rule , super :: super :: Rule :: r#Tag , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#CompoundAtomic :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: rules :: r#Silent :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Atomic :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#NonAtomic :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Regular :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#Regular :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#CompoundAtomic<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_1: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Negative<
+                                S,
+                                super::super::generics::Str<
+                                    S,
+                                    super::super::constant_wrappers::r#w_4,
+                                >,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#ANY<S>,
+                            super::super::generics::Skipped<S>,
+                            0,
+                        >),
+                    >,
+                    S,
+                    0,
+                >,
+            ) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((< super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_3 > as :: core :: default :: Default > :: default () , item_1 , < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_5 > as :: core :: default :: Default > :: default () ,)))
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Tag , "Corresponds to expression: `(Any ~ (PUSH(CompoundAtomic) ~ Any ~ &Silent ~ !Atomic)? ~ Any ~ (NonAtomic* ~ Regular ~ Regular*)?)`. Normal rule." "" "Tagged rule." , super :: super :: Rule , super :: super :: Rule :: r#Tag , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#CompoundAtomic :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: rules :: r#Silent :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#Atomic :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#NonAtomic :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Regular :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#Regular :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#Any , super :: super :: Rule :: r#Atomic , super :: super :: Rule :: r#CaseInsensitive , super :: super :: Rule :: r#CharRange , super :: super :: Rule :: r#CompoundAtomic , super :: super :: Rule :: r#NonAtomic , super :: super :: Rule :: r#Regular , super :: super :: Rule :: r#Seq , super :: super :: Rule :: r#Tag]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Tag<S, INHERITED> {
             #[doc = "A helper function to access [`Any`]."]
             #[allow(non_snake_case)]
@@ -376,7 +467,7 @@ pub mod rules_impl {
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| {
+                                let res = res.content.as_ref().map(|res| {
                                     let res = &res.content.1.matched;
                                     res
                                 });
@@ -409,7 +500,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.1.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = &res.content.0.matched;
                             {
                                 let res = &res.content;
@@ -438,7 +529,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.3.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = &res.content.0.matched;
                             {
                                 let res = res
@@ -475,7 +566,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.3.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = (
                                 {
                                     let res = &res.content.1.matched;
@@ -519,7 +610,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.1.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = &res.content.2.matched;
                             {
                                 let res = &res.content;
@@ -531,7 +622,82 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#NonAtomic , "Corresponds to expression: `((CaseInsensitive? ~ (CharRange ~ CharRange*) ~ Tag*)? ~ (\"b\" | (\"BB\" ~ \"b\"?))? ~ ^\"c\"* ~ (!Seq ~ Any) ~ (!Seq ~ Any)*)`. Non-atomic rule." "" "Non-atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#NonAtomic , super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#CaseInsensitive :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , 1 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#Tag :: < S , 1 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > > , super :: super :: generics :: Skipped < S > , 1 >) , > , > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_9 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Tag<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#Any<S, INHERITED>,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::generics::Seq4<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Push<
+                                super::super::rules::r#CompoundAtomic<S, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Any<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Positive<
+                                super::super::rules::r#Silent<S, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Negative<
+                                S,
+                                super::super::rules::r#Atomic<S, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                >,
+                item_2: super::super::rules::r#Any<S, INHERITED>,
+                item_3: super::super::generics::Optional<
+                    S,
+                    super::super::generics::Seq3<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                super::super::rules::r#NonAtomic<S, INHERITED>,
+                                S,
+                                INHERITED,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Regular<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                super::super::rules::r#Regular<S, INHERITED>,
+                                S,
+                                INHERITED,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#NonAtomic , "Corresponds to expression: `((CaseInsensitive? ~ (CharRange ~ CharRange*) ~ Tag*)? ~ (\"b\" | (\"BB\" ~ \"b\"?))? ~ ^\"c\"* ~ (!Seq ~ Any) ~ (!Seq ~ Any)*)`. Non-atomic rule." "" "Non-atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#NonAtomic , super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#CaseInsensitive :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#CharRange :: < S , 1 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#Tag :: < S , 1 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_6 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_8 > > , super :: super :: generics :: Skipped < S > , 1 >) , > , > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_9 > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#Seq :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < S , super :: super :: rules :: r#Seq :: < S , 1 > > , super :: super :: generics :: Skipped < S > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , S , 1 > , super :: super :: generics :: Skipped < S > , 1 >) , > , super :: super :: generics :: Skipped :: < S > , false , Both , true , [super :: super :: Rule :: r#Any , super :: super :: Rule :: r#Atomic , super :: super :: Rule :: r#CaseInsensitive , super :: super :: Rule :: r#CharRange , super :: super :: Rule :: r#CompoundAtomic , super :: super :: Rule :: r#NonAtomic , super :: super :: Rule :: r#Regular , super :: super :: Rule :: r#Seq , super :: super :: Rule :: r#Tag]);
         impl<S, const INHERITED: ::core::primitive::usize> r#NonAtomic<S, INHERITED> {
             #[doc = "A helper function to access [`Any`]."]
             #[allow(non_snake_case)]
@@ -597,11 +763,12 @@ pub mod rules_impl {
                     let res = &res.content.0.matched;
                     {
                         let res = res
+                            .content
                             .as_ref()
                             .map(|res| {
                                 let res = &res.content.0.matched;
                                 {
-                                    let res = res.as_ref().map(|res| res);
+                                    let res = res.content.as_ref().map(|res| res);
                                     res
                                 }
                             })
@@ -629,7 +796,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.0.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = &res.content.1.matched;
                             {
                                 let res = (
@@ -677,7 +844,7 @@ pub mod rules_impl {
                 {
                     let res = &res.content.0.matched;
                     {
-                        let res = res.as_ref().map(|res| {
+                        let res = res.content.as_ref().map(|res| {
                             let res = &res.content.2.matched;
                             {
                                 let res = res
@@ -696,13 +863,159 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#ExactString , "Corresponds to expression: `\"r#\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ExactString , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#NonAtomic<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Optional<
+                    S,
+                    super::super::generics::Seq3<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Optional<
+                                S,
+                                super::super::rules::r#CaseInsensitive<S, 1>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            1,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#CharRange<S, 1>,
+                                    super::super::generics::Skipped<S>,
+                                    1,
+                                >),
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        super::super::rules::r#CharRange<S, 1>,
+                                        S,
+                                        1,
+                                    >,
+                                    super::super::generics::Skipped<S>,
+                                    1,
+                                >),
+                            >,
+                            super::super::generics::Skipped<S>,
+                            1,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<super::super::rules::r#Tag<S, 1>, S, 1>,
+                            super::super::generics::Skipped<S>,
+                            1,
+                        >),
+                    >,
+                >,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::generics::Choice2<
+                        super::super::generics::Str<S, super::super::constant_wrappers::r#w_6>,
+                        super::super::generics::Seq2<
+                            (::pest_typed::predefined_node::Skipped<
+                                super::super::generics::Str<
+                                    S,
+                                    super::super::constant_wrappers::r#w_7,
+                                >,
+                                super::super::generics::Skipped<S>,
+                                1,
+                            >),
+                            (::pest_typed::predefined_node::Skipped<
+                                super::super::generics::Optional<
+                                    S,
+                                    super::super::generics::Str<
+                                        S,
+                                        super::super::constant_wrappers::r#w_8,
+                                    >,
+                                >,
+                                super::super::generics::Skipped<S>,
+                                1,
+                            >),
+                        >,
+                    >,
+                >,
+                item_2: super::super::generics::Rep<
+                    super::super::generics::Insens<S, super::super::constant_wrappers::r#w_9>,
+                    S,
+                    1,
+                >,
+                item_3: super::super::generics::Seq2<
+                    (::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Negative<S, super::super::rules::r#Seq<S, 1>>,
+                        super::super::generics::Skipped<S>,
+                        1,
+                    >),
+                    (::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<S, 1>,
+                        super::super::generics::Skipped<S>,
+                        1,
+                    >),
+                >,
+                item_4: super::super::generics::Rep<
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Negative<S, super::super::rules::r#Seq<S, 1>>,
+                            super::super::generics::Skipped<S>,
+                            1,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Any<S, 1>,
+                            super::super::generics::Skipped<S>,
+                            1,
+                        >),
+                    >,
+                    S,
+                    1,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2, item_3, item_4)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#ExactString , "Corresponds to expression: `\"r#\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ExactString , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#ExactString<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#CaseInsensitive , "Corresponds to expression: `^\"0x\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CaseInsensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#ExactString<S, INHERITED>
+        {
+            #[doc = r" Create with the string literal, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children() -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::default::Default::default(),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#CaseInsensitive , "Corresponds to expression: `^\"0x\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CaseInsensitive , super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#CaseInsensitive<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#CharRange , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CharRange , super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#CaseInsensitive<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Insens<S, super::super::constant_wrappers::r#w_11>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#CharRange , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CharRange , super :: super :: generics :: CharRange :: < S , '0' , '9' > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#CharRange<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Any , "Corresponds to expression: `ANY`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Any , super :: super :: rules :: r#ANY , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#CharRange<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::CharRange<S, '0', '9'>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Any , "Corresponds to expression: `ANY`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Any , super :: super :: rules :: r#ANY :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Any<S, INHERITED> {
             #[doc = "A helper function to access [`ANY`]."]
             #[allow(non_snake_case)]
@@ -713,14 +1026,33 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#ANY<'s>(&'s self) -> &'s super::super::rules::r#ANY {
+            pub fn r#ANY<'s>(&'s self) -> &'s super::super::rules::r#ANY<S> {
                 let res = &*self.content;
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#Seq , "Corresponds to expression: `(\"1\" ~ ('2'..'9') ~ \".\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Seq , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '2' , '9' > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_13 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Any<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#ANY<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Seq , "Corresponds to expression: `(\"1\" ~ ('2'..'9') ~ \".\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Seq , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < S , '2' , '9' > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_13 > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Seq<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Choice , "Corresponds to expression: `(\"a\" | ((^\"b\" ~ ^\"b\"*) ~ RepAtLeastOnce) | (\"c\" ~ &\"d\" ~ Choice ~ Rep ~ Opt) | Peek | PeekLeft | PeekRight | PeekLeftRight | Drop | PeekAll)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Choice , super :: super :: generics :: Choice9 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_14 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_15 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_16 > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_17 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_18 > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Choice :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Rep :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Opt :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: rules :: r#Peek :: < S , INHERITED > , super :: super :: rules :: r#PeekLeft :: < S , INHERITED > , super :: super :: rules :: r#PeekRight :: < S , INHERITED > , super :: super :: rules :: r#PeekLeftRight :: < S , INHERITED > , super :: super :: rules :: r#Drop :: < S , INHERITED > , super :: super :: rules :: r#PeekAll :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Seq<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(item_1: super::super::generics::CharRange<S, '2', '9'>) -> Self {
+                < Self as :: pest_typed :: builder :: BuildRule < S >> :: from_content (:: core :: convert :: From :: from ((< super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_12 > as :: core :: default :: Default > :: default () , item_1 , < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_13 > as :: core :: default :: Default > :: default () ,)))
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Choice , "Corresponds to expression: `(\"a\" | ((^\"b\" ~ ^\"b\"*) ~ RepAtLeastOnce) | (\"c\" ~ &\"d\" ~ Choice ~ Rep ~ Opt) | Peek | PeekLeft | PeekRight | PeekLeftRight | Drop | PeekAll)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Choice , super :: super :: generics :: Choice9 :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_14 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_15 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_16 > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_17 > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_18 > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Choice :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Rep :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Opt :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: rules :: r#Peek :: < S , INHERITED > , super :: super :: rules :: r#PeekLeft :: < S , INHERITED > , super :: super :: rules :: r#PeekRight :: < S , INHERITED > , super :: super :: rules :: r#PeekLeftRight :: < S , INHERITED > , super :: super :: rules :: r#Drop :: < S , INHERITED > , super :: super :: rules :: r#PeekAll :: < S , INHERITED > , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#Choice , super :: super :: Rule :: r#Drop , super :: super :: Rule :: r#Opt , super :: super :: Rule :: r#Peek , super :: super :: Rule :: r#PeekAll , super :: super :: Rule :: r#PeekLeft , super :: super :: Rule :: r#PeekLeftRight , super :: super :: Rule :: r#PeekRight , super :: super :: Rule :: r#Rep , super :: super :: Rule :: r#RepAtLeastOnce]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Choice<S, INHERITED> {
             #[doc = "A helper function to access [`Choice`]."]
             #[allow(non_snake_case)]
@@ -927,13 +1259,159 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Rep , "Corresponds to expression: `^\"b\"*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Rep , super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_19 > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Choice<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Choice9<
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_14>,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Insens<
+                                        S,
+                                        super::super::constant_wrappers::r#w_15,
+                                    >,
+                                    super::super::generics::Skipped<S>,
+                                    INHERITED,
+                                >),
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        super::super::generics::Insens<
+                                            S,
+                                            super::super::constant_wrappers::r#w_16,
+                                        >,
+                                        S,
+                                        INHERITED,
+                                    >,
+                                    super::super::generics::Skipped<S>,
+                                    INHERITED,
+                                >),
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepAtLeastOnce<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    super::super::generics::Seq5<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Str<S, super::super::constant_wrappers::r#w_17>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Positive<
+                                super::super::generics::Str<
+                                    S,
+                                    super::super::constant_wrappers::r#w_18,
+                                >,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Choice<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Rep<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Opt<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                    super::super::rules::r#Peek<S, INHERITED>,
+                    super::super::rules::r#PeekLeft<S, INHERITED>,
+                    super::super::rules::r#PeekRight<S, INHERITED>,
+                    super::super::rules::r#PeekLeftRight<S, INHERITED>,
+                    super::super::rules::r#Drop<S, INHERITED>,
+                    super::super::rules::r#PeekAll<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Rep , "Corresponds to expression: `^\"b\"*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Rep , super :: super :: generics :: Rep :: < super :: super :: generics :: Insens :: < S , super :: super :: constant_wrappers :: r#w_19 > , S , INHERITED > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#Rep,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#Rep<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#RepAtLeastOnce , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: CharRange :: < '0' , '9' > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Rep<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Rep<
+                    super::super::generics::Insens<S, super::super::constant_wrappers::r#w_19>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#RepAtLeastOnce , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < S , '0' , '9' > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: generics :: CharRange :: < S , '0' , '9' > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#RepAtLeastOnce<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Opt , "Corresponds to expression: `\"?\"?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Opt , :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_20 > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#RepAtLeastOnce<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::CharRange<S, '0', '9'>,
+                item_1: super::super::generics::Rep<
+                    super::super::generics::CharRange<S, '0', '9'>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Opt , "Corresponds to expression: `\"?\"?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Opt , super :: super :: generics :: Optional :: < S , super :: super :: generics :: Str :: < S , super :: super :: constant_wrappers :: r#w_20 > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
+        ::pest_typed::impl_never_failed!(
+            r#Opt,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#Opt<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#RepExact , "Corresponds to expression: `(RepAtLeastOnce ~ RepAtLeastOnce ~ RepAtLeastOnce)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepExact , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Opt<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Optional<
+                    S,
+                    super::super::generics::Str<S, super::super::constant_wrappers::r#w_20>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#RepExact , "Corresponds to expression: `(RepAtLeastOnce ~ RepAtLeastOnce ~ RepAtLeastOnce)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepExact , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#RepAtLeastOnce]);
         impl<S, const INHERITED: ::core::primitive::usize> r#RepExact<S, INHERITED> {
             #[doc = "A helper function to access [`RepAtLeastOnce`]."]
             #[allow(non_snake_case)]
@@ -971,7 +1449,23 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#RepLeft , "Corresponds to expression: `(RepExact ~ RepExact*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeft , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepExact :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#RepExact :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#RepExact<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::rules::r#RepAtLeastOnce<S, INHERITED>,
+                item_1: super::super::rules::r#RepAtLeastOnce<S, INHERITED>,
+                item_2: super::super::rules::r#RepAtLeastOnce<S, INHERITED>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1, item_2)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#RepLeft , "Corresponds to expression: `(RepExact ~ RepExact*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeft , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepExact :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#RepExact :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact]);
         impl<S, const INHERITED: ::core::primitive::usize> r#RepLeft<S, INHERITED> {
             #[doc = "A helper function to access [`RepExact`]."]
             #[allow(non_snake_case)]
@@ -1014,7 +1508,32 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#RepRight , "Corresponds to expression: `(RepLeft? ~ RepLeft?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#RepLeft<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#RepExact: super::super::rules::r#RepExact<S, INHERITED>,
+                item_1: super::super::generics::Rep<
+                    super::super::rules::r#RepExact<S, INHERITED>,
+                    S,
+                    INHERITED,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#RepExact, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#RepRight , "Corresponds to expression: `(RepLeft? ~ RepLeft?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#RepLeft :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#RepLeft :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact , super :: super :: Rule :: r#RepLeft]);
+        ::pest_typed::impl_never_failed!(
+            r#RepRight,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#RepRight<S, INHERITED> {
             #[doc = "A helper function to access [`RepLeft`]."]
             #[allow(non_snake_case)]
@@ -1037,14 +1556,14 @@ pub mod rules_impl {
                         {
                             let res = &res.content.0.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -1053,7 +1572,34 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#RepLeftRight , "Corresponds to expression: `(RepRight ~ RepRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeftRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#RepRight<S, INHERITED> {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                item_0: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#RepLeft<S, INHERITED>,
+                >,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#RepLeft<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((item_0, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#RepLeftRight , "Corresponds to expression: `(RepRight ~ RepRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeftRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#RepRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact , super :: super :: Rule :: r#RepLeft , super :: super :: Rule :: r#RepRight]);
+        ::pest_typed::impl_never_failed!(
+            r#RepLeftRight,
+            super::super::Rule,
+            super::super::generics::Skipped::<S>,
+            INHERITED
+        );
         impl<S, const INHERITED: ::core::primitive::usize> r#RepLeftRight<S, INHERITED> {
             #[doc = "A helper function to access [`RepRight`]."]
             #[allow(non_snake_case)]
@@ -1082,7 +1628,7 @@ pub mod rules_impl {
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res.content.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -1091,7 +1637,27 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Pos , "Corresponds to expression: `&(SOI ~ RepLeftRight ~ RepLeftRight ~ RepLeftRight? ~ RepLeftRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pos , super :: super :: generics :: Positive :: < super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#RepLeftRight<S, INHERITED>
+        {
+            #[doc = r" Create from items that are not string literals, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_children(
+                r#RepRight: super::super::rules::r#RepRight<S, INHERITED>,
+                item_1: super::super::generics::Optional<
+                    S,
+                    super::super::rules::r#RepRight<S, INHERITED>,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(
+                    ::core::convert::From::from((r#RepRight, item_1)),
+                )
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Pos , "Corresponds to expression: `&(SOI ~ RepLeftRight ~ RepLeftRight ~ RepLeftRight? ~ RepLeftRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pos , super :: super :: generics :: Positive :: < super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Optional :: < S , super :: super :: rules :: r#RepLeftRight :: < S , INHERITED > > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact , super :: super :: Rule :: r#RepLeft , super :: super :: Rule :: r#RepLeftRight , super :: super :: Rule :: r#RepRight]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Pos<S, INHERITED> {
             #[doc = "A helper function to access [`RepLeftRight`]."]
             #[allow(non_snake_case)]
@@ -1130,14 +1696,14 @@ pub mod rules_impl {
                             {
                                 let res = &res.content.3.matched;
                                 {
-                                    let res = res.as_ref().map(|res| res);
+                                    let res = res.content.as_ref().map(|res| res);
                                     res
                                 }
                             },
                             {
                                 let res = &res.content.4.matched;
                                 {
-                                    let res = res.as_ref().map(|res| res);
+                                    let res = res.content.as_ref().map(|res| res);
                                     res
                                 }
                             },
@@ -1155,7 +1721,7 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
+            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI<S> {
                 let res = &*self.content;
                 {
                     let res = &res.content;
@@ -1166,9 +1732,79 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Neg , "Corresponds to expression: `!(EOI ~ Pos)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Neg , super :: super :: generics :: Negative :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pos :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Pos<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Positive<
+                    super::super::generics::Seq5<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#SOI<S>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepLeftRight<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepLeftRight<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Optional<
+                                S,
+                                super::super::rules::r#RepLeftRight<S, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Optional<
+                                S,
+                                super::super::rules::r#RepLeftRight<S, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Neg , "Corresponds to expression: `!(EOI ~ Pos)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Neg , super :: super :: generics :: Negative :: < S , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < S > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pos :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#EOI , super :: super :: Rule :: r#Pos , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact , super :: super :: Rule :: r#RepLeft , super :: super :: Rule :: r#RepLeftRight , super :: super :: Rule :: r#RepRight]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Neg<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Push , "Corresponds to expression: `PUSH((RepLeft* ~ Neg ~ (ExactString ~ ExactString*) ~ Push ~ Pop ~ Push ~ PopAll))`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Push , super :: super :: generics :: Push :: < super :: super :: generics :: Seq7 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#RepLeft :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Neg :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ExactString :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ExactString :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pop :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PopAll :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Neg<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Negative<
+                    S,
+                    super::super::generics::Seq2<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#EOI<S>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Pos<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Push , "Corresponds to expression: `PUSH((RepLeft* ~ Neg ~ (ExactString ~ ExactString*) ~ Push ~ Pop ~ Push ~ PopAll))`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Push , super :: super :: generics :: Push :: < super :: super :: generics :: Seq7 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#RepLeft :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Neg :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ExactString :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < super :: super :: rules :: r#ExactString :: < S , INHERITED > , S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pop :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PopAll :: < S , INHERITED > , super :: super :: generics :: Skipped < S > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , [super :: super :: Rule :: r#EOI , super :: super :: Rule :: r#ExactString , super :: super :: Rule :: r#Neg , super :: super :: Rule :: r#Pop , super :: super :: Rule :: r#PopAll , super :: super :: Rule :: r#Pos , super :: super :: Rule :: r#Push , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: Rule :: r#RepExact , super :: super :: Rule :: r#RepLeft , super :: super :: Rule :: r#RepLeftRight , super :: super :: Rule :: r#RepRight]);
         impl<S, const INHERITED: ::core::primitive::usize> r#Push<S, INHERITED> {
             #[doc = "A helper function to access [`ExactString`]."]
             #[allow(non_snake_case)]
@@ -1341,7 +1977,75 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (pub r#Pop , "Corresponds to expression: `POP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pop , super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Push<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::Push<
+                    super::super::generics::Seq7<
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                super::super::rules::r#RepLeft<S, INHERITED>,
+                                S,
+                                INHERITED,
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Neg<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#ExactString<S, INHERITED>,
+                                    super::super::generics::Skipped<S>,
+                                    INHERITED,
+                                >),
+                                (::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        super::super::rules::r#ExactString<S, INHERITED>,
+                                        S,
+                                        INHERITED,
+                                    >,
+                                    super::super::generics::Skipped<S>,
+                                    INHERITED,
+                                >),
+                            >,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Pop<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                        (::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#PopAll<S, INHERITED>,
+                            super::super::generics::Skipped<S>,
+                            INHERITED,
+                        >),
+                    >,
+                >,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Pop , "Corresponds to expression: `POP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pop , super :: super :: rules :: r#POP :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Pop<S, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1357,7 +2061,16 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#PopAll , "Corresponds to expression: `POP_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PopAll , super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Pop<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#POP<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#PopAll , "Corresponds to expression: `POP_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PopAll , super :: super :: rules :: r#POP_ALL :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#PopAll<S, INHERITED> {
             #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
@@ -1373,15 +2086,64 @@ pub mod rules_impl {
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#Peek , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Peek , super :: super :: generics :: PeekSlice1 :: < 0i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#PopAll<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#POP_ALL<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Peek , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Peek , super :: super :: generics :: PeekSlice1 :: < S , 0i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Peek<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#PeekLeft , "Corresponds to expression: `PEEK[1..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeft , super :: super :: generics :: PeekSlice1 :: < 1i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Peek<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::PeekSlice1<S, 0i32>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#PeekLeft , "Corresponds to expression: `PEEK[1..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeft , super :: super :: generics :: PeekSlice1 :: < S , 1i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#PeekLeft<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#PeekRight , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekRight , super :: super :: generics :: PeekSlice1 :: < 0i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#PeekLeft<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::PeekSlice1<S, 1i32>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#PeekRight , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekRight , super :: super :: generics :: PeekSlice1 :: < S , 0i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#PeekRight<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#PeekLeftRight , "Corresponds to expression: `PEEK[1..2]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeftRight , super :: super :: generics :: PeekSlice2 :: < 1i32 , 2i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#PeekRight<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::generics::PeekSlice1<S, 0i32>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#PeekLeftRight , "Corresponds to expression: `PEEK[1..2]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeftRight , super :: super :: generics :: PeekSlice2 :: < S , 1i32 , 2i32 > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#PeekLeftRight<S, INHERITED> {}
-        :: pest_typed :: rule ! (pub r#Drop , "Corresponds to expression: `DROP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Drop , super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize>
+            r#PeekLeftRight<S, INHERITED>
+        {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(
+                content: super::super::generics::PeekSlice2<S, 1i32, 2i32>,
+            ) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#Drop , "Corresponds to expression: `DROP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Drop , super :: super :: rules :: r#DROP :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#Drop<S, INHERITED> {
             #[doc = "A helper function to access [`DROP`]."]
             #[allow(non_snake_case)]
@@ -1392,12 +2154,21 @@ pub mod rules_impl {
             #[allow(clippy::needless_lifetimes)]
             #[allow(clippy::type_complexity)]
             #[allow(clippy::use_self)]
-            pub fn r#DROP<'s>(&'s self) -> &'s super::super::rules::r#DROP {
+            pub fn r#DROP<'s>(&'s self) -> &'s super::super::rules::r#DROP<S> {
                 let res = &*self.content;
                 res
             }
         }
-        :: pest_typed :: rule ! (pub r#PeekAll , "Corresponds to expression: `PEEK_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekAll , super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true);
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#Drop<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#DROP<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
+        :: pest_typed :: rule ! (pub r#PeekAll , "Corresponds to expression: `PEEK_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekAll , super :: super :: rules :: r#PEEK_ALL :: < S > , super :: super :: generics :: Skipped :: < S > , INHERITED , Both , true , []);
         impl<S, const INHERITED: ::core::primitive::usize> r#PeekAll<S, INHERITED> {
             #[doc = "A helper function to access [`PEEK_ALL`]."]
             #[allow(non_snake_case)]
@@ -1413,6 +2184,15 @@ pub mod rules_impl {
                 res
             }
         }
+        #[allow(non_snake_case)]
+        #[allow(clippy::type_complexity)]
+        #[allow(clippy::use_self)]
+        impl<S: ::pest_typed::RefStr, const INHERITED: ::core::primitive::usize> r#PeekAll<S, INHERITED> {
+            #[doc = r" Create from its content, to be built by [`Builder::build`](pest_typed::builder::Builder::build)."]
+            pub fn from_children(content: super::super::rules::r#PEEK_ALL<S>) -> Self {
+                <Self as ::pest_typed::builder::BuildRule<S>>::from_content(content)
+            }
+        }
         #[allow(unused_imports)]
         use super::super::unicode::*;
         :: pest_typed :: rule_eoi ! (pub EOI , super :: super :: Rule);
@@ -1438,28 +2218,29 @@ pub mod generics {
     pub use pest_typed::sequence::Seq5;
     pub use pest_typed::sequence::Seq7;
     pub use predefined_node::{
-        CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, Skip, Str,
+        CharRange, CharSet, Insens, Lazy, Negative, Optional, PeekSlice1, PeekSlice2, Positive,
+        Push, PushLiteral, Skip, Str,
     };
     #[doc = r" Repeat arbitrary times."]
     pub type Rep<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::Rep<T, Skipped<S>, SKIP>;
+        predefined_node::Rep<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least once."]
     pub type RepOnce<T, S, const SKIP: ::core::primitive::usize> =
-        predefined_node::RepOnce<T, Skipped<S>, SKIP>;
+        predefined_node::RepOnce<S, T, Skipped<S>, SKIP>;
     #[doc = r" Repeat at least `MIN` times."]
     pub type RepMin<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
-    > = predefined_node::RepMin<T, Skipped<S>, SKIP, MIN>;
+    > = predefined_node::RepMin<S, T, Skipped<S>, SKIP, MIN>;
     #[doc = r" Repeat at most `MAX` times."]
     pub type RepMax<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMax<T, Skipped<S>, SKIP, MAX>;
+    > = predefined_node::RepMax<S, T, Skipped<S>, SKIP, MAX>;
     #[doc = r" Repeat between `MIN` and `MAX` times."]
     pub type RepMinMax<
         T,
@@ -1467,14 +2248,14 @@ pub mod generics {
         const SKIP: ::core::primitive::usize,
         const MIN: ::core::primitive::usize,
         const MAX: ::core::primitive::usize,
-    > = predefined_node::RepMinMax<T, Skipped<S>, SKIP, MIN, MAX>;
+    > = predefined_node::RepMinMax<S, T, Skipped<S>, SKIP, MIN, MAX>;
     #[doc = r" Repeat exactly `TIMES` times."]
     pub type RepExact<
         T,
         S,
         const SKIP: ::core::primitive::usize,
         const TIMES: ::core::primitive::usize,
-    > = predefined_node::RepExact<T, Skipped<S>, SKIP, TIMES>;
+    > = predefined_node::RepExact<S, T, Skipped<S>, SKIP, TIMES>;
 }
 #[doc = "Re-export some types from rules to simplify the usage."]
 pub use rules as pairs;
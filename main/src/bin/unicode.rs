@@ -9,38 +9,52 @@ fn main() {
         r#"//! Wrapped types for unicode property. See [pest::unicode] for details.
 
 use crate::{
-    iterators::{Pairs, Token},
+    iterators::{PairVisitor, Pairs},
+    leaves::{LeafKind, LeafText},
+    semantic::{SemanticEq, SemanticHash},
     tracker::Tracker,
-    Cursor, RuleType, Span, Stack, TypedNode,
+    Cursor, RefStr, RuleType, Span, Spanned, Stack, TypedNode,
 };
-use core::fmt;
+use core::{fmt, ops::ControlFlow};
+use derive_where::derive_where;
 
 macro_rules! unicode {
     ($property_ident:ident) => {
         #[allow(non_camel_case_types)]
         #[doc = concat!("Auto generated. Unicode property ", stringify!($property_ident))]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-        #[derive(Clone, Hash, PartialEq, Eq)]
-        pub struct $property_ident {
+        #[derive_where(Clone, Hash, PartialEq, Eq; S: RefStr)]
+        pub struct $property_ident<S> {
             /// Matched character.
             ///
             /// Do not trust this field as it may be assigned to after creation.
             pub content: char,
+            /// Matched span.
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip, default = "super::placeholder", bound(deserialize = "S: RefStr"))
+            )]
+            #[derive_where(skip(EqHashOrd))]
+            pub span: Span<S>,
         }
-        impl From<char> for $property_ident {
-            fn from(content: char) -> Self {
-                Self { content }
+        impl<S> From<(char, Span<S>)> for $property_ident<S> {
+            fn from((content, span): (char, Span<S>)) -> Self {
+                Self { content, span }
             }
         }
-        impl<C: Cursor, R: RuleType> TypedNode<C, R> for $property_ident {
+        impl<C: Cursor, R: RuleType> TypedNode<C, R> for $property_ident<C::String> {
             #[inline]
             fn try_parse_partial_with(
                 mut input: C,
                 _stack: &mut Stack<Span<C::String>>,
                 _tracker: &mut Tracker<C::String, R>,
             ) -> Option<(C, Self)> {
+                let start = input.clone();
                 match super::match_char_by(&mut input, pest::unicode::$property_ident) {
-                    Some(content) => Some((input, Self::from(content))),
+                    Some(content) => {
+                        let span = start.span(&input);
+                        Some((input, Self::from((content, span))))
+                    }
                     None => None,
                 }
             }
@@ -56,15 +70,41 @@ macro_rules! unicode {
                 }
             }
         }
-        impl fmt::Debug for $property_ident {
+        impl<S> SemanticEq for $property_ident<S> {
+            #[inline]
+            fn semantic_eq(&self, other: &Self) -> bool {
+                self.content == other.content
+            }
+        }
+        impl<S> SemanticHash for $property_ident<S> {
+            #[inline]
+            fn semantic_hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(&self.content, state)
+            }
+        }
+        impl<S: RefStr, R: RuleType> Spanned<S, R> for $property_ident<S> {
+            #[inline]
+            fn span(&self) -> Span<S> {
+                self.span.clone()
+            }
+        }
+        impl<S: RefStr> fmt::Display for $property_ident<S> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.span.as_str().to_text())
+            }
+        }
+        impl<S> fmt::Debug for $property_ident<S> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.debug_struct(stringify!($property_ident))
                     .field("content", &self.content)
                     .finish()
             }
         }
-        impl<S, R: RuleType> Pairs<S, R> for $property_ident {
-            fn for_self_or_each_child(&self, _f: &mut impl FnMut(Token<S, R>)) {}
+        impl<S: RefStr, R: RuleType> Pairs<S, R> for $property_ident<S> {
+            fn visit_self_or_children<'t>(&'t self, visitor: &mut impl PairVisitor<'t, S, R>) -> ControlFlow<()> {
+                visitor.visit_leaf(LeafKind::Char, LeafText::Char(self.content))
+            }
         }
     };
 }
@@ -0,0 +1,411 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Build typed nodes programmatically, with synthetic spans.
+//!
+//! Rule structs are created from their children by `from_children`, generated for each rule,
+//! with placeholder spans. A [`Builder`] then assembles the text of the root once,
+//! and moves all spans in it to a buffer it owns, so the tree can be printed or traversed
+//! as if it was parsed from that buffer.
+//!
+//! String literals fill in their own texts,
+//! and [`Builder::trivia`] is put wherever trivia may be skipped, that is,
+//! between items of sequences and repetitions in non-atomic rules,
+//! unless the item after it is empty.
+
+use crate::{
+    predefined_node::{
+        AtomicRepeat, CharRange, CharSet, Empty, Insens, Lazy, Negative, NewLineType, Optional,
+        Push, PushLiteral, RepeatMin, RepeatMinMax, Skip, SkipChar, SkipTrivia, Skipped, Str, ANY,
+        DROP, EOI, NEWLINE, PEEK, PEEK_ALL, POP, POP_ALL, SOI,
+    },
+    CharSetWrapper, RefStr, Span, StringArrayWrapper, StringWrapper, TriviaWrapper,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, fmt};
+
+/// Nodes that can be moved to a synthetic input.
+///
+/// Implemented for nodes whose texts can be recovered,
+/// so lookaheads and `PEEK[..]` are not supported.
+pub trait Build<S: RefStr> {
+    /// Append the text of the node to `text`, with `trivia` wherever trivia may be skipped.
+    fn write_text(&self, trivia: &str, text: &mut String);
+    /// Move spans in the node to `input`, in which the node starts at `offset`, and return where it ends.
+    ///
+    /// `input` should contain the text written by [`Build::write_text`] with the same `trivia` at `offset`.
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize;
+    /// [`Build::write_text`] after `trivia`, which is omitted if the node is empty.
+    ///
+    /// Used for items after the first one in sequences and repetitions.
+    fn write_text_after(&self, trivia: &str, text: &mut String) {
+        let len = text.len();
+        text.push_str(trivia);
+        let start = text.len();
+        self.write_text(trivia, text);
+        if text.len() == start {
+            text.truncate(len);
+        }
+    }
+    /// [`Build::rebase`] after `trivia`, consistent with [`Build::write_text_after`].
+    fn rebase_after(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        let start = offset + S::str_len(trivia);
+        match self.rebase(trivia, input, start) {
+            end if end == start => self.rebase(trivia, input, offset),
+            end => end,
+        }
+    }
+}
+
+/// Rule structs that can be created from their contents.
+///
+/// Generated `from_children` constructors call [`BuildRule::from_content`].
+pub trait BuildRule<S: RefStr> {
+    /// Content of the rule, which is the span for rules that only keep spans.
+    type Content;
+    /// Create from `content`, with a placeholder span until [`Build::rebase`].
+    fn from_content(content: Self::Content) -> Self;
+}
+
+/// Build nodes over buffers it owns.
+///
+/// ```
+/// use pest_typed::{builder::Builder, predefined_node::{Skipped, Str}, sequence::Seq2};
+/// # use pest_typed::{predefined_node::SkipTrivia, StringWrapper, TriviaWrapper};
+/// # #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// # struct Hello;
+/// # impl StringWrapper for Hello {
+/// #     const CONTENT: &'static str = "hello";
+/// # }
+/// # #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// # struct World;
+/// # impl StringWrapper for World {
+/// #     const CONTENT: &'static str = "world";
+/// # }
+/// # struct Whitespace;
+/// # impl TriviaWrapper for Whitespace {
+/// #     const TRIVIA: &'static [pest_typed::predefined_node::Trivia] = &[];
+/// # }
+/// type Greeting<'i> = Seq2<
+///     Skipped<Str<&'i str, Hello>, SkipTrivia<&'i str, Whitespace>, 1>,
+///     Skipped<Str<&'i str, World>, SkipTrivia<&'i str, Whitespace>, 1>,
+/// >;
+///
+/// let builder = Builder::new();
+/// let greeting: Greeting<'_> = builder.build((Str::default(), Str::default()).into());
+/// assert_eq!(builder.text(&greeting), "hello world");
+/// ```
+pub struct Builder {
+    trivia: String,
+    buffers: RefCell<Vec<Box<str>>>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::with_trivia(" ")
+    }
+}
+
+impl fmt::Debug for Builder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("trivia", &self.trivia)
+            .finish()
+    }
+}
+
+impl Builder {
+    /// Create a builder that puts a space wherever trivia may be skipped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Create a builder that puts `trivia` wherever trivia may be skipped.
+    ///
+    /// It should match `WHITESPACE` or `COMMENT` for built nodes to be parsed again.
+    pub fn with_trivia(trivia: impl Into<String>) -> Self {
+        Self {
+            trivia: trivia.into(),
+            buffers: RefCell::new(Vec::new()),
+        }
+    }
+    /// Text put wherever trivia may be skipped.
+    pub fn trivia(&self) -> &str {
+        &self.trivia
+    }
+    /// Keep `text` in a buffer until the builder is dropped.
+    fn alloc(&self, text: String) -> &str {
+        let text = text.into_boxed_str();
+        let ptr: *const str = &*text;
+        self.buffers.borrow_mut().push(text);
+        // SAFETY: buffers are never removed or mutated before the builder is dropped,
+        // and moving a box does not move its content.
+        unsafe { &*ptr }
+    }
+    /// A span over `text` in a new buffer.
+    ///
+    /// Used to create rules that only keep spans, such as atomic rules.
+    pub fn span<'b>(&'b self, text: &str) -> Span<&'b str> {
+        Span::new_full(self.alloc(text.to_owned()))
+    }
+    /// Assemble the text of `node` in a new buffer, and move spans in `node` to it.
+    ///
+    /// Build only the root, as the text of each built node is copied to a new buffer.
+    pub fn build<'b, T: Build<&'b str>>(&'b self, mut node: T) -> T {
+        let mut text = String::new();
+        node.write_text(&self.trivia, &mut text);
+        let input = self.alloc(text);
+        node.rebase(&self.trivia, &input, 0);
+        node
+    }
+    /// Text of a node as it would be built.
+    pub fn text<'i>(&self, node: &impl Build<&'i str>) -> String {
+        let mut text = String::new();
+        node.write_text(&self.trivia, &mut text);
+        text
+    }
+}
+
+/// Move `span` to `start..end` in `input`, and return `end`.
+#[inline]
+fn move_span<S: RefStr>(span: &mut Span<S>, input: &S, start: usize, end: usize) -> usize {
+    if let Some(moved) = Span::new(input.clone(), start, end) {
+        *span = moved;
+    }
+    end
+}
+
+/// Write `text`, whose length in `S` is `len`, and move the `span` field over it on rebasing.
+macro_rules! impl_by_text {
+    ($node:ty, |$self:ident| $text:expr, $len:expr $(, $($tt:tt)*)?) => {
+        impl<S: RefStr $(, $($tt)*)?> Build<S> for $node {
+            #[inline]
+            fn write_text(&$self, _trivia: &str, text: &mut String) {
+                text.push_str(&$text);
+            }
+            #[inline]
+            fn rebase(&mut $self, _trivia: &str, input: &S, offset: usize) -> usize {
+                let end = offset + $len;
+                move_span(&mut $self.span, input, offset, end)
+            }
+        }
+    };
+}
+
+/// Implement by the `span` field.
+macro_rules! impl_by_span {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl<S: RefStr $(, $($tt)*)?> Build<S> for $node {
+            #[inline]
+            fn write_text(&self, trivia: &str, text: &mut String) {
+                self.span.write_text(trivia, text)
+            }
+            #[inline]
+            fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+                self.span.rebase(trivia, input, offset)
+            }
+        }
+    };
+}
+
+/// Implement for nodes that consume nothing, whose `span` fields are moved to where they are.
+macro_rules! impl_empty {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl<S: RefStr $(, $($tt)*)?> Build<S> for $node {
+            #[inline(always)]
+            fn write_text(&self, _trivia: &str, _text: &mut String) {}
+            #[inline]
+            fn rebase(&mut self, _trivia: &str, input: &S, offset: usize) -> usize {
+                move_span(&mut self.span, input, offset, offset)
+            }
+        }
+    };
+}
+
+/// Implement for repetitions with trivia between items.
+macro_rules! impl_repeat {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl<S: RefStr, T: Build<S>, Skip: Default, const SKIP: usize $(, $($tt)*)?> Build<S>
+            for $node
+        {
+            fn write_text(&self, trivia: &str, text: &mut String) {
+                for (i, item) in self.content.iter().enumerate() {
+                    if i > 0 && SKIP > 0 {
+                        item.write_text_after(trivia, text);
+                    } else {
+                        item.write_text(trivia, text);
+                    }
+                }
+            }
+            fn rebase(&mut self, trivia: &str, input: &S, start: usize) -> usize {
+                let mut offset = start;
+                for (i, item) in self.content.iter_mut().enumerate() {
+                    offset = if i > 0 && SKIP > 0 {
+                        item.rebase_after(trivia, input, offset)
+                    } else {
+                        item.rebase(trivia, input, offset)
+                    };
+                }
+                move_span(&mut self.span, input, start, offset)
+            }
+        }
+    };
+}
+
+const fn newline(content: &NewLineType) -> &'static str {
+    match content {
+        NewLineType::CRLF => "\r\n",
+        NewLineType::LF => "\n",
+        NewLineType::CR => "\r",
+    }
+}
+
+impl<S: RefStr> Build<S> for Span<S> {
+    #[inline]
+    fn write_text(&self, _trivia: &str, text: &mut String) {
+        text.push_str(&self.as_str().to_text())
+    }
+    #[inline]
+    fn rebase(&mut self, _trivia: &str, input: &S, offset: usize) -> usize {
+        let end = offset + (self.end() - self.start());
+        if let Some(span) = Self::new(input.clone(), offset, end) {
+            *self = span;
+        }
+        end
+    }
+}
+
+impl_by_text!(Str<S, T>, |self| T::CONTENT, S::str_len(T::CONTENT), T: StringWrapper);
+impl_by_text!(CharRange<S, MIN, MAX>, |self| self.content.to_string(), S::char_len(self.content), const MIN: char, const MAX: char);
+impl_by_text!(CharSet<S, T>, |self| self.content.to_string(), S::char_len(self.content), T: CharSetWrapper);
+impl_by_text!(
+    ANY<S>,
+    |self| self.content.to_string(),
+    S::char_len(self.content)
+);
+impl_by_text!(
+    NEWLINE<S>,
+    |self| newline(&self.content),
+    S::str_len(newline(&self.content))
+);
+impl_by_span!(Skip<S, Strings>, Strings: StringArrayWrapper);
+impl_by_span!(SkipChar<S, N>, const N: usize);
+impl_by_span!(PEEK<S>);
+impl_by_span!(PEEK_ALL<S>);
+impl_by_span!(POP<S>);
+impl_by_span!(POP_ALL<S>);
+impl_by_span!(Lazy<S, T>, T);
+impl_empty!(Negative<S, T>, T);
+impl_empty!(SOI<S>);
+impl_empty!(EOI<S>);
+impl_empty!(DROP<S>);
+impl_empty!(Empty<S>);
+impl_empty!(PushLiteral<S, T>, T: StringWrapper);
+// Trivia are put by sequences and repetitions.
+impl_empty!(SkipTrivia<S, T>, T: TriviaWrapper);
+
+impl<S: RefStr, T> Build<S> for Insens<S, T> {
+    #[inline]
+    fn write_text(&self, _trivia: &str, text: &mut String) {
+        text.push_str(&self.content.to_text())
+    }
+    #[inline]
+    fn rebase(&mut self, _trivia: &str, input: &S, offset: usize) -> usize {
+        let end = offset + self.content.len();
+        if let Some(content) = input.get(offset..end) {
+            self.content = content;
+        }
+        move_span(&mut self.span, input, offset, end)
+    }
+}
+
+impl<S: RefStr, T: Build<S> + ?Sized> Build<S> for Box<T> {
+    #[inline]
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        (**self).write_text(trivia, text)
+    }
+    #[inline]
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        (**self).rebase(trivia, input, offset)
+    }
+}
+
+impl<S: RefStr, T: Build<S>> Build<S> for Optional<S, T> {
+    #[inline]
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        self.content.write_text(trivia, text)
+    }
+    #[inline]
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        let end = self.content.rebase(trivia, input, offset);
+        move_span(&mut self.span, input, offset, end)
+    }
+}
+
+impl<S: RefStr, T: Build<S>> Build<S> for Option<T> {
+    #[inline]
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        if let Some(inner) = self {
+            inner.write_text(trivia, text)
+        }
+    }
+    #[inline]
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        match self {
+            Some(inner) => inner.rebase(trivia, input, offset),
+            None => offset,
+        }
+    }
+}
+
+impl<S: RefStr, T: Build<S>> Build<S> for Push<T> {
+    #[inline]
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        self.content.write_text(trivia, text)
+    }
+    #[inline]
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        self.content.rebase(trivia, input, offset)
+    }
+}
+
+/// Trivia before the matched content are put by the container.
+/// Typed trivia are reset, as they are not built.
+impl<S: RefStr, T: Build<S>, Skip: Default, const SKIP: usize> Build<S> for Skipped<T, Skip, SKIP> {
+    #[inline]
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        self.matched.write_text(trivia, text)
+    }
+    #[inline]
+    fn rebase(&mut self, trivia: &str, input: &S, offset: usize) -> usize {
+        self.skipped = core::array::from_fn(|_| Skip::default());
+        self.matched.rebase(trivia, input, offset)
+    }
+}
+
+impl<S: RefStr, T: Build<S>> Build<S> for AtomicRepeat<S, T> {
+    fn write_text(&self, trivia: &str, text: &mut String) {
+        for item in &self.content {
+            item.write_text(trivia, text);
+        }
+    }
+    fn rebase(&mut self, trivia: &str, input: &S, start: usize) -> usize {
+        let mut offset = start;
+        for item in &mut self.content {
+            offset = item.rebase(trivia, input, offset);
+        }
+        move_span(&mut self.span, input, start, offset)
+    }
+}
+impl_repeat!(RepeatMin<S, Skipped<T, Skip, SKIP>, MIN>, const MIN: usize);
+impl_repeat!(RepeatMinMax<S, Skipped<T, Skip, SKIP>, MIN, MAX>, const MIN: usize, const MAX: usize);
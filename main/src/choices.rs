@@ -171,12 +171,22 @@ macro_rules! choices {
                     stack: &mut $crate::Stack<$crate::Span<C::String>>,
                     tracker: &mut $crate::tracker::Tracker<C::String, R>,
                 ) -> ::core::option::Option<C> {
-                    let res = $crate::predefined_node::restore_on_none(stack, |stack| $V0::try_check_partial_with(input.clone(), stack, tracker));
+                    Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+                }
+
+                #[inline]
+                fn try_check_partial_reporting_with(
+                    input: C,
+                    stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                    tracker: &mut $crate::tracker::Tracker<C::String, R>,
+                    events: &mut impl $crate::tracker::EventSink<C::String, R>,
+                ) -> ::core::option::Option<C> {
+                    let res = $crate::predefined_node::restore_on_none_with(stack, events, |stack, events| $V0::try_check_partial_reporting_with(input.clone(), stack, tracker, events));
                     if let Some(input) = res {
                         return Some(input);
                     }
                     $(
-                        let res = $crate::predefined_node::restore_on_none(stack, |stack| $V::try_check_partial_with(input.clone(), stack, tracker));
+                        let res = $crate::predefined_node::restore_on_none_with(stack, events, |stack, events| $V::try_check_partial_reporting_with(input.clone(), stack, tracker, events));
                         if let Some(input) = res {
                             return Some(input);
                         }
@@ -192,11 +202,96 @@ macro_rules! choices {
             > $crate::iterators::Pairs<S, R> for $name<$V0, $($V, )* >
             {
                 #[inline]
-                fn for_self_or_each_child(&self, f: &mut impl FnMut($crate::iterators::Token<S, R>)) {
+                fn visit_self_or_children<'t>(&'t self, visitor: &mut impl $crate::iterators::PairVisitor<'t, S, R>) -> ::core::ops::ControlFlow<()> {
+                    match self {
+                        Self::$v0($v0) =>$v0.visit_self_or_children(visitor),
+                        $(
+                            Self::$v($v) => $v.visit_self_or_children(visitor),
+                        )*
+                    }
+                }
+            }
+            impl<S: $crate::RefStr, $V0: $crate::builder::Build<S>, $($V: $crate::builder::Build<S>, )* >
+                $crate::builder::Build<S> for $name<$V0, $($V, )* >
+            {
+                #[inline]
+                fn write_text(&self, trivia: &::core::primitive::str, text: &mut $crate::re_exported::String) {
+                    match self {
+                        Self::$v0($v0) => $crate::builder::Build::<S>::write_text($v0, trivia, text),
+                        $(
+                            Self::$v($v) => $crate::builder::Build::<S>::write_text($v, trivia, text),
+                        )*
+                    }
+                }
+                #[inline]
+                fn rebase(
+                    &mut self,
+                    trivia: &::core::primitive::str,
+                    input: &S,
+                    offset: ::core::primitive::usize,
+                ) -> ::core::primitive::usize {
+                    match self {
+                        Self::$v0($v0) => $crate::builder::Build::<S>::rebase($v0, trivia, input, offset),
+                        $(
+                            Self::$v($v) => $crate::builder::Build::<S>::rebase($v, trivia, input, offset),
+                        )*
+                    }
+                }
+            }
+            impl<$V0: $crate::semantic::SemanticEq, $($V: $crate::semantic::SemanticEq, )* >
+                $crate::semantic::SemanticEq for $name<$V0, $($V, )* >
+            {
+                #[inline]
+                fn semantic_eq(&self, other: &Self) -> ::core::primitive::bool {
+                    match (self, other) {
+                        (Self::$v0(a), Self::$v0(b)) => $crate::semantic::SemanticEq::semantic_eq(a, b),
+                        $(
+                            (Self::$v(a), Self::$v(b)) => $crate::semantic::SemanticEq::semantic_eq(a, b),
+                        )*
+                        _ => false,
+                    }
+                }
+            }
+            impl<$V0: $crate::semantic::SemanticHash, $($V: $crate::semantic::SemanticHash, )* >
+                $crate::semantic::SemanticHash for $name<$V0, $($V, )* >
+            {
+                #[inline]
+                fn semantic_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+                    match self {
+                        Self::$v0($v0) => $crate::semantic::SemanticHash::semantic_hash($v0, state),
+                        $(
+                            Self::$v($v) => $crate::semantic::SemanticHash::semantic_hash($v, state),
+                        )*
+                    }
+                }
+            }
+            impl<
+                S: $crate::RefStr,
+                R: $crate::RuleType,
+                $V0: $crate::Spanned<S, R>,
+                $($V: $crate::Spanned<S, R>, )*
+            > $crate::Spanned<S, R> for $name<$V0, $($V, )* >
+            {
+                #[inline]
+                fn span(&self) -> $crate::Span<S> {
+                    match self {
+                        Self::$v0($v0) => $crate::Spanned::<S, R>::span($v0),
+                        $(
+                            Self::$v($v) => $crate::Spanned::<S, R>::span($v),
+                        )*
+                    }
+                }
+            }
+            impl<$V0: ::core::fmt::Display, $($V: ::core::fmt::Display, )* >
+                ::core::fmt::Display for $name<$V0, $($V, )* >
+            {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
-                        Self::$v0($v0) =>$v0.for_self_or_each_child(f),
+                        Self::$v0($v0) => ::core::fmt::Display::fmt($v0, f),
                         $(
-                            Self::$v($v) => $v.for_self_or_each_child(f),
+                            Self::$v($v) => ::core::fmt::Display::fmt($v, f),
                         )*
                     }
                 }
@@ -0,0 +1,157 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parse independent chunks of the input in parallel.
+
+use crate::{
+    error::Error,
+    input::RefStr,
+    tracker::{error_at, Tracker},
+    Cursor, Input, ParsableTypedNode, Position, RuleType, Span, Stack, TypedNode,
+};
+use alloc::{boxed::Box, format, vec::Vec};
+use core::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{panic, thread};
+
+/// Split `input` into chunks at each match of `B`.
+///
+/// `B` is tried at every character that is not inside a previous match,
+/// and the matched text is not included in any chunk.
+/// A boundary that matches nothing, such as `&"section"`,
+/// keeps the following text in the next chunk.
+/// Empty chunks are skipped.
+///
+/// The boundary is matched with an empty [`Stack`],
+/// and must not occur inside a chunk.
+pub fn split_chunks<S: RefStr, R: RuleType, B: TypedNode<Position<S>, R>>(
+    input: S,
+) -> Vec<Span<S>> {
+    let mut chunks = Vec::new();
+    let mut start = Position::from_start(input);
+    let mut cursor = start.clone();
+    // Failures are not reported, so one tracker is enough.
+    let mut tracker = Tracker::new(cursor.clone());
+    let mut stack = Stack::new();
+    loop {
+        stack.snapshot();
+        let end = B::try_check_partial_with(cursor.clone(), &mut stack, &mut tracker);
+        stack.restore();
+        if let Some(end) = end {
+            if cursor.pos() > start.pos() {
+                chunks.push(start.span(&cursor));
+            }
+            start = end.clone();
+            if end.pos() > cursor.pos() {
+                cursor = end;
+                continue;
+            }
+        }
+        if cursor.advance_char().is_none() {
+            break;
+        }
+    }
+    if cursor.pos() > start.pos() {
+        chunks.push(start.span(&cursor));
+    }
+    chunks
+}
+
+/// Split `input` with [`split_chunks`] and parse each chunk into `T` on all available threads.
+///
+/// See [`try_parse_chunks_with_threads`].
+pub fn try_parse_chunks<S, R, T, B>(input: S) -> Vec<Result<T, Box<Error<R>>>>
+where
+    S: RefStr + Send + Sync,
+    R: RuleType + Send,
+    T: ParsableTypedNode<<Span<S> as Input>::Cursor, R> + Send,
+    B: TypedNode<Position<S>, R>,
+{
+    let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    try_parse_chunks_with_threads::<S, R, T, B>(input, threads)
+}
+
+/// Split `input` with [`split_chunks`] and parse each chunk into `T` on at most `threads` threads.
+///
+/// Each chunk is parsed like [`ParsableTypedNode::try_parse`] on its [`Span`],
+/// so `SOI` and `EOI` match at the start and the end of the chunk.
+/// Spans in the nodes and positions in the errors are those in the whole `input`.
+/// Results are in the order of chunks.
+///
+/// Each chunk is parsed with an empty [`Stack`].
+/// To get the same result as parsing chunks one after another,
+/// a chunk that leaves anything on the stack is reported as an error.
+///
+/// # Panics
+///
+/// Panics if parsing panics on any thread.
+pub fn try_parse_chunks_with_threads<S, R, T, B>(
+    input: S,
+    threads: NonZeroUsize,
+) -> Vec<Result<T, Box<Error<R>>>>
+where
+    S: RefStr + Send + Sync,
+    R: RuleType + Send,
+    T: ParsableTypedNode<<Span<S> as Input>::Cursor, R> + Send,
+    B: TypedNode<Position<S>, R>,
+{
+    let chunks = split_chunks::<S, R, B>(input);
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<Result<T, Box<Error<R>>>>> = chunks.iter().map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.get().min(chunks.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut parsed = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(chunk) = chunks.get(index) else {
+                            break;
+                        };
+                        parsed.push((index, parse_chunk::<S, R, T>(chunk)));
+                    }
+                    parsed
+                })
+            })
+            .collect();
+        for worker in workers {
+            let parsed = worker
+                .join()
+                .unwrap_or_else(|err| panic::resume_unwind(err));
+            for (index, result) in parsed {
+                results[index] = Some(result);
+            }
+        }
+    });
+    results.into_iter().flatten().collect()
+}
+
+fn parse_chunk<S, R, T>(chunk: &Span<S>) -> Result<T, Box<Error<R>>>
+where
+    S: RefStr,
+    R: RuleType,
+    T: ParsableTypedNode<<Span<S> as Input>::Cursor, R>,
+{
+    let cursor = chunk.as_cursor();
+    let mut stack = Stack::new();
+    let mut tracker = Tracker::new(cursor.as_position());
+    let Some(res) = T::try_parse_with(cursor, &mut stack, &mut tracker) else {
+        return Err(Box::new(tracker.collect(())));
+    };
+    if stack.is_empty() {
+        return Ok(res);
+    }
+    let message = format!(
+        "{} element(s) left on the stack, so the chunk can not be parsed independently.",
+        stack.len()
+    );
+    Err(Box::new(error_at(&chunk.end_pos(), |_, _| message)))
+}
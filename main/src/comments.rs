@@ -0,0 +1,172 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Attach skipped `COMMENT`s to the nearest syntax nodes.
+//!
+//! Each comment is attached to a rule struct in the innermost node that encloses it,
+//! according to its preceding and following siblings, in the same way as prettier:
+//!
+//! - A comment on its own line leads the following node,
+//!   or trails the preceding node if there is no following one.
+//! - A comment at the end of a line trails the preceding node,
+//!   or leads the following node if there is no preceding one.
+//! - Other comments lead the following node if only trivia are between them,
+//!   and trail the preceding node otherwise.
+//!
+//! A comment without preceding or following nodes is dangling in the enclosing node.
+
+use crate::{
+    iterators::{Pair, Token},
+    leaves::{Leaf, LeafKind},
+    RefStr, RuleType, Span,
+};
+use alloc::vec::Vec;
+use derive_where::derive_where;
+
+/// How a comment is attached to a node.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Placement {
+    /// Before the node.
+    Leading,
+    /// After the node.
+    Trailing,
+    /// Inside the node, which has no children around the comment.
+    Dangling,
+}
+
+/// A comment attached to a node.
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Comment<S, R: RuleType> {
+    /// Span of the comment.
+    pub span: Span<S>,
+    /// How the comment is attached.
+    pub placement: Placement,
+    /// Rule of the node.
+    pub rule: R,
+    /// Span of the node.
+    pub node: Span<S>,
+}
+
+/// Comments in a tree attached to their nodes.
+///
+/// See [`PairTree::comments`](crate::iterators::PairTree::comments).
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Comments<S, R: RuleType> {
+    comments: Vec<Comment<S, R>>,
+}
+
+/// Whether a leaf is `WHITESPACE` or `COMMENT`.
+const fn is_trivia<R>(kind: &LeafKind<R>) -> bool {
+    matches!(kind, LeafKind::Whitespace | LeafKind::Comment)
+}
+
+/// Whether there is a line break between `leaves` and the nearest non-trivia leaf in the given order.
+fn line_break<'l, S: RefStr + 'l, R: RuleType + 'l>(
+    mut leaves: impl Iterator<Item = &'l Leaf<S, R>>,
+) -> bool {
+    leaves
+        .find(|leaf| !is_trivia(&leaf.kind) || leaf.span.as_str().to_text().contains('\n'))
+        .is_none_or(|leaf| is_trivia(&leaf.kind))
+}
+
+impl<S: RefStr, R: RuleType> Comments<S, R> {
+    /// Attach comments among `leaves` to nodes in `root`.
+    ///
+    /// `leaves` should be those of `root`, as returned by [`PairTree::leaves`](crate::iterators::PairTree::leaves).
+    pub fn new(root: &Token<S, R>, leaves: &[Leaf<S, R>]) -> Self {
+        let mut comments = Vec::new();
+        for (index, leaf) in leaves.iter().enumerate() {
+            if leaf.kind != LeafKind::Comment {
+                continue;
+            }
+            let span = &leaf.span;
+            let mut enclosing = root;
+            while let Some(child) = enclosing
+                .children
+                .iter()
+                .find(|child| child.span.start() <= span.start() && span.end() <= child.span.end())
+            {
+                enclosing = child;
+            }
+            // Nodes that consume nothing, such as `EOI`, are not attached to.
+            let mut children = enclosing
+                .children
+                .iter()
+                .filter(|child| child.span.start() < child.span.end());
+            let preceding = children
+                .clone()
+                .rev()
+                .find(|child| child.span.end() <= span.start());
+            let following = children.find(|child| span.end() <= child.span.start());
+
+            let own_line = line_break(leaves[..index].iter().rev());
+            let end_of_line = line_break(leaves[index + 1..].iter());
+            let (node, placement) = match (preceding, following) {
+                (None, None) => (enclosing, Placement::Dangling),
+                (Some(preceding), None) => (preceding, Placement::Trailing),
+                (None, Some(following)) => (following, Placement::Leading),
+                (Some(_), Some(following)) if own_line => (following, Placement::Leading),
+                (Some(preceding), Some(_)) if end_of_line => (preceding, Placement::Trailing),
+                (Some(preceding), Some(following)) => {
+                    let only_trivia = leaves[index + 1..]
+                        .iter()
+                        .take_while(|leaf| leaf.span.end() <= following.span.start())
+                        .all(|leaf| is_trivia(&leaf.kind));
+                    if only_trivia {
+                        (following, Placement::Leading)
+                    } else {
+                        (preceding, Placement::Trailing)
+                    }
+                }
+            };
+            comments.push(Comment {
+                span: span.clone(),
+                placement,
+                rule: node.rule,
+                node: node.span.clone(),
+            });
+        }
+        Self { comments }
+    }
+    /// All comments in document order.
+    pub fn all(&self) -> &[Comment<S, R>] {
+        &self.comments
+    }
+    /// Spans of comments attached to `node` with `placement`, in document order.
+    ///
+    /// Nodes are identified by their rules and spans.
+    pub fn attached<'c>(
+        &'c self,
+        node: &impl Pair<S, R>,
+        placement: Placement,
+    ) -> impl Iterator<Item = &'c Span<S>> + 'c {
+        let (rule, span) = (node.rule(), node.span());
+        self.comments
+            .iter()
+            .filter(move |comment| {
+                comment.placement == placement
+                    && comment.rule == rule
+                    && comment.node.start() == span.start()
+                    && comment.node.end() == span.end()
+            })
+            .map(|comment| &comment.span)
+    }
+    /// Comments before `node`.
+    pub fn leading(&self, node: &impl Pair<S, R>) -> Vec<&Span<S>> {
+        self.attached(node, Placement::Leading).collect()
+    }
+    /// Comments after `node`.
+    pub fn trailing(&self, node: &impl Pair<S, R>) -> Vec<&Span<S>> {
+        self.attached(node, Placement::Trailing).collect()
+    }
+    /// Comments inside `node` but not around any of its children.
+    pub fn dangling(&self, node: &impl Pair<S, R>) -> Vec<&Span<S>> {
+        self.attached(node, Placement::Dangling).collect()
+    }
+}
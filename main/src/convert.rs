@@ -0,0 +1,279 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Convert typed nodes into user types, such as abstract syntax trees.
+//!
+//! See `FromTypedNode` in `pest_typed_derive` for how to derive [`FromTypedNode`].
+//! Other items in this module are helpers used by derived implementations.
+
+use crate::{RefStr, RuleType, Span, Spanned};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use derive_where::derive_where;
+
+/// Error in converting a typed node, with the span of the node that fails.
+#[derive_where(Clone, Debug, PartialEq, Eq; S: RefStr)]
+pub struct ConvertError<S> {
+    /// Span of the node that fails to be converted.
+    pub span: Span<S>,
+    /// Error message.
+    pub message: String,
+}
+
+impl<S: RefStr> ConvertError<S> {
+    /// Create an error at `span`.
+    pub fn new(span: Span<S>, message: impl ToString) -> Self {
+        let message = message.to_string();
+        Self { span, message }
+    }
+}
+
+impl<S: RefStr> fmt::Display for ConvertError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (at {}..{}: {:?})",
+            self.message,
+            self.span.start(),
+            self.span.end(),
+            self.span.as_str().to_text()
+        )
+    }
+}
+
+impl<S: RefStr> core::error::Error for ConvertError<S> {}
+
+/// Types that can be created from typed node `N` in input `S`.
+pub trait FromTypedNode<S: RefStr, N: ?Sized>: Sized {
+    /// Convert `node`.
+    fn from_typed_node(node: &N) -> Result<Self, ConvertError<S>>;
+}
+
+/// Results of getter functions, which are nested references, [`Option`]s, [`Vec`]s and tuples of nodes.
+pub trait GetterResult<'n> {
+    /// Node type.
+    type Node: ?Sized + 'n;
+    /// Push nodes in order to `nodes`.
+    fn collect_into(self, nodes: &mut Vec<&'n Self::Node>);
+    /// Nodes in order.
+    fn into_nodes(self) -> Vec<&'n Self::Node>
+    where
+        Self: Sized,
+    {
+        let mut nodes = Vec::new();
+        self.collect_into(&mut nodes);
+        nodes
+    }
+}
+
+impl<'n, N: ?Sized> GetterResult<'n> for &'n N {
+    type Node = N;
+    #[inline]
+    fn collect_into(self, nodes: &mut Vec<&'n N>) {
+        nodes.push(self)
+    }
+}
+impl<'n, T: GetterResult<'n>> GetterResult<'n> for Option<T> {
+    type Node = T::Node;
+    #[inline]
+    fn collect_into(self, nodes: &mut Vec<&'n T::Node>) {
+        if let Some(inner) = self {
+            inner.collect_into(nodes)
+        }
+    }
+}
+impl<'n, T: GetterResult<'n>> GetterResult<'n> for Vec<T> {
+    type Node = T::Node;
+    #[inline]
+    fn collect_into(self, nodes: &mut Vec<&'n T::Node>) {
+        for inner in self {
+            inner.collect_into(nodes)
+        }
+    }
+}
+
+macro_rules! impl_getter_result_for_tuple {
+    ($T0:ident, $t0:tt, $($T:ident, $t:tt, )*) => {
+        impl<'n, $T0: GetterResult<'n>, $($T: GetterResult<'n, Node = $T0::Node>, )*> GetterResult<'n>
+            for ($T0, $($T, )*)
+        {
+            type Node = $T0::Node;
+            #[inline]
+            fn collect_into(self, nodes: &mut Vec<&'n $T0::Node>) {
+                self.$t0.collect_into(nodes);
+                $(self.$t.collect_into(nodes);)*
+            }
+        }
+    };
+}
+
+impl_getter_result_for_tuple!(T0, 0, T1, 1,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3, T4, 4,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6, T7, 7,);
+impl_getter_result_for_tuple!(T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6, T7, 7, T8, 8,);
+impl_getter_result_for_tuple!(
+    T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6, T7, 7, T8, 8, T9, 9,
+);
+impl_getter_result_for_tuple!(
+    T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6, T7, 7, T8, 8, T9, 9, T10, 10,
+);
+impl_getter_result_for_tuple!(
+    T0, 0, T1, 1, T2, 2, T3, 3, T4, 4, T5, 5, T6, 6, T7, 7, T8, 8, T9, 9, T10, 10, T11, 11,
+);
+
+/// Span of `node`.
+#[inline]
+pub fn span_of<S: RefStr, R: RuleType, N: Spanned<S, R> + ?Sized>(node: &N) -> Span<S> {
+    node.span()
+}
+
+/// Convert the text of `node` by fallible `f`.
+pub fn with<S: RefStr, R: RuleType, N: Spanned<S, R> + ?Sized, T, E: fmt::Display>(
+    node: &N,
+    f: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, ConvertError<S>> {
+    let span = node.span();
+    let text = span.as_str();
+    f(&text.to_text()).map_err(|error| ConvertError::new(span.clone(), error))
+}
+
+/// Convert the text of `node` by infallible `f`.
+pub fn map<S: RefStr, R: RuleType, N: Spanned<S, R> + ?Sized, T>(
+    node: &N,
+    f: impl FnOnce(&str) -> T,
+) -> Result<T, ConvertError<S>> {
+    let text = node.span().as_str();
+    Ok(f(&text.to_text()))
+}
+
+/// Convert all nodes in `nodes`.
+pub fn many<'n, S: RefStr, N: ?Sized, T>(
+    nodes: Vec<&'n N>,
+    convert: impl FnMut(&'n N) -> Result<T, ConvertError<S>>,
+) -> Result<Vec<T>, ConvertError<S>> {
+    nodes.into_iter().map(convert).collect()
+}
+
+/// Getter results converted into field values, whose types follow how many nodes there may be.
+///
+/// A reference to a node is converted into `T`,
+/// an [`Option`] of a node into `Option<T>`,
+/// and other results into `Vec<T>`.
+pub trait ConvertNodes<'n>: GetterResult<'n> {
+    /// Field value with nodes converted into `T`s.
+    type Output<T>;
+    /// Convert nodes by `convert`.
+    fn convert_nodes<S: RefStr, T>(
+        self,
+        convert: impl FnMut(&'n Self::Node) -> Result<T, ConvertError<S>>,
+    ) -> Result<Self::Output<T>, ConvertError<S>>;
+}
+
+impl<'n, N: ?Sized> ConvertNodes<'n> for &'n N {
+    type Output<T> = T;
+    #[inline]
+    fn convert_nodes<S: RefStr, T>(
+        self,
+        mut convert: impl FnMut(&'n N) -> Result<T, ConvertError<S>>,
+    ) -> Result<T, ConvertError<S>> {
+        convert(self)
+    }
+}
+impl<'n, N: ?Sized> ConvertNodes<'n> for Option<&'n N> {
+    type Output<T> = Option<T>;
+    #[inline]
+    fn convert_nodes<S: RefStr, T>(
+        self,
+        convert: impl FnMut(&'n N) -> Result<T, ConvertError<S>>,
+    ) -> Result<Option<T>, ConvertError<S>> {
+        self.map(convert).transpose()
+    }
+}
+impl<'n, T: GetterResult<'n>> ConvertNodes<'n> for Option<Option<T>>
+where
+    Option<T>: ConvertNodes<'n, Node = T::Node>,
+{
+    type Output<U> = <Option<T> as ConvertNodes<'n>>::Output<U>;
+    #[inline]
+    fn convert_nodes<S: RefStr, U>(
+        self,
+        convert: impl FnMut(&'n T::Node) -> Result<U, ConvertError<S>>,
+    ) -> Result<Self::Output<U>, ConvertError<S>> {
+        self.flatten().convert_nodes(convert)
+    }
+}
+
+macro_rules! impl_convert_nodes_for_many {
+    ($ty:ty, $($tt:tt)*) => {
+        impl<'n, $($tt)*> ConvertNodes<'n> for $ty {
+            type Output<U> = Vec<U>;
+            #[inline]
+            fn convert_nodes<S: RefStr, U>(
+                self,
+                convert: impl FnMut(&'n Self::Node) -> Result<U, ConvertError<S>>,
+            ) -> Result<Vec<U>, ConvertError<S>> {
+                many(self.into_nodes(), convert)
+            }
+        }
+    };
+}
+
+impl_convert_nodes_for_many!(Vec<T>, T: GetterResult<'n>);
+impl_convert_nodes_for_many!(Option<Vec<T>>, T: GetterResult<'n>);
+
+macro_rules! impl_convert_nodes_for_tuple {
+    ($T0:ident, $($T:ident, )*) => {
+        impl_convert_nodes_for_many!(
+            ($T0, $($T, )*),
+            $T0: GetterResult<'n>, $($T: GetterResult<'n, Node = $T0::Node>, )*
+        );
+        impl_convert_nodes_for_many!(
+            Option<($T0, $($T, )*)>,
+            $T0: GetterResult<'n>, $($T: GetterResult<'n, Node = $T0::Node>, )*
+        );
+    };
+}
+
+impl_convert_nodes_for_tuple!(T0, T1,);
+impl_convert_nodes_for_tuple!(T0, T1, T2,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10,);
+impl_convert_nodes_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11,);
+
+/// Getter results with at most one node, from which variants of enums are converted.
+pub trait OptionalNode<'n>: GetterResult<'n> {
+    /// The node if any.
+    fn into_option(self) -> Option<&'n Self::Node>;
+}
+
+impl<'n, N: ?Sized> OptionalNode<'n> for &'n N {
+    #[inline]
+    fn into_option(self) -> Option<&'n N> {
+        Some(self)
+    }
+}
+impl<'n, T: OptionalNode<'n>> OptionalNode<'n> for Option<T> {
+    #[inline]
+    fn into_option(self) -> Option<&'n T::Node> {
+        self.and_then(T::into_option)
+    }
+}
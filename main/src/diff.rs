@@ -0,0 +1,476 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Structural diff between two parse trees.
+//!
+//! Subtrees are identified by hashes of their rules and texts.
+//! Children of matched nodes are aligned by Myers' diff after trimming common prefixes and suffixes,
+//! first of identical subtrees, then of rules between them.
+//! Subtrees deleted from one place and inserted to another are reported as moved.
+
+use crate::{iterators::Token, RefStr, RuleType, Span};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+use derive_where::derive_where;
+
+/// A change from the old tree to the new one.
+#[derive_where(Clone, Debug, Hash, Eq, PartialEq; S: RefStr)]
+pub enum Change<S, R: RuleType> {
+    /// A subtree only in the new tree.
+    Insert(R, Span<S>),
+    /// A subtree only in the old tree.
+    Delete(R, Span<S>),
+    /// An identical subtree at another place, with old and new spans.
+    Move(R, Span<S>, Span<S>),
+    /// A node whose text is changed while its descendants are not, with old and new spans.
+    Update(R, Span<S>, Span<S>),
+}
+
+/// How a line in the rendering is changed.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum Mark {
+    Same,
+    Insert,
+    Delete,
+    Move,
+    Update,
+}
+
+/// FNV-1a, which is enough to tell subtrees apart before comparing them.
+struct Fnv(u64);
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+/// A token with the hash of its subtree.
+struct Node<'t, S, R: RuleType> {
+    token: &'t Token<S, R>,
+    /// Hash of the rule and the text, including texts between children.
+    hash: u64,
+    children: Vec<Self>,
+}
+
+impl<'t, S: RefStr, R: RuleType> Node<'t, S, R> {
+    /// Hash all subtrees, reading each byte once.
+    fn new(token: &'t Token<S, R>) -> Self {
+        let children: Vec<_> = token.children.iter().map(Self::new).collect();
+        let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+        token.rule.hash(&mut hasher);
+        let text = token.span.as_str();
+        let start = token.span.start();
+        let mut pos = start;
+        for child in &children {
+            text.get(pos - start..child.token.span.start() - start)
+                .hash(&mut hasher);
+            hasher.write_u64(child.hash);
+            pos = child.token.span.end();
+        }
+        text.get(pos - start..).hash(&mut hasher);
+        Self {
+            token,
+            hash: hasher.finish(),
+            children,
+        }
+    }
+}
+
+/// Whether two subtrees have the same rules and texts.
+///
+/// Hashes are compared first, so texts are only compared for identical subtrees.
+fn same<S: RefStr, R: RuleType>(a: &Node<'_, S, R>, b: &Node<'_, S, R>) -> bool {
+    a.hash == b.hash && same_tokens(a.token, b.token)
+}
+fn same_tokens<S: RefStr, R: RuleType>(a: &Token<S, R>, b: &Token<S, R>) -> bool {
+    a.rule == b.rule
+        && a.children.len() == b.children.len()
+        && a.span.as_str() == b.span.as_str()
+        && a.children
+            .iter()
+            .zip(&b.children)
+            .all(|(a, b)| same_tokens(a, b))
+}
+
+/// A node in the merged tree, in pre-order.
+struct Line<'t, S, R: RuleType> {
+    depth: usize,
+    mark: Mark,
+    /// Whether this is the root of a changed subtree.
+    root: bool,
+    old: Option<&'t Node<'t, S, R>>,
+    new: Option<&'t Node<'t, S, R>>,
+}
+
+/// A step in an alignment of two sequences.
+enum Step {
+    Both(usize, usize),
+    Old(usize),
+    New(usize),
+}
+
+/// Align `a` and `b` by a shortest edit script under `eq`.
+///
+/// Common prefixes and suffixes are trimmed first.
+/// At each gap, items only in `a` come before those only in `b`.
+fn align<T>(a: &[T], b: &[T], eq: impl Fn(&T, &T) -> bool) -> Vec<Step> {
+    let prefix = a.iter().zip(b).take_while(|(a, b)| eq(a, b)).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(a, b)| eq(a, b))
+        .count();
+    let (n, m) = (a.len() - suffix, b.len() - suffix);
+    let mut steps: Vec<_> = (0..prefix).map(|i| Step::Both(i, i)).collect();
+    let mut gap_old = Vec::new();
+    let mut gap_new = Vec::new();
+    for step in myers(&a[prefix..n], &b[prefix..m], &eq) {
+        match step {
+            Step::Both(i, j) => {
+                steps.append(&mut gap_old);
+                steps.append(&mut gap_new);
+                steps.push(Step::Both(prefix + i, prefix + j));
+            }
+            Step::Old(i) => gap_old.push(Step::Old(prefix + i)),
+            Step::New(j) => gap_new.push(Step::New(prefix + j)),
+        }
+    }
+    steps.extend(gap_old.into_iter().chain(gap_new));
+    steps.extend((0..suffix).map(|k| Step::Both(n + k, m + k)));
+    steps
+}
+
+/// Myers' diff in `O((n + m) * d)` time, where `d` is the length of the edit script.
+///
+/// Furthest reaching points of each round are kept to trace the script back,
+/// which takes `O(d * d)` space.
+fn myers<T>(a: &[T], b: &[T], eq: impl Fn(&T, &T) -> bool) -> Vec<Step> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    // `v[k + max + 1]` is the furthest `x` reached on diagonal `k = x - y`.
+    let mut v = vec![0isize; 2 * max as usize + 3];
+    let at = |k: isize| (k + max + 1) as usize;
+    // `trace[d]` is `v` on diagonals `-d..=d` after round `d`.
+    let mut trace = Vec::new();
+    'rounds: for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && eq(&a[x as usize], &b[y as usize]) {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                trace.push(v[at(-d)..=at(d)].to_vec());
+                break 'rounds;
+            }
+        }
+        trace.push(v[at(-d)..=at(d)].to_vec());
+    }
+
+    let mut steps = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..trace.len() as isize).rev() {
+        let prev = &trace[d as usize - 1];
+        let get = |k: isize| prev[(k + d - 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = get(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            steps.push(Step::Both(x as usize, y as usize));
+        }
+        if x == prev_x {
+            steps.push(Step::New(prev_y as usize));
+        } else {
+            steps.push(Step::Old(prev_x as usize));
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        steps.push(Step::Both(x as usize, y as usize));
+    }
+    steps.reverse();
+    steps
+}
+
+struct Differ<'t, S, R: RuleType> {
+    lines: Vec<Line<'t, S, R>>,
+}
+
+impl<'t, S: RefStr, R: RuleType> Differ<'t, S, R> {
+    fn push_same(&mut self, old: &'t Node<'t, S, R>, new: &'t Node<'t, S, R>, depth: usize) {
+        self.lines.push(Line {
+            depth,
+            mark: Mark::Same,
+            root: false,
+            old: Some(old),
+            new: Some(new),
+        });
+        for (old, new) in old.children.iter().zip(&new.children) {
+            self.push_same(old, new, depth + 1);
+        }
+    }
+    fn push_one_side(&mut self, node: &'t Node<'t, S, R>, mark: Mark, root: bool, depth: usize) {
+        let (old, new) = match mark {
+            Mark::Delete => (Some(node), None),
+            _ => (None, Some(node)),
+        };
+        self.lines.push(Line {
+            depth,
+            mark,
+            root,
+            old,
+            new,
+        });
+        for child in &node.children {
+            self.push_one_side(child, mark, false, depth + 1);
+        }
+    }
+    /// Diff two nodes of the same rule.
+    fn node(&mut self, old: &'t Node<'t, S, R>, new: &'t Node<'t, S, R>, depth: usize) {
+        if same(old, new) {
+            self.push_same(old, new, depth);
+            return;
+        }
+        let index = self.lines.len();
+        self.lines.push(Line {
+            depth,
+            mark: Mark::Same,
+            root: false,
+            old: Some(old),
+            new: Some(new),
+        });
+        self.children(&old.children, &new.children, depth + 1);
+        if self.lines[index + 1..]
+            .iter()
+            .all(|line| line.mark == Mark::Same)
+        {
+            self.lines[index].mark = Mark::Update;
+            self.lines[index].root = true;
+        }
+    }
+    fn children(&mut self, old: &'t [Node<'t, S, R>], new: &'t [Node<'t, S, R>], depth: usize) {
+        let mut gap_old = Vec::new();
+        let mut gap_new = Vec::new();
+        for step in align(old, new, |a, b| a.hash == b.hash) {
+            match step {
+                Step::Both(i, j) if same_tokens(old[i].token, new[j].token) => {
+                    self.gap(&gap_old, &gap_new, depth);
+                    gap_old.clear();
+                    gap_new.clear();
+                    self.push_same(&old[i], &new[j], depth);
+                }
+                // Hashes collide.
+                Step::Both(i, j) => {
+                    gap_old.push(&old[i]);
+                    gap_new.push(&new[j]);
+                }
+                Step::Old(i) => gap_old.push(&old[i]),
+                Step::New(j) => gap_new.push(&new[j]),
+            }
+        }
+        self.gap(&gap_old, &gap_new, depth);
+    }
+    /// Diff children between two identical ones, matching them by rules.
+    fn gap(&mut self, old: &[&'t Node<'t, S, R>], new: &[&'t Node<'t, S, R>], depth: usize) {
+        for step in align(old, new, |a, b| a.token.rule == b.token.rule) {
+            match step {
+                Step::Both(i, j) => self.node(old[i], new[j], depth),
+                Step::Old(i) => self.push_one_side(old[i], Mark::Delete, true, depth),
+                Step::New(j) => self.push_one_side(new[j], Mark::Insert, true, depth),
+            }
+        }
+    }
+    /// Replace pairs of deleted and inserted identical subtrees with moves.
+    fn find_moves(&mut self) {
+        let mut inserted = BTreeMap::<u64, Vec<usize>>::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            if let (true, Mark::Insert, Some(new)) = (line.root, line.mark, line.new) {
+                inserted.entry(new.hash).or_default().push(index);
+            }
+        }
+        let mut removed = Vec::new();
+        for index in 0..self.lines.len() {
+            let line = &self.lines[index];
+            let (true, Mark::Delete, Some(old)) = (line.root, line.mark, line.old) else {
+                continue;
+            };
+            let Some(candidates) = inserted.get_mut(&old.hash) else {
+                continue;
+            };
+            let Some(found) = candidates
+                .iter()
+                .position(|&i| same(old, self.lines[i].new.unwrap()))
+            else {
+                continue;
+            };
+            let target = candidates.remove(found);
+            self.lines[target].mark = Mark::Move;
+            self.lines[target].old = Some(old);
+            let depth = self.lines[target].depth;
+            for line in self.lines[target + 1..]
+                .iter_mut()
+                .take_while(|line| line.depth > depth)
+            {
+                line.mark = Mark::Same;
+            }
+            let depth = self.lines[index].depth;
+            let len = self.lines[index + 1..]
+                .iter()
+                .take_while(|line| line.depth > depth)
+                .count();
+            removed.push(index..index + 1 + len);
+        }
+        let mut index = 0;
+        self.lines.retain(|_| {
+            let keep = !removed.iter().any(|range| range.contains(&index));
+            index += 1;
+            keep
+        });
+    }
+}
+
+/// A row of the rendering.
+#[derive_where(Clone, Debug, Hash, Eq, PartialEq; S: RefStr)]
+struct Row<S, R: RuleType> {
+    depth: usize,
+    mark: Mark,
+    rule: R,
+    old: Option<Span<S>>,
+    new: Option<Span<S>>,
+    leaf: bool,
+}
+
+/// Structural diff between two parse trees.
+///
+/// Its [`Display`](fmt::Display) renders the merged tree like
+/// [`PairTree::format_as_tree`](crate::iterators::PairTree::format_as_tree),
+/// with each line prefixed by `+` (inserted), `-` (deleted), `>` (moved), `~` (updated) or a space.
+#[derive_where(Clone, Debug, Hash, Eq, PartialEq; S: RefStr)]
+pub struct TreeDiff<S, R: RuleType> {
+    changes: Vec<Change<S, R>>,
+    rows: Vec<Row<S, R>>,
+}
+
+impl<S: RefStr, R: RuleType> TreeDiff<S, R> {
+    /// Diff `old` against `new`.
+    pub fn new(old: &Token<S, R>, new: &Token<S, R>) -> Self {
+        let (old, new) = (Node::new(old), Node::new(new));
+        let mut differ = Differ { lines: Vec::new() };
+        if old.token.rule == new.token.rule {
+            differ.node(&old, &new, 0);
+        } else {
+            differ.push_one_side(&old, Mark::Delete, true, 0);
+            differ.push_one_side(&new, Mark::Insert, true, 0);
+        }
+        differ.find_moves();
+        let span = |node: Option<&Node<'_, S, R>>| node.map(|node| node.token.span.clone());
+        let changes = differ
+            .lines
+            .iter()
+            .filter(|line| line.root)
+            .map(|line| {
+                let (old, new) = (span(line.old), span(line.new));
+                match line.mark {
+                    Mark::Insert => Change::Insert(line.new.unwrap().token.rule, new.unwrap()),
+                    Mark::Delete => Change::Delete(line.old.unwrap().token.rule, old.unwrap()),
+                    Mark::Move => {
+                        Change::Move(line.new.unwrap().token.rule, old.unwrap(), new.unwrap())
+                    }
+                    Mark::Update | Mark::Same => {
+                        Change::Update(line.new.unwrap().token.rule, old.unwrap(), new.unwrap())
+                    }
+                }
+            })
+            .collect();
+        let rows = differ
+            .lines
+            .iter()
+            .map(|line| {
+                let token = line.new.or(line.old).unwrap().token;
+                Row {
+                    depth: line.depth,
+                    mark: line.mark,
+                    rule: token.rule,
+                    old: span(line.old),
+                    new: span(line.new),
+                    leaf: token.children.is_empty(),
+                }
+            })
+            .collect();
+        Self { changes, rows }
+    }
+    /// Changes in the order of the merged tree.
+    pub fn changes(&self) -> &[Change<S, R>] {
+        &self.changes
+    }
+    /// Whether the two trees are the same.
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<S: RefStr, R: RuleType> fmt::Display for TreeDiff<S, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            let mark = match row.mark {
+                Mark::Same => ' ',
+                Mark::Insert => '+',
+                Mark::Delete => '-',
+                Mark::Move => '>',
+                Mark::Update => '~',
+            };
+            write!(f, "{} {}{:?}", mark, "    ".repeat(row.depth), row.rule)?;
+            match (row.mark, &row.old, &row.new) {
+                (Mark::Update, Some(old), Some(new)) => {
+                    write!(f, " {:?} -> {:?}", old.as_str(), new.as_str())?
+                }
+                (Mark::Move, Some(old), Some(new)) => write!(
+                    f,
+                    " {}..{} -> {}..{}",
+                    old.start(),
+                    old.end(),
+                    new.start(),
+                    new.end()
+                )?,
+                (_, old, new) if row.leaf => {
+                    if let Some(span) = new.as_ref().or(old.as_ref()) {
+                        write!(f, " {:?}", span.as_str())?
+                    }
+                }
+                _ => (),
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,531 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! A flat parse tree stored in a single [`Vec`].
+
+use crate::{
+    input::RefStr,
+    iterators::{Pair, PairVisitor, ThinToken, Token},
+    RuleType, Span,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    fmt,
+    ops::{ControlFlow, Range},
+};
+
+/// A node in a [`FlatTree`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FlatNode<R> {
+    /// Rule.
+    pub rule: R,
+    /// Start position.
+    pub start: usize,
+    /// End position.
+    pub end: usize,
+    /// Index of the parent, [`None`] for the root.
+    pub parent: Option<usize>,
+    /// Index after the last descendant.
+    ///
+    /// Descendants are at `index + 1..subtree_end` in pre-order.
+    pub subtree_end: usize,
+}
+
+/// A parse tree stored in a single [`Vec`] of [`FlatNode`]s in pre-order.
+///
+/// This is the same tree as [`ThinToken`], but without nested [`Vec`]s,
+/// so it is cheap to store, to send between threads and to (de)serialize.
+/// Nodes are referred by their indices, and the root is at index `0`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct FlatTree<R> {
+    nodes: Vec<FlatNode<R>>,
+}
+
+/// Push pairs in pre-order.
+struct Builder<R> {
+    nodes: Vec<FlatNode<R>>,
+    parent: Option<usize>,
+}
+impl<'t, S, R: RuleType> PairVisitor<'t, S, R> for Builder<R> {
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let span = pair.span();
+        let index = self.push(pair.rule(), span.start(), span.end());
+        let parent = self.parent.replace(index);
+        let flow = pair.visit_children(self);
+        self.parent = parent;
+        self.nodes[index].subtree_end = self.nodes.len();
+        flow
+    }
+    fn visit_detached_children<P: Pair<S, R>>(&mut self, pair: &P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        pair.visit_children(self)
+    }
+}
+impl<R: RuleType> Builder<R> {
+    const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            parent: None,
+        }
+    }
+    fn push(&mut self, rule: R, start: usize, end: usize) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(FlatNode {
+            rule,
+            start,
+            end,
+            parent: self.parent,
+            subtree_end: index + 1,
+        });
+        index
+    }
+    fn push_thin(&mut self, token: &ThinToken<R>) {
+        let index = self.push(token.rule, token.start, token.end);
+        let parent = self.parent.replace(index);
+        token
+            .children
+            .iter()
+            .for_each(|child| self.push_thin(child));
+        self.parent = parent;
+        self.nodes[index].subtree_end = self.nodes.len();
+    }
+    fn push_token<S>(&mut self, token: &Token<S, R>) {
+        let index = self.push(token.rule, token.span.start(), token.span.end());
+        let parent = self.parent.replace(index);
+        token
+            .children
+            .iter()
+            .for_each(|child| self.push_token(child));
+        self.parent = parent;
+        self.nodes[index].subtree_end = self.nodes.len();
+    }
+    fn finish(self) -> FlatTree<R> {
+        FlatTree { nodes: self.nodes }
+    }
+}
+
+impl<R: RuleType> FlatTree<R> {
+    /// Build from a [`Pair`] as the root.
+    ///
+    /// Pairs are visited by reference, so no [`Token`] is built.
+    pub fn from_pair<S: RefStr>(pair: &impl Pair<S, R>) -> Self {
+        let mut builder = Builder::new();
+        let _ = builder.visit(pair);
+        builder.finish()
+    }
+    /// Build from a [`ThinToken`] as the root.
+    pub fn from_thin_token(token: &ThinToken<R>) -> Self {
+        let mut builder = Builder::new();
+        builder.push_thin(token);
+        builder.finish()
+    }
+    /// Build from a [`Token`] as the root.
+    pub fn from_token<S>(token: &Token<S, R>) -> Self {
+        let mut builder = Builder::new();
+        builder.push_token(token);
+        builder.finish()
+    }
+    /// All nodes in pre-order.
+    #[inline]
+    pub fn nodes(&self) -> &[FlatNode<R>] {
+        &self.nodes
+    }
+    /// Count of nodes, which is never zero.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    /// Always `false`, as there is a root.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    /// The root node.
+    #[inline]
+    pub fn root(&self) -> &FlatNode<R> {
+        &self.nodes[0]
+    }
+    /// Get the node at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&FlatNode<R>> {
+        self.nodes.get(index)
+    }
+    /// Index of the parent of the node at `index`.
+    #[inline]
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.nodes.get(index)?.parent
+    }
+    /// Index of the first child of the node at `index`.
+    #[inline]
+    pub fn first_child(&self, index: usize) -> Option<usize> {
+        let node = self.nodes.get(index)?;
+        (index + 1 < node.subtree_end).then_some(index + 1)
+    }
+    /// Index of the next sibling of the node at `index`.
+    #[inline]
+    pub fn next_sibling(&self, index: usize) -> Option<usize> {
+        let node = self.nodes.get(index)?;
+        let parent = &self.nodes[node.parent?];
+        (node.subtree_end < parent.subtree_end).then_some(node.subtree_end)
+    }
+    /// Indices of the children of the node at `index`.
+    #[inline]
+    pub fn children(&self, index: usize) -> Children<'_, R> {
+        Children {
+            tree: self,
+            next: self.first_child(index),
+        }
+    }
+    /// Indices of the descendants of the node at `index` in pre-order.
+    #[inline]
+    pub fn descendants(&self, index: usize) -> Range<usize> {
+        match self.nodes.get(index) {
+            Some(node) => index + 1..node.subtree_end,
+            None => index..index,
+        }
+    }
+    /// Convert the subtree at `index` into a [`ThinToken`].
+    pub fn to_thin_token(&self, index: usize) -> Option<ThinToken<R>> {
+        let node = self.nodes.get(index)?;
+        let children = self
+            .children(index)
+            .filter_map(|child| self.to_thin_token(child))
+            .collect();
+        Some(ThinToken {
+            rule: node.rule,
+            start: node.start,
+            end: node.end,
+            children,
+        })
+    }
+    /// Convert the subtree at `index` into a [`Token`] with spans in `input`.
+    ///
+    /// Returns [`None`] if `index` or any position is out of bound.
+    pub fn to_token<S: RefStr>(&self, input: S, index: usize) -> Option<Token<S, R>> {
+        let node = self.nodes.get(index)?;
+        let span = Span::new(input.clone(), node.start, node.end)?;
+        let children = self
+            .children(index)
+            .map(|child| self.to_token(input.clone(), child))
+            .collect::<Option<_>>()?;
+        Some(Token {
+            rule: node.rule,
+            span,
+            children,
+        })
+    }
+
+    /// Encode into bytes.
+    ///
+    /// Rules are stored by their [names](RuleType::name),
+    /// so the bytes can still be decoded after rules are reordered in the grammar.
+    /// Numbers are stored as LEB128 variable-length integers.
+    ///
+    /// The layout is:
+    ///
+    /// - Magic bytes `PTFT` and the version `1`.
+    /// - Count of rule names, and each name as its length and its UTF-8 bytes.
+    /// - Count of nodes, and each node as the index of its rule name,
+    ///   its start, its length and the count of its descendants.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut names: Vec<R> = Vec::new();
+        let mut indices = BTreeMap::new();
+        let mut rules = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let index = *indices.entry(node.rule).or_insert_with(|| {
+                names.push(node.rule);
+                names.len() - 1
+            });
+            rules.push(index);
+        }
+        let mut bytes = Vec::from(MAGIC);
+        bytes.push(VERSION);
+        write_uint(&mut bytes, names.len());
+        for rule in names {
+            let name = rule.name();
+            write_uint(&mut bytes, name.len());
+            bytes.extend_from_slice(name.as_bytes());
+        }
+        write_uint(&mut bytes, self.nodes.len());
+        for (index, (node, rule)) in self.nodes.iter().zip(rules).enumerate() {
+            write_uint(&mut bytes, rule);
+            write_uint(&mut bytes, node.start);
+            write_uint(&mut bytes, node.end - node.start);
+            write_uint(&mut bytes, node.subtree_end - index - 1);
+        }
+        bytes
+    }
+    /// Decode from bytes created by [`FlatTree::to_bytes`].
+    ///
+    /// `rule` looks up a rule by its name.
+    pub fn from_bytes(
+        bytes: &[u8],
+        mut rule: impl FnMut(&str) -> Option<R>,
+    ) -> Result<Self, DecodeError> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(DecodeError::InvalidMagic);
+        }
+        let version = reader.take(1)?[0];
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let count = reader.uint()?;
+        let mut rules = Vec::new();
+        for _ in 0..count {
+            let len = reader.uint()?;
+            let name =
+                core::str::from_utf8(reader.take(len)?).map_err(|_| DecodeError::InvalidData)?;
+            let found = rule(name).ok_or_else(|| DecodeError::UnknownRule(name.into()))?;
+            rules.push(found);
+        }
+        let count = reader.uint()?;
+        if count == 0 {
+            return Err(DecodeError::InvalidData);
+        }
+        let mut nodes: Vec<FlatNode<R>> = Vec::new();
+        // Indices of nodes whose subtrees are not finished.
+        let mut open: Vec<usize> = Vec::new();
+        for index in 0..count {
+            let rule = *rules.get(reader.uint()?).ok_or(DecodeError::InvalidData)?;
+            let start = reader.uint()?;
+            let end = start
+                .checked_add(reader.uint()?)
+                .ok_or(DecodeError::InvalidData)?;
+            let subtree_end = reader
+                .uint()?
+                .checked_add(index + 1)
+                .ok_or(DecodeError::InvalidData)?;
+            while let Some(&last) = open.last() {
+                if nodes[last].subtree_end > index {
+                    break;
+                }
+                open.pop();
+            }
+            let parent = open.last().copied();
+            let nested = match parent {
+                Some(parent) => subtree_end <= nodes[parent].subtree_end,
+                None => index == 0 && subtree_end == count,
+            };
+            if !nested || start > end {
+                return Err(DecodeError::InvalidData);
+            }
+            nodes.push(FlatNode {
+                rule,
+                start,
+                end,
+                parent,
+                subtree_end,
+            });
+            open.push(index);
+        }
+        if !reader.bytes.is_empty() {
+            return Err(DecodeError::InvalidData);
+        }
+        Ok(Self { nodes })
+    }
+}
+
+/// Iterator over indices of children in a [`FlatTree`].
+#[derive(Clone, Debug)]
+pub struct Children<'t, R> {
+    tree: &'t FlatTree<R>,
+    next: Option<usize>,
+}
+impl<R: RuleType> Iterator for Children<'_, R> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.tree.next_sibling(current);
+        Some(current)
+    }
+}
+
+impl<R: RuleType> From<&ThinToken<R>> for FlatTree<R> {
+    #[inline]
+    fn from(token: &ThinToken<R>) -> Self {
+        Self::from_thin_token(token)
+    }
+}
+
+impl<S, R: RuleType> From<&Token<S, R>> for FlatTree<R> {
+    #[inline]
+    fn from(token: &Token<S, R>) -> Self {
+        Self::from_token(token)
+    }
+}
+
+/// Error in [`FlatTree::from_bytes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The bytes do not start with the magic bytes.
+    InvalidMagic,
+    /// The bytes are encoded in an unsupported version.
+    UnsupportedVersion(u8),
+    /// A rule name is not known.
+    UnknownRule(String),
+    /// The bytes end unexpectedly.
+    UnexpectedEnd,
+    /// The bytes are not a valid tree.
+    InvalidData,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("Not an encoded flat tree."),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported flat tree version {}.", version)
+            }
+            Self::UnknownRule(name) => write!(f, "Unknown rule {:?}.", name),
+            Self::UnexpectedEnd => f.write_str("Unexpected end of bytes."),
+            Self::InvalidData => f.write_str("Invalid flat tree."),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+const MAGIC: &[u8] = b"PTFT";
+const VERSION: u8 = 1;
+
+fn write_uint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+struct Reader<'b> {
+    bytes: &'b [u8],
+}
+impl<'b> Reader<'b> {
+    const fn take(&mut self, len: usize) -> Result<&'b [u8], DecodeError> {
+        if self.bytes.len() < len {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+    fn uint(&mut self) -> Result<usize, DecodeError> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.take(1)?[0];
+            let bits = usize::from(byte & 0x7f);
+            if bits.checked_shl(shift).map(|v| v >> shift) != Some(bits) {
+                return Err(DecodeError::InvalidData);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::InvalidData)
+    }
+}
+
+/// A cursor over a tree, similar to the one in tree-sitter.
+///
+/// The tree is stored as a [`FlatTree`], so the cursor can move up to the parent,
+/// and nodes are returned as [`Span`]s in the input.
+#[derive(Clone, Debug)]
+pub struct TreeCursor<S, R> {
+    tree: FlatTree<R>,
+    input: S,
+    index: usize,
+    depth: usize,
+}
+
+impl<S: RefStr, R: RuleType> TreeCursor<S, R> {
+    /// Create a cursor at the root of `tree`, whose positions are in `input`.
+    pub const fn new(tree: FlatTree<R>, input: S) -> Self {
+        Self {
+            tree,
+            input,
+            index: 0,
+            depth: 0,
+        }
+    }
+    /// Create a cursor at a [`Pair`] as the root.
+    pub fn from_pair(pair: &impl Pair<S, R>) -> Self {
+        Self::new(FlatTree::from_pair(pair), pair.span().get_input())
+    }
+    /// Create a cursor at a [`Token`] as the root.
+    pub fn from_token(token: &Token<S, R>) -> Self {
+        Self::new(FlatTree::from_token(token), token.span.get_input())
+    }
+    /// The tree.
+    #[inline]
+    pub const fn tree(&self) -> &FlatTree<R> {
+        &self.tree
+    }
+    /// Index of the current node in the [`FlatTree`].
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+    /// Depth of the current node, `0` for the root.
+    #[inline]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+    /// The current node.
+    #[inline]
+    pub fn node(&self) -> &FlatNode<R> {
+        &self.tree.nodes[self.index]
+    }
+    /// Rule of the current node.
+    #[inline]
+    pub fn rule(&self) -> R {
+        self.node().rule
+    }
+    /// Span of the current node.
+    #[inline]
+    pub fn span(&self) -> Span<S> {
+        let node = self.node();
+        Span::new(self.input.clone(), node.start, node.end)
+            .expect("Positions in the tree should be valid in the input.")
+    }
+    /// Move to the parent. Returns `false` at the root.
+    pub fn goto_parent(&mut self) -> bool {
+        self.goto(self.tree.parent(self.index), |depth| depth - 1)
+    }
+    /// Move to the first child. Returns `false` if there is no child.
+    pub fn goto_first_child(&mut self) -> bool {
+        self.goto(self.tree.first_child(self.index), |depth| depth + 1)
+    }
+    /// Move to the next sibling. Returns `false` if there is no next sibling.
+    pub fn goto_next_sibling(&mut self) -> bool {
+        self.goto(self.tree.next_sibling(self.index), |depth| depth)
+    }
+    /// Move back to the root.
+    pub const fn reset(&mut self) {
+        self.index = 0;
+        self.depth = 0;
+    }
+    fn goto(&mut self, index: Option<usize>, depth: impl FnOnce(usize) -> usize) -> bool {
+        match index {
+            Some(index) => {
+                self.index = index;
+                self.depth = depth(self.depth);
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -68,12 +68,10 @@ struct Partition2<'i> {
     _p: PhantomData<&'i str>,
 }
 impl<'i> Partition2<'i> {
-    fn new<'s: 'i>(line: usize, s: &'s str, col_start: usize, col_end: usize) -> Self {
-        let (former, latter) = s.split_at(col_end);
-        let (former, middle) = former.split_at(col_start);
-        let former = visualize_ws_and_cntrl(former);
-        let middle = visualize_ws_and_cntrl(middle);
-        let latter = visualize_ws_and_cntrl(latter);
+    fn new<S: RefStr>(line: usize, s: &S, col_start: usize, col_end: usize) -> Self {
+        let former = visualize_ws_and_cntrl(&s.get_checked(..col_start).to_text());
+        let middle = visualize_ws_and_cntrl(&s.get_checked(col_start..col_end).to_text());
+        let latter = visualize_ws_and_cntrl(&s.get_checked(col_end..).to_text());
         let _p = PhantomData;
         Self {
             line,
@@ -92,10 +90,9 @@ struct Partition<'i> {
     _p: PhantomData<&'i str>,
 }
 impl<'i> Partition<'i> {
-    fn new<'s: 'i>(line: usize, s: &'s str, col: usize) -> Self {
-        let (former, latter) = s.split_at(col);
-        let former = visualize_ws_and_cntrl(former);
-        let latter = visualize_ws_and_cntrl(latter);
+    fn new<S: RefStr>(line: usize, s: &S, col: usize) -> Self {
+        let former = visualize_ws_and_cntrl(&s.get_checked(..col).to_text());
+        let latter = visualize_ws_and_cntrl(&s.get_checked(col..).to_text());
         let _p = PhantomData;
         Self {
             line,
@@ -365,28 +362,25 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
             .take(end.line - start.line + 1);
         let index_digit = Self::ceil_log10(end.line + 1);
         if start.line == end.line {
-            let cur_line = lines.next();
-            let cur_line = cur_line.as_ref().map(S::as_str).unwrap_or("");
-            let line = Partition2::new(start.line, cur_line, start.col, end.col);
+            let cur_line = lines.next().unwrap_or_else(|| S::from_static(""));
+            let line = Partition2::new(start.line, &cur_line, start.col, end.col);
             self.display_snippet_single_line(f, index_digit, line)?;
         } else {
             let lines: Vec<_> = lines.collect();
-            let start_line = lines.first().unwrap().as_str();
-            let end_line = lines.last().unwrap().as_str();
-            let start = Partition::new(start.line, start_line, start.col);
-            let end = Partition::new(end.line, end_line, end.col);
+            let start = Partition::new(start.line, lines.first().unwrap(), start.col);
+            let end = Partition::new(end.line, lines.last().unwrap(), end.col);
             let inner_first = if lines.len() >= 3 {
-                Some(visualize_ws_and_cntrl(lines[1].as_str()))
+                Some(visualize_ws_and_cntrl(&lines[1].to_text()))
             } else {
                 None
             };
             let inner_mid = match lines.len() {
                 6.. => (None, true),
-                5 => (Some(visualize_ws_and_cntrl(lines[2].as_str())), false),
+                5 => (Some(visualize_ws_and_cntrl(&lines[2].to_text())), false),
                 _ => (None, false),
             };
             let inner_last = if lines.len() >= 4 {
-                Some(visualize_ws_and_cntrl(lines[lines.len() - 2].as_str()))
+                Some(visualize_ws_and_cntrl(&lines[lines.len() - 2].to_text()))
             } else {
                 None
             };
@@ -422,7 +416,7 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
                 let l = *index;
                 let c = position.pos() - pos;
                 let index_digit = Self::ceil_log10(l + 1);
-                let line = Partition::new(l, line.as_str(), c);
+                let line = Partition::new(l, line, c);
                 self.display_snippet_single_pos(f, index_digit, line)?;
                 break;
             }
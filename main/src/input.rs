@@ -3,17 +3,16 @@
 //!! This module defines the [`Str`], [`Input`] and [`Cursor`] traits, which are used to abstract over
 //! different input types for the parser.
 //!
-//! - [`Str`]: A trait for string-like types that can be used as input.
+//! - [`RefStr`]: A trait for string-like types that can be used as input, including `&str`, `&[u8]` and [`Tokens`](crate::token::Tokens).
 //! - [`Input`]: A trait for types that may have extra span information, such as [`Position`] and [`Span`].
 //! - [`Cursor`]: A trait for types that can traverse the input, such as [`Position`], [`PositionCursor`] and [`SpanCursor`].
 use crate::{Position, Span};
-use alloc::string::String;
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use core::{
     fmt,
     hash::Hash,
-    ops::{Range, RangeBounds},
+    ops::{Deref, Range, RangeBounds},
     ptr,
-    str::Chars,
 };
 
 /// Cursor with span information.
@@ -33,7 +32,7 @@ pub unsafe trait Cursor: Sized + Clone {
     /// Get unconsumed string.
     fn get(&self) -> Self::String;
     // /// Get unconsumed characters.
-    // fn chars(&self) -> Chars<'_> {
+    // fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
     //     self.get().chars()
     // }
 
@@ -58,7 +57,7 @@ pub unsafe trait Cursor: Sized + Clone {
     fn match_string(&mut self, string: &str) -> bool {
         let res = self.get().starts_with(string);
         if res {
-            unsafe { *self.cursor() += string.len() };
+            unsafe { *self.cursor() += Self::String::str_len(string) };
         }
         res
     }
@@ -67,7 +66,7 @@ pub unsafe trait Cursor: Sized + Clone {
     fn match_insensitive(&mut self, string: &str) -> bool {
         let res = self.get().starts_with_insensitive(string);
         if res {
-            unsafe { *self.cursor() += string.len() };
+            unsafe { *self.cursor() += Self::String::str_len(string) };
         }
         res
     }
@@ -78,11 +77,9 @@ pub unsafe trait Cursor: Sized + Clone {
             let Some(string) = self.input().get(from..) else {
                 continue;
             };
-            let bytes = string.as_str().as_bytes();
 
             for slice in strings.iter() {
-                let to = slice.len();
-                if Some(slice.as_bytes()) == bytes.get(0..to) {
+                if string.starts_with(slice) {
                     unsafe { *self.cursor() = from };
                     return true;
                 }
@@ -101,7 +98,7 @@ pub unsafe trait Cursor: Sized + Clone {
             let mut chars = unconsumed.chars();
             for _ in 0..n {
                 if let Some(c) = chars.next() {
-                    len += c.len_utf8();
+                    len += Self::String::char_len(c);
                 } else {
                     return false;
                 }
@@ -112,12 +109,24 @@ pub unsafe trait Cursor: Sized + Clone {
         unsafe { *self.cursor() += skipped };
         true
     }
+    /// Match a slice of the input, such as a span pushed onto the stack.
+    #[inline]
+    fn match_slice(&mut self, slice: &Self::String) -> bool {
+        let res = self
+            .get()
+            .get(..slice.len())
+            .is_some_and(|prefix| &prefix == slice);
+        if res {
+            unsafe { *self.cursor() += slice.len() };
+        }
+        res
+    }
     /// Match a character in a range.
     #[inline]
     fn match_range(&mut self, range: Range<char>) -> bool {
         if let Some(c) = self.get().chars().next() {
             if range.start <= c && c <= range.end {
-                unsafe { *self.cursor() += c.len_utf8() };
+                unsafe { *self.cursor() += Self::String::char_len(c) };
                 true
             } else {
                 false
@@ -131,7 +140,7 @@ pub unsafe trait Cursor: Sized + Clone {
     fn match_char_by(&mut self, f: impl FnOnce(char) -> bool) -> bool {
         if let Some(c) = self.get().chars().next() {
             if f(c) {
-                unsafe { *self.cursor() += c.len_utf8() };
+                unsafe { *self.cursor() += Self::String::char_len(c) };
                 true
             } else {
                 false
@@ -145,7 +154,7 @@ pub unsafe trait Cursor: Sized + Clone {
     fn advance_char(&mut self) -> Option<char> {
         let c = self.get().chars().next();
         if let Some(c) = c {
-            unsafe { *self.cursor() += c.len_utf8() };
+            unsafe { *self.cursor() += Self::String::char_len(c) };
         }
         c
     }
@@ -201,6 +210,11 @@ unsafe impl<S: RefStr> Cursor for Position<S> {
         c
     }
 
+    #[inline]
+    fn skip_until(&mut self, strings: &[&str]) -> bool {
+        skip_until_unbounded(self, strings)
+    }
+
     #[inline(always)]
     unsafe fn cursor(&mut self) -> &mut usize {
         &mut self.pos
@@ -214,6 +228,31 @@ unsafe impl<S: RefStr> Cursor for Position<S> {
     fn end(&self) -> usize {
         self.input().len()
     }
+
+    #[inline]
+    fn at_end(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+/// Skip until one of several strings without measuring the whole input, which may be read on demand.
+#[inline]
+fn skip_until_unbounded<C: Cursor>(cursor: &mut C, strings: &[&str]) -> bool {
+    let input = cursor.input();
+    let mut from = cursor.byte_offset();
+    loop {
+        if let Some(string) = input.get(from..) {
+            if string.is_empty() {
+                unsafe { *cursor.cursor() = from };
+                return false;
+            }
+            if strings.iter().any(|slice| string.starts_with(slice)) {
+                unsafe { *cursor.cursor() = from };
+                return true;
+            }
+        }
+        from += 1;
+    }
 }
 
 /// A part of input.
@@ -271,6 +310,11 @@ unsafe impl<S: RefStr> Cursor for PositionCursor<S> {
         unsafe { self.input.get_range_unchecked(self.cursor..) }
     }
 
+    #[inline]
+    fn skip_until(&mut self, strings: &[&str]) -> bool {
+        skip_until_unbounded(self, strings)
+    }
+
     #[inline(always)]
     unsafe fn cursor(&mut self) -> &mut usize {
         &mut self.cursor
@@ -284,6 +328,11 @@ unsafe impl<S: RefStr> Cursor for PositionCursor<S> {
     fn end(&self) -> usize {
         self.input().len()
     }
+
+    #[inline]
+    fn at_end(&self) -> bool {
+        self.get().is_empty()
+    }
 }
 
 unsafe impl<S: RefStr> Cursor for SpanCursor<S> {
@@ -342,7 +391,7 @@ pub trait Input: Clone {
 pub unsafe trait RefStr: Clone + Hash + PartialEq + Eq + fmt::Debug {
     /// Create from a static string.
     fn from_static(s: &'static str) -> Self;
-    /// Get length in bytes.
+    /// Get length in bytes, or in tokens for [`Tokens`](crate::token::Tokens).
     fn len(&self) -> usize;
     /// Check if is empty.
     #[inline(always)]
@@ -350,12 +399,53 @@ pub unsafe trait RefStr: Clone + Hash + PartialEq + Eq + fmt::Debug {
         self.len() == 0
     }
     /// Convert to a string.
-    fn as_str(&self) -> &str;
+    ///
+    /// Only available for string types. Use [`to_text()`](RefStr::to_text) for other inputs.
+    #[inline(always)]
+    fn as_str(&self) -> &str
+    where
+        Self: Deref<Target = str>,
+    {
+        self
+    }
+    /// Convert to text, such as to report errors.
+    ///
+    /// Borrowed for string types. Byte inputs are decoded as Latin-1,
+    /// see [`RefStr` for `&[u8]`](#impl-RefStr-for-%26%5Bu8%5D).
+    fn to_text(&self) -> Cow<'_, str>;
+    /// Get the length of a character in bytes, which is [`char::len_utf8`] for UTF-8 strings.
+    #[inline(always)]
+    fn char_len(c: char) -> usize {
+        c.len_utf8()
+    }
+    /// Get the length of a string literal once matched, which is [`str::len`] for strings.
+    #[inline(always)]
+    fn str_len(s: &str) -> usize {
+        s.len()
+    }
+    /// Get the text to report errors in, and the offset of `pos` in it,
+    /// or `None` if the text at `pos` is not available.
+    ///
+    /// The text contains at least the line containing `pos`.
+    /// It's the text from [`to_text()`](RefStr::to_text) by default.
+    #[inline]
+    fn text_at(&self, pos: usize) -> Option<(Cow<'_, str>, usize)> {
+        let offset = self.get(..pos)?.to_text().len();
+        Some((self.to_text(), offset))
+    }
+    /// Get the number of lines before the text from [`text_at()`](RefStr::text_at)
+    /// if only part of the input is kept, so that lines in errors are counted in that text.
+    ///
+    /// It's `None` by default, and lines are located with a [`LineIndexer`](crate::line_indexer::LineIndexer).
+    #[inline(always)]
+    fn lines_before_text(&self) -> Option<usize> {
+        None
+    }
     /// Get a substring.
     ///
     /// # Safety
     ///
-    /// The range must be in the bounds of the string and aligned to UTF-8 character boundaries.
+    /// The range must be in the bounds of the string and aligned to character boundaries.
     unsafe fn get_range_unchecked(&self, range: impl RangeBounds<usize>) -> Self;
     /// Get a substring.
     fn get(&self, range: impl RangeBounds<usize>) -> Option<Self>;
@@ -366,7 +456,7 @@ pub unsafe trait RefStr: Clone + Hash + PartialEq + Eq + fmt::Debug {
     /// Check if starts with a string insensitively.
     fn starts_with_insensitive(&self, string: &str) -> bool;
     /// Get characters iterator.
-    fn chars(&self) -> Chars<'_>;
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_;
     /// Check if two references point to the same string.
     fn ptr_eq(&self, other: &Self) -> bool;
     /// Hash the pointer of the string.
@@ -393,6 +483,16 @@ impl<'i> Input for &'i String {
     }
 }
 
+impl<'i> Input for &'i Vec<u8> {
+    type Cursor = Position<&'i [u8]>;
+    type String = &'i [u8];
+
+    #[inline]
+    fn as_cursor(&self) -> Self::Cursor {
+        Position::from_start(self)
+    }
+}
+
 unsafe impl RefStr for &str {
     #[inline(always)]
     fn from_static(s: &'static str) -> Self {
@@ -405,8 +505,8 @@ unsafe impl RefStr for &str {
     }
 
     #[inline(always)]
-    fn as_str(&self) -> &str {
-        self
+    fn to_text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
     }
 
     #[inline(always)]
@@ -506,7 +606,7 @@ unsafe impl RefStr for &str {
     }
 
     #[inline(always)]
-    fn chars(&self) -> Chars<'_> {
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
         str::chars(self)
     }
 
@@ -521,6 +621,101 @@ unsafe impl RefStr for &str {
     }
 }
 
+/// Bytes as input, for binary and non-UTF-8 formats.
+///
+/// Bytes are Latin-1 (ISO 8859-1) characters, that is, each byte is the character with the same code point,
+/// from U+0000 to U+00FF:
+///
+/// - `ANY`, character ranges and other single-character expressions match a single byte.
+/// - String literals match byte by byte, so `'é'` and `"é"` both match the byte `0xE9`,
+///   and `"\u{ff}"` matches the byte `0xFF`.
+///   Characters above U+00FF never match.
+/// - [`to_text`](RefStr::to_text) decodes bytes in the same way.
+///
+/// Strings from [`from_static`](RefStr::from_static), such as literals pushed by `PUSH_LITERAL`,
+/// are borrowed as is, so they should be ASCII, which is the same in Latin-1 and UTF-8.
+unsafe impl RefStr for &[u8] {
+    #[inline(always)]
+    fn from_static(s: &'static str) -> Self {
+        s.as_bytes()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    #[inline]
+    fn to_text(&self) -> Cow<'_, str> {
+        match self.is_ascii() {
+            // SAFETY: ASCII is valid UTF-8.
+            true => Cow::Borrowed(unsafe { core::str::from_utf8_unchecked(self) }),
+            false => Cow::Owned(self.iter().map(|&b| char::from(b)).collect()),
+        }
+    }
+
+    #[inline(always)]
+    fn char_len(_c: char) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    unsafe fn get_range_unchecked(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        <[u8]>::get_unchecked(self, range)
+    }
+
+    #[inline(always)]
+    fn get(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        <[u8]>::get(self, range)
+    }
+
+    #[inline(always)]
+    fn get_checked(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        &self[range]
+    }
+
+    #[inline(always)]
+    fn str_len(s: &str) -> usize {
+        s.chars().count()
+    }
+
+    #[inline]
+    fn starts_with(&self, string: &str) -> bool {
+        let mut bytes = self.iter();
+        string
+            .chars()
+            .all(|c| bytes.next().is_some_and(|&b| char::from(b) == c))
+    }
+
+    #[inline]
+    fn starts_with_insensitive(&self, string: &str) -> bool {
+        let mut bytes = self.iter();
+        string.chars().all(|c| {
+            bytes
+                .next()
+                .is_some_and(|&b| char::from(b).to_lowercase().eq(c.to_lowercase()))
+        })
+    }
+
+    #[inline(always)]
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        self.iter().map(|&b| char::from(b))
+    }
+
+    #[inline(always)]
+    fn ptr_eq(&self, other: &Self) -> bool {
+        ptr::eq::<[u8]>(*self, *other)
+    }
+
+    #[inline(always)]
+    fn ptr_hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        ptr::hash::<[u8], H>(*self, state);
+    }
+}
+
 #[cfg(feature = "shared-string")]
 mod impl_shared_string {
     use super::*;
@@ -539,8 +734,8 @@ mod impl_shared_string {
         }
 
         #[inline(always)]
-        fn as_str(&self) -> &str {
-            self
+        fn to_text(&self) -> Cow<'_, str> {
+            Cow::Borrowed(self)
         }
 
         #[inline(always)]
@@ -572,7 +767,7 @@ mod impl_shared_string {
         }
 
         #[inline(always)]
-        fn chars(&self) -> Chars<'_> {
+        fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
             str::chars(self)
         }
 
@@ -607,8 +802,8 @@ mod impl_shared_vec {
         }
 
         #[inline(always)]
-        fn as_str(&self) -> &str {
-            self
+        fn to_text(&self) -> Cow<'_, str> {
+            Cow::Borrowed(self)
         }
 
         #[inline(always)]
@@ -640,7 +835,7 @@ mod impl_shared_vec {
         }
 
         #[inline(always)]
-        fn chars(&self) -> Chars<'_> {
+        fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
             str::chars(self)
         }
 
@@ -835,4 +1030,39 @@ mod tests {
 
         assert!(cursor.at_end());
     }
+
+    #[test]
+    fn bytes_match() {
+        let input: &[u8] = b"\xe9t\xe9, OK\xff";
+        let mut cursor = input.as_cursor();
+
+        assert!(cursor.match_range('\u{e9}'..'\u{e9}'));
+        assert_eq!(cursor.byte_offset(), 1);
+        assert_eq!(cursor.advance_char(), Some('t'));
+        assert!(cursor.match_char_by(|c| c == '\u{e9}'));
+        assert!(cursor.match_string(", "));
+        assert!(cursor.match_insensitive("ok"));
+        assert_eq!(cursor.byte_offset(), 7);
+        assert!(cursor.skip(1));
+        assert!(cursor.at_end());
+
+        assert_eq!(input.to_text(), "\u{e9}t\u{e9}, OK\u{ff}");
+        assert_eq!(b"ascii".as_slice().to_text(), Cow::Borrowed("ascii"));
+    }
+
+    #[test]
+    fn bytes_match_latin1_literal() {
+        let input: &[u8] = b"\xe9\xc9\xff";
+        let mut cursor = input.as_cursor();
+
+        assert!(cursor.clone().match_range('é'..'é'));
+        assert!(cursor.match_string("é"));
+        assert!(cursor.match_insensitive("é"));
+        assert!(!cursor.clone().match_string("ÿ\u{100}"));
+        assert!(cursor.match_string("\u{ff}"));
+        assert!(cursor.at_end());
+
+        let input: &[u8] = "é".as_bytes();
+        assert!(!input.as_cursor().match_string("é"));
+    }
 }
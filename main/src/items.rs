@@ -0,0 +1,154 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parse a sequence of items lazily.
+
+use crate::{
+    error::Error,
+    predefined_node::{Empty, EOI},
+    span::Span,
+    tracker::Tracker,
+    Cursor, Input, RuleType, Stack, TypedNode,
+};
+use alloc::boxed::Box;
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+/// An iterator that parses items of type `T` one at a time.
+///
+/// This behaves like `SOI ~ (T ~ (Sep ~ T)* ~ Sep?)? ~ EOI`,
+/// but each item is yielded as soon as it is parsed,
+/// so the whole sequence is never held in memory.
+///
+/// `Sep` is parsed between items and may also follow the last item.
+/// Use a trivia rule (or the default [`Empty`]) if items are not separated.
+/// Items end once a separator and an item consume nothing, as they would be repeated forever.
+///
+/// The [`Stack`] and the [`Tracker`] are shared by all items,
+/// so errors are the same as those of parsing the whole sequence at once.
+/// If an item can not be parsed, or there is some input left after the last item,
+/// an error is yielded (with `EOI` among the expected rules) and the iteration ends.
+///
+/// ```
+/// # use pest_typed::predefined_node::{Str, ANY};
+/// # use pest_typed::{items::Items, RuleType, StringWrapper};
+/// # #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// # enum Rule { EOI }
+/// # impl RuleType for Rule {
+/// #     fn name(&self) -> &'static str {
+/// #         "EOI"
+/// #     }
+/// # }
+/// # #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// # struct Semi;
+/// # impl StringWrapper for Semi {
+/// #     const CONTENT: &'static str = ";";
+/// # }
+/// let items = Items::<_, Rule, ANY<&str>, Str<&str, Semi>>::new("a;b;", Rule::EOI);
+/// let items = items.map(|item| item.map(|any| any.content)).collect::<Result<Vec<_>, _>>();
+/// assert_eq!(items.unwrap(), vec!['a', 'b']);
+/// ```
+pub struct Items<C: Cursor, R: RuleType, T, Sep = Empty<<C as Cursor>::String>> {
+    cursor: C,
+    stack: Stack<Span<C::String>>,
+    /// [`None`] after the iteration ends.
+    tracker: Option<Tracker<C::String, R>>,
+    rule_eoi: R,
+    first: bool,
+    _phantom: PhantomData<fn() -> (T, Sep)>,
+}
+
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>, Sep: TypedNode<C, R>> Items<C, R, T, Sep> {
+    /// Create an iterator that parses items from the start of `input`.
+    ///
+    /// `rule_eoi` is recorded when the end of input is expected, usually `Rule::EOI`.
+    pub fn new(input: impl Input<Cursor = C>, rule_eoi: R) -> Self {
+        Self::from_cursor(input.as_cursor(), rule_eoi)
+    }
+    /// Create an iterator that parses items from `cursor`.
+    pub fn from_cursor(cursor: C, rule_eoi: R) -> Self {
+        Self {
+            tracker: Some(Tracker::new(cursor.as_position())),
+            cursor,
+            stack: Stack::new(),
+            rule_eoi,
+            first: true,
+            _phantom: PhantomData,
+        }
+    }
+    /// Cursor after the last parsed item.
+    pub const fn cursor(&self) -> &C {
+        &self.cursor
+    }
+    /// Stack shared by all items.
+    pub const fn stack(&self) -> &Stack<Span<C::String>> {
+        &self.stack
+    }
+
+    /// Check that the end of input is reached at `cursor`.
+    fn expect_eoi(&mut self, cursor: C) -> Option<Box<Error<R>>> {
+        let mut tracker = self.tracker.take()?;
+        let stack = &mut self.stack;
+        match tracker.record_during_with(
+            cursor.clone(),
+            |tracker| EOI::try_parse_partial_with(cursor, stack, tracker),
+            self.rule_eoi,
+        ) {
+            Some(_) => None,
+            None => Some(Box::new(tracker.collect(()))),
+        }
+    }
+}
+
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>, Sep: TypedNode<C, R>> Iterator
+    for Items<C, R, T, Sep>
+{
+    type Item = Result<T, Box<Error<R>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tracker = self.tracker.as_mut()?;
+        let start = if self.first {
+            self.cursor.clone()
+        } else {
+            match Sep::try_parse_partial_with(self.cursor.clone(), &mut self.stack, tracker) {
+                Some((next, _)) => next,
+                None => return self.expect_eoi(self.cursor.clone()).map(Err),
+            }
+        };
+        if start.at_end() {
+            self.tracker = None;
+            return None;
+        }
+        match T::try_parse_partial_with(start.clone(), &mut self.stack, tracker) {
+            Some((next, _)) if !self.first && next.byte_offset() == self.cursor.byte_offset() => {
+                let cursor = self.cursor.clone();
+                self.expect_eoi(cursor).map(Err)
+            }
+            Some((next, item)) => {
+                self.cursor = next;
+                self.first = false;
+                Some(Ok(item))
+            }
+            None => self.expect_eoi(start).map(Err),
+        }
+    }
+}
+
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>, Sep: TypedNode<C, R>> FusedIterator
+    for Items<C, R, T, Sep>
+{
+}
+
+impl<C: Cursor, R: RuleType, T, Sep> fmt::Debug for Items<C, R, T, Sep> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Items")
+            .field("cursor", &self.cursor.as_position())
+            .field("finished", &self.tracker.is_none())
+            .finish()
+    }
+}
@@ -10,19 +10,26 @@
 //! Simulates [`pest::iterators`].
 
 use crate::{
+    comments::Comments,
+    diff::TreeDiff,
+    flat_tree::TreeCursor,
     input::RefStr,
+    leaves::{Leaf, LeafKind, LeafText, LeafVisitor},
     predefined_node::{
-        AlwaysFail, AtomicRepeat, CharRange, Empty, Insens, Negative, PeekSlice1, PeekSlice2,
-        Positive, Push, RepeatMin, RepeatMinMax, Skip, Skipped, Str, ANY, DROP, NEWLINE, PEEK,
-        PEEK_ALL, POP, POP_ALL, SOI,
+        AlwaysFail, AtomicRepeat, CharRange, CharSet, Empty, Insens, Negative, NewLineType,
+        Optional, PeekSlice1, PeekSlice2, Positive, Push, RepeatMin, RepeatMinMax, Skip,
+        SkipTrivia, Skipped, Str, Trivia, ANY, DROP, NEWLINE, PEEK, PEEK_ALL, POP, POP_ALL, SOI,
     },
+    query::{Query, QueryMatch},
     typed_node::{RuleStorage, RuleStruct, Spanned},
-    RuleType, Span, StringArrayWrapper, StringWrapper,
+    CharSetWrapper, RuleType, RuleWrapper, Span, StringArrayWrapper, StringWrapper, TriviaWrapper,
 };
 use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
 use core::{
+    any::TypeId,
     iter::{once, Iterator},
     mem::swap,
+    ops::{ControlFlow, Range},
 };
 use derive_where::derive_where;
 #[cfg(feature = "serde")]
@@ -53,6 +60,50 @@ pub struct Token<S, R: RuleType> {
 }
 
 impl<S, R: RuleType> Token<S, R> {
+    /// Tokens whose spans contain the byte at `offset`, from the root to the deepest one.
+    ///
+    /// At the end of this token, where there is no byte, tokens ending there are returned.
+    /// See [`Token::nodes_covering`].
+    #[inline]
+    pub fn node_at(&self, offset: usize) -> Vec<PairView<S, R>>
+    where
+        S: RefStr,
+    {
+        self.nodes_covering(byte_at(offset, self.span.end()))
+    }
+    /// Tokens whose spans contain `range`, from the root to the deepest one.
+    ///
+    /// Like [`PairTree::nodes_covering`], subtrees whose spans do not contain `range` are skipped.
+    pub fn nodes_covering(&self, range: Range<usize>) -> Vec<PairView<S, R>>
+    where
+        S: RefStr,
+    {
+        let covers =
+            |token: &Self| token.span.start() <= range.start && range.end <= token.span.end();
+        let mut chain = Vec::new();
+        let mut current = Some(self).filter(|token| covers(token));
+        while let Some(token) = current {
+            chain.push(PairView {
+                rule: token.rule,
+                span: token.span.clone(),
+            });
+            current = token
+                .children
+                .iter()
+                .take_while(|child| child.span.start() <= range.start)
+                .find(|child| covers(child));
+        }
+        chain
+    }
+    /// The deepest token whose span contains `range`.
+    #[inline]
+    pub fn smallest_enclosing(&self, range: Range<usize>) -> Option<PairView<S, R>>
+    where
+        S: RefStr,
+    {
+        self.nodes_covering(range).pop()
+    }
+
     /// To [`ThinToken`].
     #[inline]
     pub fn to_thin(&self) -> ThinToken<R> {
@@ -78,17 +129,297 @@ impl<Str: RefStr, R: RuleType> serde::Serialize for Token<Str, R> {
         let mut seq = serializer.serialize_struct(self.rule.name(), 3)?;
 
         seq.serialize_field("type", self.rule.name())?;
-        seq.serialize_field("content", self.span.as_str().as_str())?;
+        seq.serialize_field("content", &*self.span.as_str().to_text())?;
         seq.serialize_field("children", &self.children)?;
 
         seq.end()
     }
 }
 
+/// A lightweight view of a [`Pair`], without its children.
+///
+/// Nothing is allocated to create a view.
+#[derive_where(Clone, Debug, Hash, Eq, PartialEq; S: RefStr)]
+pub struct PairView<S, R: RuleType> {
+    /// Rule.
+    pub rule: R,
+    /// Span.
+    pub span: Span<S>,
+}
+
+/// Visitor of [`Pair`]s by reference.
+///
+/// Pairs are visited with their own types, so nothing is allocated or dynamically dispatched.
+/// Visited pairs are borrowed for `'t`, which is usually the lifetime of the root.
+///
+/// Every method returns [`ControlFlow::Break`] to stop the traversal,
+/// which is then returned by [`Pair::visit_children`] and [`Pairs::visit_self_or_children`].
+pub trait PairVisitor<'t, S, R: RuleType> {
+    /// Visit a pair.
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr;
+    /// Visit a pair of a rule struct.
+    ///
+    /// Calls [`PairVisitor::visit`] by default.
+    #[inline]
+    fn visit_rule<P: TypedRule<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        self.visit(pair)
+    }
+    /// Visit children of a pair that is built on demand and dropped afterwards,
+    /// such as one from [`Lazy::force`](crate::predefined_node::Lazy::force).
+    ///
+    /// They can not be borrowed for `'t`, so they are skipped by default.
+    #[inline]
+    fn visit_detached_children<P: Pair<S, R>>(&mut self, pair: &P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let _ = pair;
+        ControlFlow::Continue(())
+    }
+    /// Visit the content of a silent rule.
+    ///
+    /// Visits pairs in the content by default.
+    #[inline]
+    fn visit_silent<P: Pairs<S, R>>(&mut self, rule: R, content: &'t P) -> ControlFlow<()>
+    where
+        Self: Sized,
+    {
+        let _ = rule;
+        content.visit_self_or_children(self)
+    }
+    /// Visit a leaf that is not a pair, such as a string literal or a character.
+    ///
+    /// Ignored by default. See [`PairTree::leaves`].
+    #[inline]
+    fn visit_leaf(&mut self, kind: LeafKind<R>, text: LeafText<'_, S>) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let _ = (kind, text);
+        ControlFlow::Continue(())
+    }
+    /// Visit trivia skipped by [`SkipTrivia`], which keeps only the span of all of them.
+    ///
+    /// The first `whitespace` shapes in `trivia` are from `WHITESPACE`, and the rest are from `COMMENT`.
+    ///
+    /// Ignored by default. See [`PairTree::leaves`].
+    #[inline]
+    fn visit_trivia(&mut self, trivia: &'static [Trivia], whitespace: usize) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let _ = (trivia, whitespace);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Forward [`PairVisitor::visit_detached_children`] to children,
+/// for visitors that do not keep borrowed pairs.
+macro_rules! visit_detached_children {
+    () => {
+        #[inline]
+        fn visit_detached_children<P: Pair<S, R>>(&mut self, pair: &P) -> ControlFlow<()>
+        where
+            S: RefStr,
+        {
+            pair.visit_children(self)
+        }
+    };
+}
+
+/// Calls `f` with each pair converted into a [`Token`].
+struct TokenVisitor<F>(F);
+impl<'t, S, R: RuleType, F: FnMut(Token<S, R>)> PairVisitor<'t, S, R> for TokenVisitor<F> {
+    visit_detached_children!();
+    #[inline]
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        (self.0)(pair.as_token());
+        ControlFlow::Continue(())
+    }
+}
+
+/// Calls `f` with each pair converted into a [`PairView`].
+struct ViewVisitor<F>(F);
+impl<'t, S, R: RuleType, F: FnMut(PairView<S, R>)> PairVisitor<'t, S, R> for ViewVisitor<F> {
+    visit_detached_children!();
+    #[inline]
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        (self.0)(pair.as_view());
+        ControlFlow::Continue(())
+    }
+}
+
+/// Stops at the first pair.
+struct AnyVisitor;
+impl<'t, S, R: RuleType> PairVisitor<'t, S, R> for AnyVisitor {
+    visit_detached_children!();
+    #[inline]
+    fn visit<P: Pair<S, R>>(&mut self, _pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        ControlFlow::Break(())
+    }
+}
+
+/// Collects children as [`PairRef`]s.
+struct ChildrenVisitor<'c, 't, S, R: RuleType>(&'c mut Vec<PairRef<'t, S, R>>);
+impl<'t, S, R: RuleType> PairVisitor<'t, S, R> for ChildrenVisitor<'_, 't, S, R> {
+    #[inline]
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        self.0.push(PairRef(PairRefInner::Borrowed(pair)));
+        ControlFlow::Continue(())
+    }
+    #[inline]
+    fn visit_detached_children<P: Pair<S, R>>(&mut self, pair: &P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        pair.for_each_child(|token| self.0.push(PairRef(PairRefInner::Detached(token))));
+        ControlFlow::Continue(())
+    }
+}
+
+/// The object-safe part of [`Pair`] used by [`PairRef`].
+trait ErasedPair<S, R: RuleType> {
+    fn view(&self) -> PairView<S, R>;
+    fn push_children<'t>(&'t self, children: &mut Vec<PairRef<'t, S, R>>);
+}
+impl<S: RefStr, R: RuleType, P: Pair<S, R>> ErasedPair<S, R> for P {
+    #[inline]
+    fn view(&self) -> PairView<S, R> {
+        self.as_view()
+    }
+    #[inline]
+    fn push_children<'t>(&'t self, children: &mut Vec<PairRef<'t, S, R>>) {
+        let _ = self.visit_children(&mut ChildrenVisitor(children));
+    }
+}
+
+enum PairRefInner<'t, S, R: RuleType> {
+    Borrowed(&'t dyn ErasedPair<S, R>),
+    Detached(Token<S, R>),
+}
+
+/// A [`Pair`] of any type borrowed from a tree, whose children are iterated on demand.
+///
+/// Pairs built on demand, such as those from [`Lazy::force`](crate::predefined_node::Lazy::force),
+/// can not be borrowed, so they are copied into [`Token`]s instead.
+///
+/// See [`Pair::children_iter`].
+pub struct PairRef<'t, S, R: RuleType>(PairRefInner<'t, S, R>);
+
+impl<'t, S: RefStr, R: RuleType> PairRef<'t, S, R> {
+    /// Borrow `pair`.
+    #[inline]
+    pub fn new(pair: &'t impl Pair<S, R>) -> Self {
+        Self(PairRefInner::Borrowed(pair))
+    }
+    /// Rule of the pair.
+    #[inline]
+    pub fn rule(&self) -> R {
+        match &self.0 {
+            PairRefInner::Borrowed(pair) => pair.view().rule,
+            PairRefInner::Detached(token) => token.rule,
+        }
+    }
+    /// Span of the pair.
+    #[inline]
+    pub fn span(&self) -> Span<S> {
+        match &self.0 {
+            PairRefInner::Borrowed(pair) => pair.view().span,
+            PairRefInner::Detached(token) => token.span.clone(),
+        }
+    }
+    /// As a [`PairView`].
+    #[inline]
+    pub fn as_view(&self) -> PairView<S, R> {
+        match &self.0 {
+            PairRefInner::Borrowed(pair) => pair.view(),
+            PairRefInner::Detached(token) => PairView {
+                rule: token.rule,
+                span: token.span.clone(),
+            },
+        }
+    }
+    /// Iterate over inner children. See [`Pair::children_iter`].
+    pub fn children_iter(&self) -> Children<'t, S, R> {
+        let mut children = Vec::new();
+        match &self.0 {
+            PairRefInner::Borrowed(pair) => pair.push_children(&mut children),
+            PairRefInner::Detached(token) => children.extend(
+                token
+                    .children
+                    .iter()
+                    .map(|child| PairRef(PairRefInner::Detached(child.clone()))),
+            ),
+        }
+        Children(children.into_iter())
+    }
+}
+
+impl<S: RefStr, R: RuleType> core::fmt::Debug for PairRef<'_, S, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+/// Iterator over inner children of a pair, as [`PairRef`]s.
+///
+/// Only references to the children are collected when it is created,
+/// and their descendants are not visited until asked.
+pub struct Children<'t, S, R: RuleType>(alloc::vec::IntoIter<PairRef<'t, S, R>>);
+
+impl<'t, S, R: RuleType> Iterator for Children<'t, S, R> {
+    type Item = PairRef<'t, S, R>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+impl<S, R: RuleType> DoubleEndedIterator for Children<'_, S, R> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+impl<S, R: RuleType> ExactSizeIterator for Children<'_, S, R> {}
+
 /// Simulate [`pest::iterators::Pairs`].
 pub trait Pairs<S, R: RuleType> {
-    /// For each inner pair by reference if this is a container, otherwise for self.
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>));
+    /// Visit inner pairs by reference if this is a container, otherwise visit self.
+    ///
+    /// Stops once the visitor returns [`ControlFlow::Break`].
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()>;
+    /// For each inner pair if this is a container, otherwise for self.
+    ///
+    /// Each pair is copied into a [`Token`] with all its descendants.
+    #[inline]
+    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
+        let _ = self.visit_self_or_children(&mut TokenVisitor(f));
+    }
     /// Collect inner pairs and make them into a [`Vec`].
     #[inline]
     fn self_or_children(&self) -> Vec<Token<S, R>> {
@@ -100,8 +431,32 @@ pub trait Pairs<S, R: RuleType> {
 
 /// Simulate [`pest::iterators::Pair`].
 pub trait Pair<S: RefStr, R: RuleType>: Spanned<S, R> + RuleStorage<R> {
+    /// Visit all inner children by reference.
+    ///
+    /// Stops once the visitor returns [`ControlFlow::Break`].
+    fn visit_children<'t>(&'t self, visitor: &mut impl PairVisitor<'t, S, R>) -> ControlFlow<()>;
     /// Iterate over all inner children.
-    fn for_each_child(&self, f: impl FnMut(Token<S, R>));
+    ///
+    /// Each child is copied into a [`Token`] with all its descendants.
+    #[inline]
+    fn for_each_child(&self, f: impl FnMut(Token<S, R>)) {
+        let _ = self.visit_children(&mut TokenVisitor(f));
+    }
+    /// Iterate over [`PairView`]s of all inner children without allocation.
+    #[inline]
+    fn for_each_child_view(&self, f: impl FnMut(PairView<S, R>)) {
+        let _ = self.visit_children(&mut ViewVisitor(f));
+    }
+    /// Iterate over all inner children by reference.
+    ///
+    /// Nothing is copied, and grandchildren are only visited when
+    /// [`PairRef::children_iter`] is called on a child.
+    #[inline]
+    fn children_iter(&self) -> Children<'_, S, R> {
+        let mut children = Vec::new();
+        let _ = self.visit_children(&mut ChildrenVisitor(&mut children));
+        Children(children.into_iter())
+    }
     /// Collect inner [Token]s and make them into a [`Vec`].
     #[inline]
     fn children(&self) -> Vec<Token<S, R>> {
@@ -109,6 +464,21 @@ pub trait Pair<S: RefStr, R: RuleType>: Spanned<S, R> + RuleStorage<R> {
         self.for_each_child(|token| children.push(token));
         children
     }
+    /// Whether there is any inner child.
+    ///
+    /// Stops at the first child.
+    #[inline]
+    fn has_children(&self) -> bool {
+        self.visit_children(&mut AnyVisitor).is_break()
+    }
+    /// As a [`PairView`].
+    #[inline]
+    fn as_view(&self) -> PairView<S, R> {
+        PairView {
+            rule: self.rule(),
+            span: self.span(),
+        }
+    }
     /// As [Token]s. Call [`Pair::children`] inside.
     #[inline]
     fn as_token(&self) -> Token<S, R> {
@@ -126,6 +496,105 @@ pub trait Pair<S: RefStr, R: RuleType>: Spanned<S, R> + RuleStorage<R> {
     }
 }
 
+/// A [`Pair`] of a rule struct, such as those generated by [`rule!`](crate::rule!).
+///
+/// Implemented by [`impl_typed_rule!`](crate::impl_typed_rule!).
+///
+/// # Safety
+///
+/// [`TypedRule::Key`] must be different for different implementors with the same `S`,
+/// as a pair is cast to another type with the same key.
+pub unsafe trait TypedRule<S: RefStr, R: RuleType>: Pair<S, R> + RuleWrapper<R> {
+    /// A `'static` type that tells the implementor from others with the same `S`.
+    ///
+    /// Normally the implementor itself with `S` replaced by `&'static str`.
+    type Key: 'static;
+    /// Whether a pair of `rule` may be among the descendants.
+    ///
+    /// Subtrees are skipped while searching for `rule` if this returns `false`.
+    fn may_contain(rule: R) -> bool;
+}
+
+/// Collect descendants of rule struct `T` in pre-order.
+struct Find<'t, T> {
+    found: Vec<&'t T>,
+    /// Whether to stop at the first one.
+    first_only: bool,
+}
+impl<'t, S: RefStr, R: RuleType, T: TypedRule<S, R>> PairVisitor<'t, S, R> for Find<'t, T> {
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()> {
+        pair.visit_children(self)
+    }
+    fn visit_rule<P: TypedRule<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()> {
+        if TypeId::of::<P::Key>() == TypeId::of::<T::Key>() {
+            // SAFETY: keys are different for different types with the same `S`, so `P` is `T`.
+            self.found.push(unsafe { &*(pair as *const P).cast::<T>() });
+            if self.first_only {
+                return ControlFlow::Break(());
+            }
+        }
+        if P::may_contain(T::RULE) {
+            pair.visit_children(self)
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// Pre-order traversal, which stops at the first error.
+struct PreOrder<'f, F, E> {
+    f: &'f mut F,
+    depth: usize,
+    res: Result<(), E>,
+}
+impl<'t, S, R: RuleType, E, F: FnMut(&PairView<S, R>, usize) -> Result<(), E>> PairVisitor<'t, S, R>
+    for PreOrder<'_, F, E>
+{
+    visit_detached_children!();
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        if let Err(error) = (self.f)(&pair.as_view(), self.depth) {
+            self.res = Err(error);
+            return ControlFlow::Break(());
+        }
+        self.depth += 1;
+        let flow = pair.visit_children(self);
+        self.depth -= 1;
+        flow
+    }
+}
+
+/// Level order traversal, with depths of pairs.
+///
+/// Only references to pairs are queued.
+fn iterate_level_order_views<S: RefStr, R: RuleType, E>(
+    p: &impl Pair<S, R>,
+    mut f: impl FnMut(&PairView<S, R>, usize) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut queue = VecDeque::new();
+    queue.push_back((PairRef::new(p), 0));
+    while let Some((pair, depth)) = queue.pop_front() {
+        f(&pair.as_view(), depth)?;
+        queue.extend(pair.children_iter().map(|child| (child, depth + 1)));
+    }
+    Ok(())
+}
+/// Pre-order traversal, with depths of pairs.
+fn iterate_pre_order_views<S: RefStr, R: RuleType, E>(
+    p: &impl Pair<S, R>,
+    mut f: impl FnMut(&PairView<S, R>, usize) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut visitor = PreOrder {
+        f: &mut f,
+        depth: 0,
+        res: Ok(()),
+    };
+    let _ = visitor.visit(p);
+    visitor.res
+}
+
 fn iterate_level_order<S: RefStr, R: RuleType, E>(
     p: &impl Pair<S, R>,
     mut f: impl FnMut(&Token<S, R>, usize) -> Result<(), E>,
@@ -168,28 +637,100 @@ fn iterate_pre_order<S: RefStr, R: RuleType, E>(
     }
 }
 
+/// Range of the byte at `offset`, or an empty range if `offset` is `end`.
+const fn byte_at(offset: usize, end: usize) -> Range<usize> {
+    if offset == end {
+        offset..offset
+    } else {
+        offset..offset + 1
+    }
+}
+
+/// Collect the chain of pairs containing `range`.
+///
+/// Stops after the first sibling containing `range`.
+struct Covering<S, R: RuleType> {
+    range: Range<usize>,
+    chain: Vec<PairView<S, R>>,
+}
+impl<'t, S, R: RuleType> PairVisitor<'t, S, R> for Covering<S, R> {
+    visit_detached_children!();
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let span = pair.span();
+        if span.start() <= self.range.start && self.range.end <= span.end() {
+            self.chain.push(pair.as_view());
+            let _ = pair.visit_children(self);
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// Write each pair as a line with indentation.
+struct TreeWriter<'b, B> {
+    buf: &'b mut B,
+    depth: usize,
+    res: core::fmt::Result,
+}
+impl<'t, S, R: RuleType, B: core::fmt::Write> PairVisitor<'t, S, R> for TreeWriter<'_, B> {
+    visit_detached_children!();
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()>
+    where
+        S: RefStr,
+    {
+        let indent = "    ".repeat(self.depth);
+        if pair.has_children() {
+            self.res = writeln!(self.buf, "{}{:?}", indent, pair.rule());
+            if self.res.is_err() {
+                return ControlFlow::Break(());
+            }
+            self.depth += 1;
+            let flow = pair.visit_children(self);
+            self.depth -= 1;
+            flow
+        } else {
+            self.res = writeln!(
+                self.buf,
+                "{}{:?} {:?}",
+                indent,
+                pair.rule(),
+                pair.span().as_str()
+            );
+            if self.res.is_err() {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+}
+
 /// Write the tree to.
-fn write_tree_to<'n, S: RefStr, R: RuleType + 'n>(
-    p: &'n impl Pair<S, R>,
+fn write_tree_to<S: RefStr, R: RuleType>(
+    p: &impl Pair<S, R>,
     buf: &mut impl core::fmt::Write,
 ) -> core::fmt::Result {
-    iterate_pre_order(p, |p, depth| {
-        if p.children.is_empty() {
-            buf.write_fmt(format_args!(
-                "{}{:?} {:?}\n",
-                &"    ".repeat(depth),
-                p.rule,
-                p.span.as_str(),
-            ))
-        } else {
-            buf.write_fmt(format_args!("{}{:?}\n", &"    ".repeat(depth), p.rule))
-        }
-    })
+    let mut writer = TreeWriter {
+        buf,
+        depth: 0,
+        res: Ok(()),
+    };
+    let _ = writer.visit(p);
+    writer.res
 }
 
 /// A trait to traverse the pair as the root of a tree.
+///
+/// Traversals visit pairs by reference, so no [`Token`] is built.
 pub trait PairTree<S: RefStr, R: RuleType>: Pair<S, R> + Sized {
     /// Level order traversal
+    #[deprecated(
+        note = "each pair is copied into a `Token` with all its descendants, use `iterate_level_order_views` instead"
+    )]
     #[inline]
     fn iterate_level_order<E>(
         &self,
@@ -198,6 +739,9 @@ pub trait PairTree<S: RefStr, R: RuleType>: Pair<S, R> + Sized {
         iterate_level_order(self, f)
     }
     /// Pre-order traversal
+    #[deprecated(
+        note = "each pair is copied into a `Token` with all its descendants, use `iterate_pre_order_views` instead"
+    )]
     #[inline]
     fn iterate_pre_order<E>(
         &self,
@@ -205,6 +749,24 @@ pub trait PairTree<S: RefStr, R: RuleType>: Pair<S, R> + Sized {
     ) -> Result<(), E> {
         iterate_pre_order(self, f)
     }
+    /// Level order traversal by [`PairView`]s, with the depth of each pair.
+    ///
+    /// Pairs are visited in one pass, with references to them queued.
+    #[inline]
+    fn iterate_level_order_views<E>(
+        &self,
+        f: impl FnMut(&PairView<S, R>, usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        iterate_level_order_views(self, f)
+    }
+    /// Pre-order traversal by [`PairView`]s, with the depth of each pair.
+    #[inline]
+    fn iterate_pre_order_views<E>(
+        &self,
+        f: impl FnMut(&PairView<S, R>, usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        iterate_pre_order_views(self, f)
+    }
 
     /// Write the tree to the `buf`.
     #[inline]
@@ -219,6 +781,93 @@ pub trait PairTree<S: RefStr, R: RuleType>: Pair<S, R> + Sized {
         self.write_tree_to(&mut buf)?;
         Ok(buf)
     }
+
+    /// Create a [`TreeCursor`] at this pair as the root.
+    #[inline]
+    fn cursor(&self) -> TreeCursor<S, R> {
+        TreeCursor::from_pair(self)
+    }
+
+    /// Pairs whose spans contain the byte at `offset`, from the root to the deepest one.
+    ///
+    /// At the end of this pair, where there is no byte, pairs ending there are returned.
+    /// See [`PairTree::nodes_covering`].
+    #[inline]
+    fn node_at(&self, offset: usize) -> Vec<PairView<S, R>> {
+        self.nodes_covering(byte_at(offset, self.span().end()))
+    }
+    /// Pairs whose spans contain `range`, from the root to the deepest one.
+    ///
+    /// Subtrees whose spans do not contain `range` are skipped without visiting their children.
+    /// If several siblings contain an empty `range`, only the first one is followed.
+    #[inline]
+    fn nodes_covering(&self, range: Range<usize>) -> Vec<PairView<S, R>> {
+        let mut visitor = Covering {
+            range,
+            chain: Vec::new(),
+        };
+        let _ = visitor.visit(self);
+        visitor.chain
+    }
+    /// The deepest pair whose span contains `range`.
+    #[inline]
+    fn smallest_enclosing(&self, range: Range<usize>) -> Option<PairView<S, R>> {
+        self.nodes_covering(range).pop()
+    }
+
+    /// All descendants of rule struct `T`, in document order.
+    ///
+    /// Subtrees that can not contain `T` are skipped,
+    /// and children of [`Lazy`](crate::predefined_node::Lazy) rules are not searched.
+    #[inline]
+    fn find_all<T: TypedRule<S, R>>(&self) -> Vec<&T> {
+        let mut visitor = Find {
+            found: Vec::new(),
+            first_only: false,
+        };
+        let _ = self.visit_children(&mut visitor);
+        visitor.found
+    }
+    /// The first descendant of rule struct `T`, in document order.
+    ///
+    /// See [`PairTree::find_all`].
+    #[inline]
+    fn find_first<T: TypedRule<S, R>>(&self) -> Option<&T> {
+        let mut visitor = Find {
+            found: Vec::new(),
+            first_only: true,
+        };
+        let _ = self.visit_children(&mut visitor);
+        visitor.found.pop()
+    }
+
+    /// Structural diff from this tree to `new`. See [`TreeDiff`].
+    #[inline]
+    fn diff(&self, new: &impl Pair<S, R>) -> TreeDiff<S, R> {
+        TreeDiff::new(&self.as_token(), &new.as_token())
+    }
+
+    /// Find all matches of `query` in this tree. See [`Query::matches`].
+    #[inline]
+    fn query<'q>(&self, query: &'q Query) -> Vec<QueryMatch<'q, S, R>> {
+        query.matches(self)
+    }
+
+    /// Collect every leaf in document order, including anonymous literals, characters and trivia.
+    ///
+    /// Rules without inner leaves, such as atomic rules, are leaves themselves.
+    /// Concatenating spans of the leaves reproduces the span of this tree.
+    fn leaves(&self) -> Vec<Leaf<S, R>> {
+        let mut visitor = LeafVisitor::new();
+        let _ = visitor.visit_detached_children(self);
+        visitor.leaves
+    }
+
+    /// Attach skipped `COMMENT`s to their nearest nodes. See [`Comments`].
+    #[inline]
+    fn comments(&self) -> Comments<S, R> {
+        Comments::new(&self.as_token(), &self.leaves())
+    }
 }
 
 impl<S: RefStr, R: RuleType, T: RuleStruct<S, R> + Pairs<S, R> + Pair<S, R>> PairTree<S, R> for T {}
@@ -227,7 +876,28 @@ macro_rules! impl_empty {
     ($node:ty $(, $($tt:tt)*)?) => {
         impl<S: RefStr, R: RuleType $(, $($tt)*)?> Pairs<S, R> for $node {
             #[inline(always)]
-            fn for_self_or_each_child(&self, _f: &mut impl FnMut(Token<S, R>)) {}
+            fn visit_self_or_children<'t>(
+                &'t self,
+                _visitor: &mut impl PairVisitor<'t, S, R>,
+            ) -> ControlFlow<()> {
+                ControlFlow::Continue(())
+            }
+        }
+    };
+}
+
+macro_rules! impl_leaf {
+    ($node:ty, |$this:ident| $leaf:expr $(, $($tt:tt)*)?) => {
+        impl<S: RefStr, R: RuleType $(, $($tt)*)?> Pairs<S, R> for $node {
+            #[inline]
+            fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+                let $this = self;
+                let (kind, text) = $leaf;
+                visitor.visit_leaf(kind, text)
+            }
         }
     };
 }
@@ -236,63 +906,102 @@ macro_rules! impl_forward_inner {
     ($node:ident) => {
         impl<S: RefStr, R: RuleType, T: Pairs<S, R>> Pairs<S, R> for $node<T> {
             #[inline(always)]
-            fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-                self.content.for_self_or_each_child(f)
+            fn visit_self_or_children<'t>(
+                &'t self,
+                visitor: &mut impl PairVisitor<'t, S, R>,
+            ) -> ControlFlow<()> {
+                self.content.visit_self_or_children(visitor)
             }
         }
     };
 }
 
-impl_empty!(Str<T>, T: StringWrapper);
-impl_empty!(Insens<S, T>, T: StringWrapper);
-impl_empty!(PeekSlice2<START, END>, const START: i32, const END: i32);
-impl_empty!(PeekSlice1<START>, const START: i32);
+impl_leaf!(Str<S, T>, |_this| (LeafKind::Literal, LeafText::Str(T::CONTENT)), T: StringWrapper);
+impl_leaf!(Insens<S, T>, |this| (LeafKind::Literal, LeafText::Input(&this.content)), T: StringWrapper);
+impl_leaf!(PeekSlice2<S, START, END>, |this| (LeafKind::Other, LeafText::Span(&this.span)), const START: i32, const END: i32);
+impl_leaf!(PeekSlice1<S, START>, |this| (LeafKind::Other, LeafText::Span(&this.span)), const START: i32);
 impl_forward_inner!(Push);
-impl_empty!(Skip<S, Strings>, Strings: StringArrayWrapper);
-impl_empty!(CharRange<MIN, MAX>, const MIN: char, const MAX: char);
+impl_leaf!(Skip<S, Strings>, |this| (LeafKind::Other, LeafText::Span(&this.span)), Strings: StringArrayWrapper);
+impl_leaf!(CharRange<S, MIN, MAX>, |this| (LeafKind::Char, LeafText::Char(this.content)), const MIN: char, const MAX: char);
+impl_leaf!(CharSet<S, T>, |this| (LeafKind::Char, LeafText::Char(this.content)), T: CharSetWrapper);
+impl<S: RefStr, R: RuleType, T: TriviaWrapper> Pairs<S, R> for SkipTrivia<S, T> {
+    #[inline]
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        visitor.visit_trivia(T::TRIVIA, T::WHITESPACE)
+    }
+}
 impl_empty!(Positive<T>, T);
-impl_empty!(Negative<T>, T);
+impl_empty!(Negative<S, T>, T);
 
 impl<S: RefStr, R: RuleType, T1: Pairs<S, R>, T2: Pairs<S, R>> Pairs<S, R> for (T1, T2) {
     #[inline]
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-        self.0.for_self_or_each_child(f);
-        self.1.for_self_or_each_child(f);
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        self.0.visit_self_or_children(visitor)?;
+        self.1.visit_self_or_children(visitor)
     }
 }
 
 impl<S, R: RuleType, T: Pairs<S, R>, const N: usize> Pairs<S, R> for [T; N] {
     #[inline]
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
         self.as_slice()
             .iter()
-            .for_each(|n| n.for_self_or_each_child(f))
+            .try_for_each(|n| n.visit_self_or_children(visitor))
     }
 }
 
 impl<S, R: RuleType, T: Pairs<S, R>> Pairs<S, R> for Box<T> {
     #[inline]
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-        self.as_ref().for_self_or_each_child(f)
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        self.as_ref().visit_self_or_children(visitor)
     }
 }
 
 impl<S, R: RuleType, T: Pairs<S, R>> Pairs<S, R> for Option<T> {
     #[inline]
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-        if let Some(node) = self {
-            node.for_self_or_each_child(f)
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        match self {
+            Some(node) => node.visit_self_or_children(visitor),
+            None => ControlFlow::Continue(()),
         }
     }
 }
 
+impl<S, R: RuleType, T: Pairs<S, R>> Pairs<S, R> for Optional<S, T> {
+    #[inline(always)]
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        self.content.visit_self_or_children(visitor)
+    }
+}
+
 impl<S, R: RuleType, T: Pairs<S, R>, Skip: Pairs<S, R>, const SKIP: usize> Pairs<S, R>
     for Skipped<T, Skip, SKIP>
 {
     #[inline]
-    fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-        self.skipped.for_self_or_each_child(f);
-        self.matched.for_self_or_each_child(f);
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        self.skipped.visit_self_or_children(visitor)?;
+        self.matched.visit_self_or_children(visitor)
     }
 }
 
@@ -303,11 +1012,14 @@ macro_rules! impl_with_vec {
                 R: RuleType,
                 T: Pairs<S, R>,
                 $(const $args: $t, )*
-            > Pairs<S, R> for $name<T, $($args, )*>
+            > Pairs<S, R> for $name<S, T, $($args, )*>
         {
             #[inline]
-            fn for_self_or_each_child(&self, f: &mut impl FnMut(Token<S, R>)) {
-                self.content.iter().for_each(|n| n.for_self_or_each_child(f));
+            fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+                self.content.iter().try_for_each(|n| n.visit_self_or_children(visitor))
             }
         }
     };
@@ -317,14 +1029,36 @@ impl_with_vec!(RepeatMinMax, const MIN: usize, const MAX: usize,);
 impl_with_vec!(RepeatMin, const MIN: usize,);
 impl_with_vec!(AtomicRepeat,);
 
-impl_empty!(ANY);
-impl_empty!(SOI);
-impl_empty!(NEWLINE);
-impl_empty!(PEEK<S>);
-impl_empty!(PEEK_ALL<S>);
-impl_empty!(POP<S>);
-impl_empty!(POP_ALL<S>);
-impl_empty!(DROP);
+impl_leaf!(ANY<S>, |this| (
+    LeafKind::Char,
+    LeafText::Char(this.content)
+));
+impl_empty!(SOI<S>);
+impl_leaf!(NEWLINE<S>, |this| {
+    let text = match this.content {
+        NewLineType::CRLF => "\r\n",
+        NewLineType::LF => "\n",
+        NewLineType::CR => "\r",
+    };
+    (LeafKind::Literal, LeafText::Str(text))
+});
+impl_leaf!(PEEK<S>, |this| (
+    LeafKind::Literal,
+    LeafText::Span(&this.span)
+));
+impl_leaf!(PEEK_ALL<S>, |this| (
+    LeafKind::Literal,
+    LeafText::Span(&this.span)
+));
+impl_leaf!(POP<S>, |this| (
+    LeafKind::Literal,
+    LeafText::Span(&this.span)
+));
+impl_leaf!(POP_ALL<S>, |this| (
+    LeafKind::Literal,
+    LeafText::Span(&this.span)
+));
+impl_empty!(DROP<S>);
 
 impl_empty!(AlwaysFail<S>);
 impl_empty!(Empty<S>);
@@ -0,0 +1,197 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Lossless stream of leaves, including anonymous literals and trivia.
+//!
+//! Unlike [`Pairs`](crate::iterators::Pairs), which only yields rule structs,
+//! leaves cover every piece of the matched input in document order,
+//! so concatenating their spans reproduces it exactly.
+//! See [`PairTree::leaves`](crate::iterators::PairTree::leaves).
+
+use crate::{
+    iterators::{Pair, PairVisitor, Pairs, TypedRule},
+    predefined_node::Trivia,
+    Position, RefStr, RuleType, Span,
+};
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+use derive_where::derive_where;
+
+/// Kind of a [`Leaf`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LeafKind<R> {
+    /// A rule without inner leaves, such as an atomic rule.
+    Rule(R),
+    /// A string literal, or text matched by the stack such as `PEEK`.
+    Literal,
+    /// A single character, such as those matched by `ANY` or character ranges.
+    Char,
+    /// Skipped `WHITESPACE`.
+    Whitespace,
+    /// Skipped `COMMENT`.
+    Comment,
+    /// Other text, such as that skipped by `SKIP`,
+    /// or that matched by nodes whose positions are not kept, such as `PEEK[..]`.
+    Other,
+}
+
+/// A piece of matched input.
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Leaf<S, R: RuleType> {
+    /// Kind of the leaf.
+    pub kind: LeafKind<R>,
+    /// Matched span.
+    pub span: Span<S>,
+}
+
+/// Text of a leaf as reported by a node, which may not know its position.
+///
+/// See [`PairVisitor::visit_leaf`].
+#[derive_where(Clone, Copy, Debug; S: RefStr)]
+pub enum LeafText<'n, S> {
+    /// A string literal in the grammar.
+    Str(&'static str),
+    /// A slice of the input.
+    Input(&'n S),
+    /// A single character.
+    Char(char),
+    /// A span with known position.
+    Span(&'n Span<S>),
+    /// Text of unknown length.
+    Unknown,
+}
+
+/// Collect leaves by tracking the position after the last leaf.
+pub(crate) struct LeafVisitor<S, R: RuleType> {
+    pub(crate) leaves: Vec<Leaf<S, R>>,
+    /// Position after the last leaf, or [`None`] if lost after text of unknown length.
+    cursor: Option<Position<S>>,
+    /// Where the cursor was lost.
+    lost: Option<Position<S>>,
+}
+
+impl<S: RefStr, R: RuleType> LeafVisitor<S, R> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            leaves: Vec::new(),
+            cursor: None,
+            lost: None,
+        }
+    }
+    /// Move the cursor to `pos`, covering text since the cursor with a leaf of [`LeafKind::Other`].
+    fn sync(&mut self, pos: Position<S>) {
+        let from = match self.cursor.take() {
+            Some(cursor) => cursor,
+            None => match self.lost.take() {
+                Some(lost) => lost,
+                None => pos.clone(),
+            },
+        };
+        if from.pos() < pos.pos() {
+            let span = from.span(&pos);
+            self.leaves.push(Leaf {
+                kind: LeafKind::Other,
+                span,
+            });
+        }
+        self.cursor = Some(pos);
+    }
+    fn push(&mut self, kind: LeafKind<R>, span: Span<S>) {
+        self.sync(span.start_pos());
+        self.cursor = Some(span.end_pos());
+        if span.start() < span.end() {
+            self.leaves.push(Leaf { kind, span });
+        }
+    }
+    /// Push a leaf of `len` after the cursor.
+    fn advance(&mut self, kind: LeafKind<R>, len: usize) {
+        if let Some(cursor) = &self.cursor {
+            if let Some(end) = Position::new(cursor.input.clone(), cursor.pos() + len) {
+                let span = cursor.span(&end);
+                self.push(kind, span);
+            }
+        }
+    }
+    fn trivia_kind(rule: R) -> Option<LeafKind<R>> {
+        match rule.name() {
+            "WHITESPACE" => Some(LeafKind::Whitespace),
+            "COMMENT" => Some(LeafKind::Comment),
+            _ => None,
+        }
+    }
+}
+
+impl<'t, S: RefStr, R: RuleType> PairVisitor<'t, S, R> for LeafVisitor<S, R> {
+    fn visit<P: Pair<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()> {
+        self.visit_detached_children(pair)
+    }
+    fn visit_rule<P: TypedRule<S, R>>(&mut self, pair: &'t P) -> ControlFlow<()> {
+        self.visit(pair)
+    }
+    fn visit_detached_children<P: Pair<S, R>>(&mut self, pair: &P) -> ControlFlow<()> {
+        let span = pair.span();
+        if let Some(kind) = Self::trivia_kind(pair.rule()) {
+            self.push(kind, span);
+            return ControlFlow::Continue(());
+        }
+        self.sync(span.start_pos());
+        let len = self.leaves.len();
+        pair.visit_children(self)?;
+        if self.leaves.len() == len && self.cursor.as_ref() == Some(&span.start_pos()) {
+            self.push(LeafKind::Rule(pair.rule()), span);
+        } else {
+            self.sync(span.end_pos());
+        }
+        ControlFlow::Continue(())
+    }
+    fn visit_silent<P: Pairs<S, R>>(&mut self, rule: R, content: &'t P) -> ControlFlow<()> {
+        let (Some(kind), Some(start)) = (Self::trivia_kind(rule), self.cursor.clone()) else {
+            return content.visit_self_or_children(self);
+        };
+        let len = self.leaves.len();
+        content.visit_self_or_children(self)?;
+        if let Some(end) = self.cursor.clone() {
+            self.leaves.truncate(len);
+            self.push(kind, start.span(&end));
+        }
+        ControlFlow::Continue(())
+    }
+    fn visit_leaf(&mut self, kind: LeafKind<R>, text: LeafText<'_, S>) -> ControlFlow<()> {
+        match text {
+            LeafText::Str(string) => self.advance(kind, S::str_len(string)),
+            LeafText::Input(input) => self.advance(kind, input.len()),
+            LeafText::Char(c) => self.advance(kind, S::char_len(c)),
+            LeafText::Span(span) => self.push(kind, span.clone()),
+            LeafText::Unknown => {
+                if let Some(cursor) = self.cursor.take() {
+                    self.lost = Some(cursor);
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+    fn visit_trivia(&mut self, trivia: &'static [Trivia], whitespace: usize) -> ControlFlow<()> {
+        let Some(mut next) = self.cursor.clone() else {
+            return ControlFlow::Continue(());
+        };
+        loop {
+            let start = next.clone();
+            let Some(index) = trivia.iter().position(|trivia| trivia.skip(&mut next)) else {
+                break;
+            };
+            let kind = if index < whitespace {
+                LeafKind::Whitespace
+            } else {
+                LeafKind::Comment
+            };
+            self.push(kind, start.span(&next));
+        }
+        ControlFlow::Continue(())
+    }
+}
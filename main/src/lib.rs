@@ -13,7 +13,8 @@
 //!
 //! Features:
 //!
-//! - `std`: include [`std`] support.
+//! - `std`: include [`std`] support, such as reading input from [`std::io::Read`] on demand with `ReadStr`,
+//!   and parsing independent chunks on multiple threads with `try_parse_chunks`.
 //!
 //!   Without this feature, we'll use [core] and [alloc].
 //!
@@ -46,23 +47,41 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use typed_node::NeverFailedParsableTypedNode;
-pub use typed_node::{NeverFailedTypedNode, ParsableTypedNode, RuleStruct, Spanned, TypedNode};
+pub use typed_node::{
+    NeverFailedParsableTypedNode, NeverFailedTypedNode, ParsableTypedNode, RuleStruct, Spanned,
+    TypedNode,
+};
 pub use wrapper::{
-    BoundWrapper, ConstantStorage, RuleWrapper, Storage, StringArrayWrapper, StringWrapper,
-    TypeWrapper,
+    BoundWrapper, CharSetWrapper, ConstantStorage, RuleWrapper, Storage, StringArrayWrapper,
+    StringWrapper, TriviaWrapper, TypeWrapper,
 };
 
+pub mod builder;
 pub mod choices;
+#[cfg(feature = "std")]
+pub mod chunks;
+pub mod comments;
+pub mod convert;
+pub mod diff;
+pub mod flat_tree;
 mod formatter;
 mod input;
+pub mod items;
 pub mod iterators;
+pub mod leaves;
 pub mod line_indexer;
 mod pratt;
 pub mod predefined_node;
+pub mod query;
 pub mod re_exported;
+pub mod rewriter;
+pub mod rope;
 pub mod rule;
+pub mod semantic;
 pub mod sequence;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod token;
 pub mod tracker;
 mod typed_node;
 mod wrapper;
@@ -98,10 +117,19 @@ pub trait TypedParser<R: RuleType> {
     ) -> Result<T, Box<error::Error<R>>> {
         T::try_parse(input)
     }
-    /// Parse a `&str` into a tree starting from T.
+    /// Parse a `&str` into a tree starting from T, which never fails on a prefix of the input.
+    ///
+    /// Return [`None`] if input other than trivia is left after the matched part.
     #[inline]
-    fn parse<I: Input, T: NeverFailedParsableTypedNode<I::Cursor, R>>(input: I) -> T {
-        T::parse(input.as_cursor())
+    fn parse<I: Input, T: NeverFailedParsableTypedNode<I::Cursor, R>>(input: I) -> Option<T> {
+        T::parse(input)
+    }
+    /// Parse the input from the start into a tree starting from T, which never fails.
+    #[inline]
+    fn parse_partial<I: Input, T: NeverFailedParsableTypedNode<I::Cursor, R>>(
+        input: I,
+    ) -> (I::Cursor, T) {
+        T::parse_partial(input)
     }
     /// Check whether a `&str` can be parsed into a tree starting from T.
     #[inline]
@@ -88,7 +88,7 @@ impl<S: RefStr> LineIndexer<S> for () {
                     line_col = (line_col.0 + 1, 1);
                 }
                 Some(c) => {
-                    pos -= c.len_utf8();
+                    pos -= S::char_len(c);
                     line_col = (line_col.0, line_col.1 + 1);
                 }
                 None => unreachable!(),
@@ -105,29 +105,26 @@ impl<S: RefStr> LineIndexer<S> for () {
 
     #[inline]
     fn find_line_start(&self, input: &S, pos: usize) -> usize {
-        let start = input
-            .as_str()
-            .char_indices()
-            .rev()
-            .skip_while(|&(i, _)| i >= pos)
-            .find(|&(_, c)| c == '\n');
-        match start {
-            Some((i, _)) => i + 1,
-            None => 0,
+        let mut start = pos;
+        for c in input.get_checked(..pos).chars().rev() {
+            if c == '\n' {
+                break;
+            }
+            start -= S::char_len(c);
         }
+        start
     }
 
     #[inline]
     fn find_line_end(&self, input: &S, pos: usize) -> usize {
-        let end = input
-            .as_str()
-            .char_indices()
-            .skip_while(|&(i, _)| i < pos)
-            .find(|&(_, c)| c == '\n');
-        match end {
-            Some((i, _)) => i + 1,
-            None => input.len(),
+        let mut end = pos;
+        for c in input.get_checked(pos..).chars() {
+            end += S::char_len(c);
+            if c == '\n' {
+                break;
+            }
         }
+        end
     }
 }
 
@@ -175,7 +175,7 @@ impl<S: RefStr> Position<S> {
             let mut chars = input.chars();
             for _ in 0..n {
                 if let Some(c) = chars.next() {
-                    len += c.len_utf8();
+                    len += S::char_len(c);
                 } else {
                     return false;
                 }
@@ -199,7 +199,7 @@ impl<S: RefStr> Position<S> {
             let mut chars = input.chars().rev();
             for _ in 0..n {
                 if let Some(c) = chars.next() {
-                    len += c.len_utf8();
+                    len += S::char_len(c);
                 } else {
                     return false;
                 }
@@ -274,11 +274,9 @@ impl<S: RefStr> Position<S> {
             let Some(string) = self.input.get(from..) else {
                 continue;
             };
-            let bytes = string.as_str().as_bytes();
 
             for slice in strings.iter() {
-                let to = <str>::len(slice);
-                if Some(slice.as_bytes()) == bytes.get(0..to) {
+                if string.starts_with(slice) {
                     self.pos = from;
                     return true;
                 }
@@ -296,7 +294,8 @@ impl<S: RefStr> Position<S> {
     #[allow(dead_code)]
     pub(crate) fn match_char(&self, c: char) -> bool {
         let input = unsafe { self.input.get_range_unchecked(self.pos..) };
-        matches!(input.chars().next(), Some(cc) if c == cc)
+        let next = input.chars().next();
+        matches!(next, Some(cc) if c == cc)
     }
 
     /// Matches the char at the `Position` against a filter function and returns `true` if a match
@@ -308,9 +307,10 @@ impl<S: RefStr> Position<S> {
         F: FnOnce(char) -> bool,
     {
         let input = unsafe { self.input.get_range_unchecked(self.pos..) };
-        if let Some(c) = input.chars().next() {
+        let next = input.chars().next();
+        if let Some(c) = next {
             if f(c) {
-                self.pos += c.len_utf8();
+                self.pos += S::char_len(c);
                 true
             } else {
                 false
@@ -372,8 +372,8 @@ impl<S: RefStr> PartialOrd for Position<S> {
 impl<S: RefStr> Ord for Position<S> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        debug_assert_eq!(
-            self.input, other.input,
+        debug_assert!(
+            self.input.ptr_eq(&other.input),
             "cannot compare positions from different strings"
         );
         self.pos.cmp(&other.pos)
@@ -396,12 +396,12 @@ impl<'i> From<Position<&'i str>> for pest::Position<'i> {
     }
 }
 
-impl<S: RefStr> Position<S> {
+impl<S: RefStr + core::ops::Deref<Target = str>> Position<S> {
     /// Convert to [`pest::Position`].
     #[inline]
     pub fn as_pest_position(&self) -> pest::Position<'_> {
         //FIXME: eliminate the check
-        pest::Position::new(self.input.as_str(), self.pos).unwrap()
+        pest::Position::new(&self.input, self.pos).unwrap()
     }
 }
 
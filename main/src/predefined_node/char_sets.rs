@@ -0,0 +1,28 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Character sets used by built-in rules. See [`super::CharSet`].
+
+use crate::CharSetWrapper;
+
+macro_rules! char_set {
+    ($name:ident, $($min:literal..$max:literal),*) => {
+        #[allow(non_camel_case_types)]
+        #[doc = concat!("Character set of built-in rule ", stringify!($name), ".")]
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub struct $name;
+        impl CharSetWrapper for $name {
+            const RANGES: &'static [(char, char)] = &[$(($min, $max)),*];
+        }
+    };
+}
+
+char_set!(ASCII_HEX_DIGIT, '0'..'9', 'A'..'F', 'a'..'f');
+char_set!(ASCII_ALPHA, 'A'..'Z', 'a'..'z');
+char_set!(ASCII_ALPHANUMERIC, '0'..'9', 'A'..'Z', 'a'..'z');
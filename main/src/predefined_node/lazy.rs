@@ -0,0 +1,158 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules that are only checked while parsing.
+
+use crate::{
+    error::Error,
+    iterators::{Pair, PairVisitor, Pairs},
+    tracker::{error_at, EventSink, Tracker},
+    typed_node::NeverFailedTypedNode,
+    Cursor, Position, RefStr, RuleType, RuleWrapper, Span, Spanned, Stack, TypedNode,
+};
+use alloc::{boxed::Box, format};
+use core::{marker::PhantomData, ops::ControlFlow};
+use derive_where::derive_where;
+
+/// A rule `T` that is only checked while parsing.
+///
+/// Only the [`Span`] is stored, which is much cheaper than building the whole subtree.
+/// Call [`Lazy::force`] to parse the span into `T` when needed.
+///
+/// The subtree is parsed again with an empty [`Stack`],
+/// so it may fail if `T` peeks or pops spans pushed outside it.
+///
+/// As a [`Pair`], it's a leaf with the span of the rule,
+/// so traversals never parse it again. Pairs inside it are got from [`Lazy::force`].
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Lazy<S, T> {
+    /// Span of the rule.
+    pub span: Span<S>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<S: RefStr, T> Lazy<S, T> {
+    /// Parse the span into `T`.
+    ///
+    /// Parsing starts from the same position in the whole input,
+    /// so `SOI` and lookaheads behave the same as before.
+    /// It's an error if the rule doesn't end at the end of the span.
+    pub fn force<R: RuleType>(&self) -> Result<T, Box<Error<R>>>
+    where
+        T: TypedNode<Position<S>, R>,
+    {
+        let cursor = self.span.start_pos();
+        let mut stack = Stack::new();
+        let mut tracker = Tracker::new(cursor.clone());
+        match T::try_parse_partial_with(cursor, &mut stack, &mut tracker) {
+            Some((end, res)) if end.pos() == self.span.end() => Ok(res),
+            Some((end, _)) => {
+                let message = format!(
+                    "Rule ended at position {} rather than the end of the lazy span at position {}.",
+                    end.pos(),
+                    self.span.end(),
+                );
+                Err(Box::new(error_at(&end, |_, _| message)))
+            }
+            None => Err(Box::new(tracker.collect(()))),
+        }
+    }
+}
+
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Lazy<C::String, T> {
+    #[inline]
+    fn try_parse_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<(C, Self)> {
+        let start = input.clone();
+        let input = T::try_check_partial_with(input, stack, tracker)?;
+        let span = start.span(&input);
+        Some((
+            input,
+            Self {
+                span,
+                _phantom: PhantomData,
+            },
+        ))
+    }
+
+    #[inline]
+    fn try_check_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        T::try_check_partial_with(input, stack, tracker)
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> Option<C> {
+        T::try_check_partial_reporting_with(input, stack, tracker, events)
+    }
+}
+
+impl<C: Cursor, R: RuleType, T: NeverFailedTypedNode<C, R>> NeverFailedTypedNode<C, R>
+    for Lazy<C::String, T>
+{
+    #[inline]
+    fn parse_with(input: C, stack: &mut Stack<Span<C::String>>) -> (C, Self) {
+        let start = input.clone();
+        let input = T::check_with(input, stack);
+        let span = start.span(&input);
+        (
+            input,
+            Self {
+                span,
+                _phantom: PhantomData,
+            },
+        )
+    }
+
+    #[inline]
+    fn check_with(input: C, stack: &mut Stack<Span<C::String>>) -> C {
+        T::check_with(input, stack)
+    }
+}
+
+impl<S, R: RuleType, T: RuleWrapper<R>> RuleWrapper<R> for Lazy<S, T> {
+    const RULE: R = T::RULE;
+    type Rule = R;
+}
+
+impl<S: RefStr, R: RuleType, T> Spanned<S, R> for Lazy<S, T> {
+    #[inline]
+    fn span(&self) -> Span<S> {
+        self.span.clone()
+    }
+}
+
+/// A leaf, whose children are only got from [`Lazy::force`].
+impl<S: RefStr, R: RuleType, T: RuleWrapper<R>> Pair<S, R> for Lazy<S, T> {
+    #[inline]
+    fn visit_children<'t>(&'t self, _visitor: &mut impl PairVisitor<'t, S, R>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<S: RefStr, R: RuleType, T: RuleWrapper<R>> Pairs<S, R> for Lazy<S, T> {
+    #[inline]
+    fn visit_self_or_children<'t>(
+        &'t self,
+        visitor: &mut impl PairVisitor<'t, S, R>,
+    ) -> ControlFlow<()> {
+        visitor.visit(self)
+    }
+}
@@ -11,15 +11,19 @@
 //! The generator may use this for convenience.
 //! Normally you don't need to reference this module by yourself.
 
+pub mod char_sets;
+mod lazy;
 mod repetition;
+mod spanned;
+mod trivia;
 pub mod unicode;
 
 use super::{
     parser_state::constrain_idxs,
     span::Span,
-    tracker::Tracker,
+    tracker::{EventSink, Tracker},
     typed_node::NeverFailedTypedNode,
-    wrapper::{StringArrayWrapper, StringWrapper},
+    wrapper::{CharSetWrapper, StringArrayWrapper, StringWrapper},
     RuleType, Stack, TypedNode,
 };
 use core::{
@@ -28,9 +32,11 @@ use core::{
     ops::{Deref, DerefMut},
 };
 use derive_where::derive_where;
+pub use lazy::Lazy;
 pub use repetition::{
     AtomicRepeat, Rep, RepExact, RepMax, RepMin, RepMinMax, RepOnce, RepeatMin, RepeatMinMax,
 };
+pub use trivia::{SkipTrivia, Trivia};
 
 /// Match given string case sensitively.
 ///
@@ -38,29 +44,45 @@ pub use repetition::{
 ///
 /// See [`Insens`] for case-insensitive matching.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive_where(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Str<T: StringWrapper + 'static> {
+#[derive_where(Clone, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct Str<S, T: StringWrapper + 'static> {
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
     _phantom: PhantomData<&'static T>,
 }
-impl<T: StringWrapper> StringWrapper for Str<T> {
+impl<S, T: StringWrapper> StringWrapper for Str<S, T> {
     const CONTENT: &'static str = T::CONTENT;
 }
-impl<T: StringWrapper> From<()> for Str<T> {
-    fn from(_value: ()) -> Self {
+/// With an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr, T: StringWrapper> Default for Str<S, T> {
+    fn default() -> Self {
+        Self::from(placeholder())
+    }
+}
+impl<S, T: StringWrapper> From<Span<S>> for Str<S, T> {
+    fn from(span: Span<S>) -> Self {
         Self {
+            span,
             _phantom: PhantomData,
         }
     }
 }
-impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for Str<T> {
+impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for Str<C::String, T> {
     #[inline]
     fn try_parse_partial_with(
         mut input: C,
         _stack: &mut Stack<Span<C::String>>,
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
+        let start = input.clone();
         if input.match_string(Self::CONTENT) {
-            Some((input, Self::from(())))
+            let span = start.span(&input);
+            Some((input, Self::from(span)))
         } else {
             None
         }
@@ -79,7 +101,7 @@ impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for Str
         }
     }
 }
-impl<T: StringWrapper> Debug for Str<T> {
+impl<S, T: StringWrapper> Debug for Str<S, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Str").finish()
     }
@@ -98,15 +120,20 @@ impl<T: StringWrapper> Debug for Str<T> {
 pub struct Insens<S, T> {
     /// Matched content.
     pub content: S,
+    /// Matched span.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
     _phantom: PhantomData<T>,
 }
 impl<S, T: StringWrapper> StringWrapper for Insens<S, T> {
     const CONTENT: &'static str = T::CONTENT;
 }
-impl<S, T> From<S> for Insens<S, T> {
-    fn from(content: S) -> Self {
+impl<S: RefStr, T> From<Span<S>> for Insens<S, T> {
+    fn from(span: Span<S>) -> Self {
         Self {
-            content,
+            content: span.as_str(),
+            span,
             _phantom: PhantomData,
         }
     }
@@ -121,7 +148,7 @@ impl<C: Cursor, R: RuleType, T: StringWrapper> TypedNode<C, R> for Insens<C::Str
         let start = input.clone();
         if input.match_insensitive(Self::CONTENT) {
             let span = start.span(&input);
-            Some((input, Self::from(span.as_str())))
+            Some((input, Self::from(span)))
         } else {
             None
         }
@@ -250,13 +277,20 @@ impl<C: Cursor, R: RuleType, const N: usize> TypedNode<C, R> for SkipChar<C::Str
 /// Match a character in the range `[MIN, MAX]`.
 /// Inclusively both below and above.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CharRange<const MIN: char, const MAX: char> {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct CharRange<S, const MIN: char, const MAX: char> {
     /// Matched character.
     pub content: char,
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
 }
 impl<C: Cursor, R: RuleType, const MIN: char, const MAX: char> TypedNode<C, R>
-    for CharRange<MIN, MAX>
+    for CharRange<C::String, MIN, MAX>
 {
     #[inline]
     fn try_parse_partial_with(
@@ -269,7 +303,7 @@ impl<C: Cursor, R: RuleType, const MIN: char, const MAX: char> TypedNode<C, R>
             true => {
                 let span = start.span(&input);
                 let content = span.as_str().chars().next().unwrap();
-                Some((input, Self { content }))
+                Some((input, Self { content, span }))
             }
             false => None,
         }
@@ -288,6 +322,68 @@ impl<C: Cursor, R: RuleType, const MIN: char, const MAX: char> TypedNode<C, R>
     }
 }
 
+/// Match a character in a set described by `T` (by [`CharSetWrapper`]).
+///
+/// Equivalent to a choice of [`CharRange`]s and unicode properties,
+/// but the membership test is a single table lookup.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive_where(Clone, Hash, PartialEq, Eq; S: RefStr)]
+pub struct CharSet<S, T: CharSetWrapper + 'static> {
+    /// Matched character.
+    pub content: char,
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+    _phantom: PhantomData<&'static T>,
+}
+impl<S, T: CharSetWrapper> From<(char, Span<S>)> for CharSet<S, T> {
+    fn from((content, span): (char, Span<S>)) -> Self {
+        Self {
+            content,
+            span,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Cursor, R: RuleType, T: CharSetWrapper + 'static> TypedNode<C, R>
+    for CharSet<C::String, T>
+{
+    #[inline]
+    fn try_parse_partial_with(
+        mut input: C,
+        _stack: &mut Stack<Span<C::String>>,
+        _tracker: &mut Tracker<C::String, R>,
+    ) -> Option<(C, Self)> {
+        let start = input.clone();
+        let content = match_char_by(&mut input, T::contains)?;
+        let span = start.span(&input);
+        Some((input, Self::from((content, span))))
+    }
+
+    #[inline]
+    fn try_check_partial_with(
+        mut input: C,
+        _stack: &mut Stack<Span<C::String>>,
+        _tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        match input.match_char_by(T::contains) {
+            true => Some(input),
+            false => None,
+        }
+    }
+}
+impl<S, T: CharSetWrapper> Debug for CharSet<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharSet")
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
 /// Try to create stack slice.
 #[inline]
 fn stack_slice<'s, C: Cursor, R: RuleType>(
@@ -324,7 +420,7 @@ where
 {
     let mut matching_pos = input.clone();
     for span in iter {
-        match matching_pos.match_string(span.as_str().as_str()) {
+        match matching_pos.match_slice(&span.as_str()) {
             true => (),
             false => {
                 return None;
@@ -335,6 +431,77 @@ where
     Some((matching_pos, span))
 }
 
+/// Optionally match `T`.
+///
+/// Unlike [`Option`], it keeps its position even if `T` is not matched.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr, T)]
+pub struct Optional<S, T> {
+    /// Matched content.
+    pub content: Option<T>,
+    /// Matched span, which is empty if `T` is not matched.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+/// With an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr, T> From<Option<T>> for Optional<S, T> {
+    fn from(content: Option<T>) -> Self {
+        let span = placeholder();
+        Self { content, span }
+    }
+}
+impl<S: RefStr, T> Default for Optional<S, T> {
+    fn default() -> Self {
+        Self::from(None)
+    }
+}
+impl<S, T> Deref for Optional<S, T> {
+    type Target = Option<T>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.content
+    }
+}
+impl<S, T> DerefMut for Optional<S, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.content
+    }
+}
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Optional<C::String, T> {
+    #[inline]
+    fn try_parse_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<(C, Self)> {
+        let start = input.clone();
+        let (input, content) = Option::<T>::try_parse_partial_with(input, stack, tracker)?;
+        let span = start.span(&input);
+        Some((input, Self { content, span }))
+    }
+
+    #[inline]
+    fn try_check_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Option::<T>::try_check_partial_with(input, stack, tracker)
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> Option<C> {
+        Option::<T>::try_check_partial_reporting_with(input, stack, tracker, events)
+    }
+}
+
 /// Positive predicate.
 ///
 /// Peeked expressions will not occur in Pair/Pairs API.
@@ -410,18 +577,26 @@ impl<C: Cursor, R: RuleType, N: TypedNode<C, R>> TypedNode<C, R> for Positive<N>
 ///
 /// Will not contain anything.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive_where(Clone, Hash, PartialEq, Eq)]
-pub struct Negative<T> {
+#[derive_where(Clone, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Negative<S, T> {
+    /// Empty span at the position.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
     _phantom: PhantomData<T>,
 }
-impl<T> From<()> for Negative<T> {
-    fn from(_value: ()) -> Self {
+impl<S, T> From<Span<S>> for Negative<S, T> {
+    fn from(span: Span<S>) -> Self {
         Self {
+            span,
             _phantom: PhantomData,
         }
     }
 }
-impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Negative<T> {
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Negative<C::String, T> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -437,7 +612,8 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Negative<T>
                 }
                 None => {
                     stack.restore();
-                    Some((input, Self::from(())))
+                    let span = input.span(&input);
+                    Some((input, Self::from(span)))
                 }
             }
         })
@@ -464,7 +640,7 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Negative<T>
         })
     }
 }
-impl<T> Debug for Negative<T> {
+impl<S, T> Debug for Negative<S, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Negative").finish()
     }
@@ -472,19 +648,29 @@ impl<T> Debug for Negative<T> {
 
 /// Match any character.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct ANY {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct ANY<S> {
     /// Matched character.
     pub content: char,
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
 }
-impl<C: Cursor, R: RuleType> TypedNode<C, R> for ANY {
+impl<C: Cursor, R: RuleType> TypedNode<C, R> for ANY<C::String> {
     #[inline]
     fn try_parse_partial_with(
         mut input: C,
         _stack: &mut Stack<Span<C::String>>,
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
-        input.advance_char().map(|c| (input, Self { content: c }))
+        let start = input.clone();
+        let content = input.advance_char()?;
+        let span = start.span(&input);
+        Some((input, Self { content, span }))
     }
 
     #[inline]
@@ -499,9 +685,22 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for ANY {
 
 /// Match the start of input.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct SOI;
-impl<C: Cursor, R: RuleType> TypedNode<C, R> for SOI {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct SOI<S> {
+    /// Empty span at the position.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+impl<S> From<Span<S>> for SOI<S> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
+    }
+}
+impl<C: Cursor, R: RuleType> TypedNode<C, R> for SOI<C::String> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -509,7 +708,8 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for SOI {
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         if input.at_start() {
-            Some((input, Self))
+            let span = input.span(&input);
+            Some((input, Self::from(span)))
         } else {
             None
         }
@@ -533,9 +733,22 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for SOI {
 ///
 /// [`EOI`] will record its rule if not matched.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct EOI;
-impl<C: Cursor, R: RuleType> TypedNode<C, R> for EOI {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct EOI<S> {
+    /// Empty span at the position.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+impl<S> From<Span<S>> for EOI<S> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
+    }
+}
+impl<C: Cursor, R: RuleType> TypedNode<C, R> for EOI<C::String> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -543,7 +756,8 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for EOI {
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         if input.at_end() {
-            Some((input, Self))
+            let span = input.span(&input);
+            Some((input, Self::from(span)))
         } else {
             None
         }
@@ -578,18 +792,26 @@ pub enum NewLineType {
 /// Match a new line character.
 /// A built-in rule. Equivalent to `"\r\n" | "\n" | "\r"`.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct NEWLINE {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct NEWLINE<S> {
     /// Type of matched character.
     pub content: NewLineType,
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
 }
-impl<C: Cursor, R: RuleType> TypedNode<C, R> for NEWLINE {
+impl<C: Cursor, R: RuleType> TypedNode<C, R> for NEWLINE<C::String> {
     #[inline]
     fn try_parse_partial_with(
         mut input: C,
         _stack: &mut Stack<Span<C::String>>,
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
+        let start = input.clone();
         let (input, t) = if input.match_string("\r\n") {
             (input, NewLineType::CRLF)
         } else if input.match_string("\n") {
@@ -599,7 +821,8 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for NEWLINE {
         } else {
             return None;
         };
-        Some((input, Self { content: t }))
+        let span = start.span(&input);
+        Some((input, Self { content: t, span }))
     }
 
     #[inline]
@@ -675,7 +898,7 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for PEEK<C::String> {
     ) -> Option<(C, Self)> {
         let start = input.clone();
         match stack.peek() {
-            Some(string) => match input.match_string(string.as_str().as_str()) {
+            Some(string) => match input.match_slice(&string.as_str()) {
                 true => {
                     let span = Self::from(start.span(&input));
                     Some((input, span))
@@ -696,7 +919,7 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for PEEK<C::String> {
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<C> {
         match stack.peek() {
-            Some(string) => match input.match_string(string.as_str().as_str()) {
+            Some(string) => match input.match_slice(&string.as_str()) {
                 true => Some(input),
                 false => None,
             },
@@ -718,6 +941,13 @@ pub struct Skipped<T, Skip, const SKIP: usize> {
     /// Matched content.
     pub matched: T,
 }
+/// Wrap `matched` without skipped trivia.
+impl<T, Skip: Default, const SKIP: usize> From<T> for Skipped<T, Skip, SKIP> {
+    fn from(matched: T) -> Self {
+        let skipped = core::array::from_fn(|_| Skip::default());
+        Self { skipped, matched }
+    }
+}
 impl<T: Debug, Skip: Debug, const SKIP: usize> Debug for Skipped<T, Skip, SKIP> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if SKIP > 0 {
@@ -735,9 +965,22 @@ impl<T: Debug, Skip: Debug, const SKIP: usize> Debug for Skipped<T, Skip, SKIP>
 ///
 /// Fail if there is no span in the stack.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct DROP;
-impl<C: Cursor, R: RuleType> TypedNode<C, R> for DROP {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct DROP<S> {
+    /// Empty span at the position.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+impl<S> From<Span<S>> for DROP<S> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
+    }
+}
+impl<C: Cursor, R: RuleType> TypedNode<C, R> for DROP<C::String> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -745,7 +988,10 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for DROP {
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         match stack.pop() {
-            Some(_) => Some((input, Self)),
+            Some(_) => {
+                let span = input.span(&input);
+                Some((input, Self::from(span)))
+            }
             None => {
                 tracker.empty_stack(input);
                 None
@@ -789,7 +1035,7 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for POP<C::String> {
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         match stack.pop() {
-            Some(span) => match input.match_string(span.as_str().as_str()) {
+            Some(span) => match input.match_slice(&span.as_str()) {
                 true => Some((input, Self::from(span))),
                 false => None,
             },
@@ -807,7 +1053,7 @@ impl<C: Cursor, R: RuleType> TypedNode<C, R> for POP<C::String> {
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<C> {
         match stack.pop() {
-            Some(span) => match input.match_string(span.as_str().as_str()) {
+            Some(span) => match input.match_slice(&span.as_str()) {
                 true => Some(input),
                 false => None,
             },
@@ -891,17 +1137,29 @@ impl<S> Debug for AlwaysFail<S> {
 
 /// Empty.
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[derive_where(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Empty<S>(PhantomData<S>);
-impl<S> Default for Empty<S> {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct Empty<S> {
+    /// Empty span at the position.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+/// With an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr> Default for Empty<S> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self::from(placeholder())
+    }
+}
+impl<S> From<Span<S>> for Empty<S> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
     }
 }
 impl<C: Cursor, R: RuleType> NeverFailedTypedNode<C, R> for Empty<C::String> {
     #[inline]
     fn parse_with(input: C, _stack: &mut Stack<Span<C::String>>) -> (C, Self) {
-        (input, Self::default())
+        let span = input.span(&input);
+        (input, Self::from(span))
     }
 
     fn check_with(input: C, _stack: &mut Stack<Span<C::String>>) -> C {
@@ -962,9 +1220,19 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Push<T> {
         input: C,
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
     ) -> Option<C> {
         let start = input.clone();
-        let input = T::try_check_partial_with(input, stack, tracker)?;
+        let input = T::try_check_partial_reporting_with(input, stack, tracker, events)?;
         stack.push(start.span(&input));
         Some(input)
     }
@@ -983,22 +1251,31 @@ impl<T> DerefMut for Push<T> {
 
 /// Simply push a literal to the [Stack].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive_where(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PushLiteral<T: StringWrapper + 'static> {
-    /// Matched content.
+#[derive_where(Clone, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct PushLiteral<S, T: StringWrapper + 'static> {
+    /// Empty span at the position.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
     _phantom: PhantomData<&'static T>,
 }
-impl<T: StringWrapper> PushLiteral<T> {
-    const fn new() -> Self {
+impl<S, T: StringWrapper> From<Span<S>> for PushLiteral<S, T> {
+    fn from(span: Span<S>) -> Self {
         Self {
+            span,
             _phantom: PhantomData,
         }
     }
 }
-impl<T: StringWrapper> StringWrapper for PushLiteral<T> {
+impl<S, T: StringWrapper> StringWrapper for PushLiteral<S, T> {
     const CONTENT: &'static str = T::CONTENT;
 }
-impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for PushLiteral<T> {
+impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R>
+    for PushLiteral<C::String, T>
+{
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -1006,7 +1283,8 @@ impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for Pus
         _tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         stack.push(Span::new_full(C::String::from_static(T::CONTENT)));
-        Some((input, Self::new()))
+        let span = input.span(&input);
+        Some((input, Self::from(span)))
     }
 
     #[inline]
@@ -1018,7 +1296,7 @@ impl<C: Cursor, R: RuleType, T: StringWrapper + 'static> TypedNode<C, R> for Pus
         Some(input)
     }
 }
-impl<T: StringWrapper> Debug for PushLiteral<T> {
+impl<S, T: StringWrapper> Debug for PushLiteral<S, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PushLiteral").finish()
     }
@@ -1026,10 +1304,23 @@ impl<T: StringWrapper> Debug for PushLiteral<T> {
 
 /// Match `[START..END]` in top-to-bottom order of the stack.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PeekSlice2<const START: i32, const END: i32>;
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct PeekSlice2<S, const START: i32, const END: i32> {
+    /// Matched span.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+impl<S, const START: i32, const END: i32> From<Span<S>> for PeekSlice2<S, START, END> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
+    }
+}
 impl<C: Cursor, R: RuleType, const START: i32, const END: i32> TypedNode<C, R>
-    for PeekSlice2<START, END>
+    for PeekSlice2<C::String, START, END>
 {
     #[inline]
     fn try_parse_partial_with(
@@ -1038,8 +1329,8 @@ impl<C: Cursor, R: RuleType, const START: i32, const END: i32> TypedNode<C, R>
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         let spans = stack_slice(input.clone(), START, Some(END), stack, tracker)?;
-        let (input, _) = peek_spans::<C, R>(input, spans, tracker)?;
-        Some((input, Self))
+        let (input, span) = peek_spans::<C, R>(input, spans, tracker)?;
+        Some((input, Self::from(span)))
     }
 
     #[inline]
@@ -1055,9 +1346,20 @@ impl<C: Cursor, R: RuleType, const START: i32, const END: i32> TypedNode<C, R>
 }
 
 /// Match `[START..]` in top-to-bottom order of the stack.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PeekSlice1<const START: i32>;
-impl<C: Cursor, R: RuleType, const START: i32> TypedNode<C, R> for PeekSlice1<START> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct PeekSlice1<S, const START: i32> {
+    /// Matched span.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+impl<S, const START: i32> From<Span<S>> for PeekSlice1<S, START> {
+    fn from(span: Span<S>) -> Self {
+        Self { span }
+    }
+}
+impl<C: Cursor, R: RuleType, const START: i32> TypedNode<C, R> for PeekSlice1<C::String, START> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -1065,8 +1367,8 @@ impl<C: Cursor, R: RuleType, const START: i32> TypedNode<C, R> for PeekSlice1<ST
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
         let spans = stack_slice(input.clone(), START, None, stack, tracker)?;
-        let (input, _) = peek_spans::<C, R>(input, spans, tracker)?;
-        Some((input, Self))
+        let (input, span) = peek_spans::<C, R>(input, spans, tracker)?;
+        Some((input, Self::from(span)))
     }
 
     #[inline]
@@ -1083,48 +1385,51 @@ impl<C: Cursor, R: RuleType, const START: i32> TypedNode<C, R> for PeekSlice1<ST
 
 /// ASCII Digit. `'0'..'9'`
 #[allow(non_camel_case_types)]
-pub type ASCII_DIGIT = CharRange<'0', '9'>;
+pub type ASCII_DIGIT<S> = CharRange<S, '0', '9'>;
 
 /// Non-zero ASCII Digit. `'1'..'9'`
 #[allow(non_camel_case_types)]
-pub type ASCII_NONZERO_DIGIT = CharRange<'1', '9'>;
+pub type ASCII_NONZERO_DIGIT<S> = CharRange<S, '1', '9'>;
 
 /// Binary ASCII Digit. `'0'..'1'`
 #[allow(non_camel_case_types)]
-pub type ASCII_BIN_DIGIT = CharRange<'0', '1'>;
+pub type ASCII_BIN_DIGIT<S> = CharRange<S, '0', '1'>;
 
 /// Octal ASCII Digit. `'0'..'7'`
 #[allow(non_camel_case_types)]
-pub type ASCII_OCT_DIGIT = CharRange<'0', '7'>;
+pub type ASCII_OCT_DIGIT<S> = CharRange<S, '0', '7'>;
 
-use crate::{
-    choices::{Choice2, Choice3},
-    input::RefStr,
-    Cursor,
-};
+use crate::{input::RefStr, Cursor};
 /// Hexadecimal ASCII Digit. `'0'..'9' | 'a'..'f' | 'A'..'F'`
 #[allow(non_camel_case_types)]
-pub type ASCII_HEX_DIGIT = Choice3<ASCII_DIGIT, CharRange<'a', 'f'>, CharRange<'A', 'F'>>;
+pub type ASCII_HEX_DIGIT<S> = CharSet<S, char_sets::ASCII_HEX_DIGIT>;
 
 /// Lower case ASCII alphabet.
 #[allow(non_camel_case_types)]
-pub type ASCII_ALPHA_LOWER = CharRange<'a', 'z'>;
+pub type ASCII_ALPHA_LOWER<S> = CharRange<S, 'a', 'z'>;
 
 /// Upper case ASCII alphabet.
 #[allow(non_camel_case_types)]
-pub type ASCII_ALPHA_UPPER = CharRange<'A', 'Z'>;
+pub type ASCII_ALPHA_UPPER<S> = CharRange<S, 'A', 'Z'>;
 
-/// ASCII alphabet.
+/// ASCII alphabet. `'a'..'z' | 'A'..'Z'`
 #[allow(non_camel_case_types)]
-pub type ASCII_ALPHA = Choice2<ASCII_ALPHA_LOWER, ASCII_ALPHA_UPPER>;
+pub type ASCII_ALPHA<S> = CharSet<S, char_sets::ASCII_ALPHA>;
 
-/// ASCII alphabet or digit.
+/// ASCII alphabet or digit. `'0'..'9' | 'a'..'z' | 'A'..'Z'`
 #[allow(non_camel_case_types)]
-pub type ASCII_ALPHANUMERIC = Choice2<ASCII_ALPHA, ASCII_DIGIT>;
+pub type ASCII_ALPHANUMERIC<S> = CharSet<S, char_sets::ASCII_ALPHANUMERIC>;
 
 /// ASCII alphabet.
 #[allow(non_camel_case_types)]
-pub type ASCII = CharRange<'\x00', '\x7f'>;
+pub type ASCII<S> = CharRange<S, '\x00', '\x7f'>;
+
+/// An empty span over an empty input,
+/// as a placeholder for nodes created without parsing.
+#[inline]
+pub(crate) fn placeholder<S: RefStr>() -> Span<S> {
+    Span::new_full(S::from_static(""))
+}
 
 /// Match char by a predicate.
 ///
@@ -1156,3 +1461,18 @@ pub fn restore_on_none<S: RefStr, T>(
     }
     res
 }
+
+/// Restore on error, and roll back [`Event`](crate::tracker::Event)s of the failed attempt.
+#[inline]
+pub fn restore_on_none_with<S: RefStr, R: RuleType, E: EventSink<S, R>, T>(
+    stack: &mut Stack<Span<S>>,
+    events: &mut E,
+    f: impl FnOnce(&mut Stack<Span<S>>, &mut E) -> Option<T>,
+) -> Option<T> {
+    let len = events.reported();
+    let res = restore_on_none(stack, |stack| f(stack, events));
+    if res.is_none() {
+        events.rollback(len);
+    }
+    res
+}
@@ -12,13 +12,14 @@
 //! Normally you don't need to reference this module by yourself.
 
 use crate::{
-    predefined_node::{restore_on_none, Skipped},
-    tracker::Tracker,
+    predefined_node::{placeholder, restore_on_none, restore_on_none_with, Skipped},
+    tracker::{EventSink, Tracker},
     wrapper::BoundWrapper,
-    Cursor, NeverFailedTypedNode, RuleType, Span, Stack, TypedNode,
+    Cursor, NeverFailedTypedNode, RefStr, RuleType, Span, Stack, TypedNode,
 };
 use alloc::vec::Vec;
 use core::ops::{Deref, DerefMut};
+use derive_where::derive_where;
 
 type Iter<'n, T, IGNORED, const SKIP: usize> = core::iter::Map<
     alloc::slice::Iter<'n, Skipped<T, IGNORED, SKIP>>,
@@ -31,20 +32,36 @@ type IntoIter<T, IGNORED, const SKIP: usize> = core::iter::Map<
 
 /// Repeatably match `T` at least `MIN` times.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct AtomicRepeat<T> {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr, T)]
+pub struct AtomicRepeat<S, T> {
     /// Skipped and Matched expressions.
     pub content: Vec<T>,
+    /// Matched span, which is empty if nothing is matched.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
 }
-impl<T> Default for AtomicRepeat<T> {
+/// With an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr, T> From<Vec<T>> for AtomicRepeat<S, T> {
+    fn from(content: Vec<T>) -> Self {
+        let span = placeholder();
+        Self { content, span }
+    }
+}
+impl<S: RefStr, T> Default for AtomicRepeat<S, T> {
     fn default() -> Self {
-        let content = Vec::new();
-        Self { content }
+        Self::from(Vec::new())
     }
 }
-impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> NeverFailedTypedNode<C, R> for AtomicRepeat<T> {
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> NeverFailedTypedNode<C, R>
+    for AtomicRepeat<C::String, T>
+{
     #[inline]
     fn parse_with(mut input: C, stack: &mut Stack<Span<C::String>>) -> (C, Self) {
+        let start = input.clone();
         let mut vec = Vec::new();
         let mut tracker = Tracker::new(input.as_position());
 
@@ -59,7 +76,8 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> NeverFailedTypedNode<C, R> for
                 None => break,
             }
         }
-        (input, Self { content: vec })
+        let span = start.span(&input);
+        (input, Self { content: vec, span })
     }
 
     #[inline]
@@ -79,7 +97,7 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> NeverFailedTypedNode<C, R> for
         input
     }
 }
-impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for AtomicRepeat<T> {
+impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for AtomicRepeat<C::String, T> {
     #[inline]
     fn try_parse_partial_with(
         input: C,
@@ -98,39 +116,58 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for AtomicRepea
         Some(Self::check_with(input, stack))
     }
 }
-impl<T> Deref for AtomicRepeat<T> {
+impl<S, T> Deref for AtomicRepeat<S, T> {
     type Target = Vec<T>;
     fn deref(&self) -> &Self::Target {
         &self.content
     }
 }
-impl<T> DerefMut for AtomicRepeat<T> {
+impl<S, T> DerefMut for AtomicRepeat<S, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.content
     }
 }
-impl<T: Clone + PartialEq> BoundWrapper for AtomicRepeat<T> {
+impl<S, T: Clone + PartialEq> BoundWrapper for AtomicRepeat<S, T> {
     const MIN: usize = 0;
     const MAX: usize = usize::MAX;
 }
 
 /// Repeatably match `T` at least `MIN` times.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct RepeatMin<T, const MIN: usize> {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr, T)]
+pub struct RepeatMin<S, T, const MIN: usize> {
     /// Skipped and Matched expressions.
     pub content: Vec<T>,
+    /// Matched span, which is empty if nothing is matched.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+/// Wrap matched items without skipped trivia,
+/// with an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr, T, Skip: Default, const SKIP: usize, const MIN: usize> From<Vec<T>>
+    for RepeatMin<S, Skipped<T, Skip, SKIP>, MIN>
+{
+    fn from(matched: Vec<T>) -> Self {
+        let content = matched.into_iter().map(Skipped::from).collect();
+        let span = placeholder();
+        Self { content, span }
+    }
 }
 impl<
         C: Cursor,
         R: RuleType,
         T: TypedNode<C, R>,
-        Skip: NeverFailedTypedNode<C, R>,
+        Skip: NeverFailedTypedNode<C, R> + Default,
         const SKIP: usize,
-    > NeverFailedTypedNode<C, R> for RepeatMin<Skipped<T, Skip, SKIP>, 0>
+    > NeverFailedTypedNode<C, R> for RepeatMin<C::String, Skipped<T, Skip, SKIP>, 0>
 {
     #[inline]
     fn parse_with(mut input: C, stack: &mut Stack<Span<C::String>>) -> (C, Self) {
+        let start = input.clone();
         let mut vec = Vec::new();
         let mut tracker = Tracker::new(input.as_position());
 
@@ -145,7 +182,8 @@ impl<
                 None => break,
             }
         }
-        (input, Self { content: vec })
+        let span = start.span(&input);
+        (input, Self { content: vec, span })
     }
 
     #[inline]
@@ -154,7 +192,13 @@ impl<
 
         for i in 0usize.. {
             match restore_on_none(stack, |stack| {
-                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, &mut tracker, i)
+                try_check_unit::<C, R, T, Skip, SKIP>(
+                    input.clone(),
+                    stack,
+                    &mut tracker,
+                    &mut (),
+                    i,
+                )
             }) {
                 Some(next) => {
                     input = next;
@@ -165,20 +209,21 @@ impl<
         input
     }
 }
-impl<T> Default for RepeatMin<T, 0> {
+impl<S: RefStr, T> Default for RepeatMin<S, T, 0> {
     fn default() -> Self {
         let content = Vec::new();
-        Self { content }
+        let span = placeholder();
+        Self { content, span }
     }
 }
 impl<
         C: Cursor,
         R: RuleType,
         T: TypedNode<C, R>,
-        Skip: NeverFailedTypedNode<C, R>,
+        Skip: NeverFailedTypedNode<C, R> + Default,
         const SKIP: usize,
         const MIN: usize,
-    > TypedNode<C, R> for RepeatMin<Skipped<T, Skip, SKIP>, MIN>
+    > TypedNode<C, R> for RepeatMin<C::String, Skipped<T, Skip, SKIP>, MIN>
 {
     #[inline]
     fn try_parse_partial_with(
@@ -186,6 +231,7 @@ impl<
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
+        let start = input.clone();
         let mut vec = Vec::new();
 
         for i in 0usize.. {
@@ -206,18 +252,29 @@ impl<
             }
         }
 
-        Some((input, Self { content: vec }))
+        let span = start.span(&input);
+        Some((input, Self { content: vec, span }))
     }
 
     #[inline]
     fn try_check_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
         mut input: C,
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
     ) -> Option<C> {
         for i in 0usize.. {
-            match restore_on_none(stack, |stack| {
-                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, tracker, i)
+            match restore_on_none_with(stack, events, |stack, events| {
+                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, tracker, events, i)
             }) {
                 Some(next) => {
                     input = next;
@@ -234,7 +291,9 @@ impl<
         Some(input)
     }
 }
-impl<T, IGNORED, const SKIP: usize, const MIN: usize> RepeatMin<Skipped<T, IGNORED, SKIP>, MIN> {
+impl<S, T, IGNORED, const SKIP: usize, const MIN: usize>
+    RepeatMin<S, Skipped<T, IGNORED, SKIP>, MIN>
+{
     /// Returns an iterator over all matched expressions by reference.
     pub fn iter_matched(&'_ self) -> Iter<'_, T, IGNORED, SKIP> {
         self.content.iter().map(|s| &s.matched)
@@ -244,7 +303,7 @@ impl<T, IGNORED, const SKIP: usize, const MIN: usize> RepeatMin<Skipped<T, IGNOR
         self.content.into_iter().map(|s| s.matched)
     }
 }
-impl<T, const MIN: usize> RepeatMin<T, MIN> {
+impl<S, T, const MIN: usize> RepeatMin<S, T, MIN> {
     /// Returns an iterator over all skipped or matched expressions by reference.
     pub fn iter_all(&'_ self) -> alloc::slice::Iter<'_, T> {
         self.content.iter()
@@ -254,23 +313,42 @@ impl<T, const MIN: usize> RepeatMin<T, MIN> {
         self.content.into_iter()
     }
 }
-impl<T: Clone + PartialEq, const MIN: usize> BoundWrapper for RepeatMin<T, MIN> {
+impl<S, T: Clone + PartialEq, const MIN: usize> BoundWrapper for RepeatMin<S, T, MIN> {
     const MIN: usize = MIN;
     const MAX: usize = usize::MAX;
 }
 
 /// Repeatably match `T` at least `MIN` times and at most `MAX` times.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct RepeatMinMax<T, const MIN: usize, const MAX: usize> {
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr, T)]
+pub struct RepeatMinMax<S, T, const MIN: usize, const MAX: usize> {
     /// Skipped and Matched expressions.
     pub content: Vec<T>,
+    /// Matched span, which is empty if nothing is matched.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "placeholder", bound(deserialize = "S: RefStr"))
+    )]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+}
+/// Wrap matched items without skipped trivia,
+/// with an empty span, which is moved to the built text by [`Builder`](crate::builder::Builder).
+impl<S: RefStr, T, Skip: Default, const SKIP: usize, const MIN: usize, const MAX: usize>
+    From<Vec<T>> for RepeatMinMax<S, Skipped<T, Skip, SKIP>, MIN, MAX>
+{
+    fn from(matched: Vec<T>) -> Self {
+        let content = matched.into_iter().map(Skipped::from).collect();
+        let span = placeholder();
+        Self { content, span }
+    }
 }
 
-impl<T, const MAX: usize> Default for RepeatMinMax<T, 0, MAX> {
+impl<S: RefStr, T, const MAX: usize> Default for RepeatMinMax<S, T, 0, MAX> {
     fn default() -> Self {
         Self {
             content: Vec::new(),
+            span: placeholder(),
         }
     }
 }
@@ -278,13 +356,14 @@ impl<
         C: Cursor,
         R: RuleType,
         T: TypedNode<C, R>,
-        Skip: NeverFailedTypedNode<C, R>,
+        Skip: NeverFailedTypedNode<C, R> + Default,
         const SKIP: usize,
         const MAX: usize,
-    > NeverFailedTypedNode<C, R> for RepeatMinMax<Skipped<T, Skip, SKIP>, 0, MAX>
+    > NeverFailedTypedNode<C, R> for RepeatMinMax<C::String, Skipped<T, Skip, SKIP>, 0, MAX>
 {
     #[inline]
     fn parse_with(mut input: C, stack: &mut Stack<Span<C::String>>) -> (C, Self) {
+        let start = input.clone();
         let mut vec = Vec::new();
 
         let mut tracker = Tracker::new(input.as_position());
@@ -303,7 +382,8 @@ impl<
             }
         }
 
-        (input, Self { content: vec })
+        let span = start.span(&input);
+        (input, Self { content: vec, span })
     }
 
     #[inline]
@@ -312,7 +392,13 @@ impl<
 
         for i in 0..MAX {
             match restore_on_none(stack, |stack| {
-                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, &mut tracker, i)
+                try_check_unit::<C, R, T, Skip, SKIP>(
+                    input.clone(),
+                    stack,
+                    &mut tracker,
+                    &mut (),
+                    i,
+                )
             }) {
                 Some(next) => {
                     input = next;
@@ -330,11 +416,11 @@ impl<
         C: Cursor,
         R: RuleType,
         T: TypedNode<C, R>,
-        Skip: NeverFailedTypedNode<C, R>,
+        Skip: NeverFailedTypedNode<C, R> + Default,
         const SKIP: usize,
         const MIN: usize,
         const MAX: usize,
-    > TypedNode<C, R> for RepeatMinMax<Skipped<T, Skip, SKIP>, MIN, MAX>
+    > TypedNode<C, R> for RepeatMinMax<C::String, Skipped<T, Skip, SKIP>, MIN, MAX>
 {
     #[inline]
     fn try_parse_partial_with(
@@ -342,6 +428,7 @@ impl<
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<(C, Self)> {
+        let start = input.clone();
         let mut vec = Vec::new();
 
         for i in 0..MAX {
@@ -362,7 +449,8 @@ impl<
             }
         }
 
-        Some((input, Self { content: vec }))
+        let span = start.span(&input);
+        Some((input, Self { content: vec, span }))
     }
 
     #[inline]
@@ -370,12 +458,22 @@ impl<
         input: C,
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
     ) -> Option<C> {
         let mut input = input;
 
         for i in 0..MAX {
-            match restore_on_none(stack, |stack| {
-                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, tracker, i)
+            match restore_on_none_with(stack, events, |stack, events| {
+                try_check_unit::<C, R, T, Skip, SKIP>(input.clone(), stack, tracker, events, i)
             }) {
                 Some(next) => {
                     input = next;
@@ -393,8 +491,8 @@ impl<
         Some(input)
     }
 }
-impl<T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize>
-    RepeatMinMax<Skipped<T, IGNORED, SKIP>, MIN, MAX>
+impl<S, T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize>
+    RepeatMinMax<S, Skipped<T, IGNORED, SKIP>, MIN, MAX>
 {
     /// Returns an iterator over all matched expressions by reference.
     pub fn iter_matched(&'_ self) -> Iter<'_, T, IGNORED, SKIP> {
@@ -405,7 +503,7 @@ impl<T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize>
         self.content.into_iter().map(|s| s.matched)
     }
 }
-impl<T, const MIN: usize, const MAX: usize> RepeatMinMax<T, MIN, MAX> {
+impl<S, T, const MIN: usize, const MAX: usize> RepeatMinMax<S, T, MIN, MAX> {
     /// Returns an iterator over all skipped or matched expressions by reference.
     pub fn iter_all(&'_ self) -> alloc::slice::Iter<'_, T> {
         self.content.iter()
@@ -415,35 +513,35 @@ impl<T, const MIN: usize, const MAX: usize> RepeatMinMax<T, MIN, MAX> {
         self.content.into_iter()
     }
 }
-impl<T: Clone + PartialEq, const MIN: usize, const MAX: usize> BoundWrapper
-    for RepeatMinMax<T, MIN, MAX>
+impl<S, T: Clone + PartialEq, const MIN: usize, const MAX: usize> BoundWrapper
+    for RepeatMinMax<S, T, MIN, MAX>
 {
     const MIN: usize = MIN;
     const MAX: usize = MAX;
 }
 
 /// Repeat exactly `TIMES` times.
-pub type RepExact<T, IGNORED, const SKIP: usize, const TIMES: usize> =
-    RepeatMinMax<Skipped<T, IGNORED, SKIP>, TIMES, TIMES>;
+pub type RepExact<S, T, IGNORED, const SKIP: usize, const TIMES: usize> =
+    RepeatMinMax<S, Skipped<T, IGNORED, SKIP>, TIMES, TIMES>;
 /// Repeat at least `MIN` times.
-pub type RepMin<T, IGNORED, const SKIP: usize, const MIN: usize> =
-    RepeatMin<Skipped<T, IGNORED, SKIP>, MIN>;
+pub type RepMin<S, T, IGNORED, const SKIP: usize, const MIN: usize> =
+    RepeatMin<S, Skipped<T, IGNORED, SKIP>, MIN>;
 /// Repeat at most `MAX` times.
-pub type RepMax<T, IGNORED, const SKIP: usize, const MAX: usize> =
-    RepeatMinMax<Skipped<T, IGNORED, SKIP>, 0, MAX>;
+pub type RepMax<S, T, IGNORED, const SKIP: usize, const MAX: usize> =
+    RepeatMinMax<S, Skipped<T, IGNORED, SKIP>, 0, MAX>;
 /// Repeat at least `MIN` and at most `MAX` times (both inclusive).
-pub type RepMinMax<T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize> =
-    RepeatMinMax<Skipped<T, IGNORED, SKIP>, MIN, MAX>;
+pub type RepMinMax<S, T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize> =
+    RepeatMinMax<S, Skipped<T, IGNORED, SKIP>, MIN, MAX>;
 /// Repeat arbitrary times.
-pub type Rep<T, IGNORED, const SKIP: usize> = RepeatMin<Skipped<T, IGNORED, SKIP>, 0>;
+pub type Rep<S, T, IGNORED, const SKIP: usize> = RepeatMin<S, Skipped<T, IGNORED, SKIP>, 0>;
 /// Repeat at least one times.
-pub type RepOnce<T, IGNORED, const SKIP: usize> = RepeatMin<Skipped<T, IGNORED, SKIP>, 1>;
+pub type RepOnce<S, T, IGNORED, const SKIP: usize> = RepeatMin<S, Skipped<T, IGNORED, SKIP>, 1>;
 
 fn try_parse_unit<
     C: Cursor,
     R: RuleType,
     T: TypedNode<C, R>,
-    Skip: NeverFailedTypedNode<C, R>,
+    Skip: NeverFailedTypedNode<C, R> + Default,
     const SKIP: usize,
 >(
     mut input: C,
@@ -476,6 +574,7 @@ fn try_check_unit<
     mut input: C,
     stack: &mut Stack<Span<C::String>>,
     tracker: &mut Tracker<C::String, R>,
+    events: &mut impl EventSink<C::String, R>,
     i: usize,
 ) -> Option<C> {
     for _ in 0..SKIP {
@@ -484,7 +583,7 @@ fn try_check_unit<
             input = next;
         }
     }
-    let next = T::try_check_partial_with(input, stack, tracker)?;
+    let next = T::try_check_partial_reporting_with(input, stack, tracker, events)?;
     input = next;
     Some(input)
 }
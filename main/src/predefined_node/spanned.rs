@@ -0,0 +1,126 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! [`Spanned`] and [`Display`] for predefined nodes.
+//!
+//! Nodes keep their spans, or derive them from their contents, and print the texts of their spans.
+//! Nodes that consume nothing have empty spans at their positions.
+
+use super::{
+    AtomicRepeat, CharRange, CharSet, Empty, Insens, Lazy, Negative, Optional, PeekSlice1,
+    PeekSlice2, Positive, Push, PushLiteral, RepeatMin, RepeatMinMax, Skip, SkipChar, SkipTrivia,
+    Skipped, Str, ANY, DROP, EOI, NEWLINE, PEEK, PEEK_ALL, POP, POP_ALL, SOI,
+};
+use crate::{
+    CharSetWrapper, RefStr, RuleType, Span, Spanned, StringArrayWrapper, StringWrapper,
+    TriviaWrapper,
+};
+use alloc::boxed::Box;
+use core::fmt::{self, Display};
+
+/// Implement [`Spanned`] and [`Display`] by the `span` field.
+macro_rules! impl_by_span {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl<S: RefStr, R: RuleType $(, $($tt)*)?> Spanned<S, R> for $node {
+            #[inline]
+            fn span(&self) -> Span<S> {
+                self.span.clone()
+            }
+        }
+        impl<S: RefStr $(, $($tt)*)?> Display for $node {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.span.as_str().to_text())
+            }
+        }
+    };
+}
+
+impl_by_span!(Str<S, T>, T: StringWrapper);
+impl_by_span!(Insens<S, T>, T);
+impl_by_span!(Skip<S, Strings>, Strings: StringArrayWrapper);
+impl_by_span!(SkipChar<S, N>, const N: usize);
+impl_by_span!(CharRange<S, MIN, MAX>, const MIN: char, const MAX: char);
+impl_by_span!(CharSet<S, T>, T: CharSetWrapper);
+impl_by_span!(Negative<S, T>, T);
+impl_by_span!(ANY<S>);
+impl_by_span!(SOI<S>);
+impl_by_span!(EOI<S>);
+impl_by_span!(NEWLINE<S>);
+impl_by_span!(PEEK<S>);
+impl_by_span!(PEEK_ALL<S>);
+impl_by_span!(DROP<S>);
+impl_by_span!(POP<S>);
+impl_by_span!(POP_ALL<S>);
+impl_by_span!(Empty<S>);
+impl_by_span!(PushLiteral<S, T>, T: StringWrapper);
+impl_by_span!(PeekSlice2<S, START, END>, const START: i32, const END: i32);
+impl_by_span!(PeekSlice1<S, START>, const START: i32);
+impl_by_span!(SkipTrivia<S, T>, T: TriviaWrapper);
+impl_by_span!(Optional<S, T>, T);
+impl_by_span!(AtomicRepeat<S, T>, T);
+impl_by_span!(RepeatMin<S, T, MIN>, T, const MIN: usize);
+impl_by_span!(RepeatMinMax<S, T, MIN, MAX>, T, const MIN: usize, const MAX: usize);
+impl<S: RefStr, T> Display for Lazy<S, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.span.as_str().to_text())
+    }
+}
+
+impl<S: RefStr, R: RuleType, T: Spanned<S, R> + ?Sized> Spanned<S, R> for Box<T> {
+    #[inline]
+    fn span(&self) -> Span<S> {
+        (**self).span()
+    }
+}
+impl<S: RefStr, R: RuleType, T: Spanned<S, R>> Spanned<S, R> for Push<T> {
+    #[inline]
+    fn span(&self) -> Span<S> {
+        self.content.span()
+    }
+}
+impl<T: Display> Display for Push<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.content.fmt(f)
+    }
+}
+/// Skipped trivia before the matched content are not included.
+impl<S: RefStr, R: RuleType, T: Spanned<S, R>, Skip, const SKIP: usize> Spanned<S, R>
+    for Skipped<T, Skip, SKIP>
+{
+    #[inline]
+    fn span(&self) -> Span<S> {
+        self.matched.span()
+    }
+}
+/// Skipped trivia are printed before the matched content,
+/// so that sequences print their texts as they are.
+impl<T: Display, Skip: Display, const SKIP: usize> Display for Skipped<T, Skip, SKIP> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.skipped.iter().try_for_each(|skipped| skipped.fmt(f))?;
+        self.matched.fmt(f)
+    }
+}
+/// Positive predicates consume nothing, so the span is empty at the start of the content.
+impl<S: RefStr, R: RuleType, T: Spanned<S, R>> Spanned<S, R> for Positive<T> {
+    #[inline]
+    fn span(&self) -> Span<S> {
+        let start = self.content.span().start_pos();
+        start.span(&start)
+    }
+}
+impl<T> Display for Positive<T> {
+    #[inline(always)]
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Fast paths for skipping `WHITESPACE` and `COMMENT`.
+
+use super::placeholder;
+use crate::{
+    tracker::Tracker, typed_node::NeverFailedTypedNode, Cursor, RefStr, RuleType, Span, Stack,
+    TriviaWrapper, TypedNode,
+};
+use core::{fmt, marker::PhantomData};
+use derive_where::derive_where;
+
+/// A common shape of trivia that can be skipped without building typed nodes.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Trivia {
+    /// A character in one of the inclusive ranges.
+    Chars(&'static [(char, char)]),
+    /// An exact string.
+    Str(&'static str),
+    /// `start ~ (!(end[0] | end[1] | ...) ~ ANY)*`,
+    /// followed by one of `end` if `consume_end` is `true`.
+    Until {
+        /// The string that starts the trivia, such as `"//"`.
+        start: &'static str,
+        /// Strings that end the trivia, such as `"\n"`.
+        end: &'static [&'static str],
+        /// Whether the end is part of the trivia.
+        ///
+        /// If `true`, the trivia is not matched when no end is found.
+        /// Otherwise, the trivia may also end at the end of input.
+        consume_end: bool,
+    },
+}
+
+impl Trivia {
+    /// Try to skip this trivia once.
+    ///
+    /// Return `true` if some input is consumed, and `input` is only updated in that case.
+    #[inline]
+    pub fn skip<C: Cursor>(&self, input: &mut C) -> bool {
+        match *self {
+            Self::Chars(ranges) => {
+                input.match_char_by(|c| ranges.iter().any(|&(min, max)| min <= c && c <= max))
+            }
+            Self::Str(string) => !string.is_empty() && input.match_string(string),
+            Self::Until {
+                start,
+                end,
+                consume_end,
+            } => {
+                let mut next = input.clone();
+                if start.is_empty() || !next.match_string(start) {
+                    return false;
+                }
+                let found = next.skip_until(end);
+                if consume_end && !(found && end.iter().any(|end| next.match_string(end))) {
+                    return false;
+                }
+                *input = next;
+                true
+            }
+        }
+    }
+}
+
+/// Skip trivia described by `T` (by [`TriviaWrapper`]) as many times as possible.
+///
+/// An alternative to repetitions of `WHITESPACE` and `COMMENT`,
+/// which advances the cursor directly and keeps only the span of skipped trivia.
+///
+/// Generated parsers use it only with attribute `#[fast_trivia]`,
+/// and only if `WHITESPACE` and `COMMENT` are silent rules made up of common shapes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive_where(Clone, Hash, PartialEq, Eq, PartialOrd, Ord; S: RefStr)]
+pub struct SkipTrivia<S, T: TriviaWrapper + 'static> {
+    /// Skipped span.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    #[derive_where(skip(EqHashOrd))]
+    pub span: Span<S>,
+    _phantom: PhantomData<&'static T>,
+}
+/// With an empty span in no input, used where nothing is skipped, such as before the first item of a repetition.
+impl<S: RefStr, T: TriviaWrapper> Default for SkipTrivia<S, T> {
+    fn default() -> Self {
+        Self::from(placeholder())
+    }
+}
+impl<S, T: TriviaWrapper> From<Span<S>> for SkipTrivia<S, T> {
+    fn from(span: Span<S>) -> Self {
+        Self {
+            span,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<S, T: TriviaWrapper> SkipTrivia<S, T> {
+    #[inline]
+    fn skip<C: Cursor>(mut input: C) -> C {
+        while T::TRIVIA.iter().any(|trivia| trivia.skip(&mut input)) {}
+        input
+    }
+}
+impl<C: Cursor, R: RuleType, T: TriviaWrapper> NeverFailedTypedNode<C, R>
+    for SkipTrivia<C::String, T>
+{
+    #[inline]
+    fn parse_with(input: C, _stack: &mut Stack<Span<C::String>>) -> (C, Self) {
+        let start = input.clone();
+        let input = Self::skip(input);
+        let span = start.span(&input);
+        (input, Self::from(span))
+    }
+
+    #[inline]
+    fn check_with(input: C, _stack: &mut Stack<Span<C::String>>) -> C {
+        Self::skip(input)
+    }
+}
+impl<C: Cursor, R: RuleType, T: TriviaWrapper> TypedNode<C, R> for SkipTrivia<C::String, T> {
+    #[inline]
+    fn try_parse_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        _tracker: &mut Tracker<C::String, R>,
+    ) -> Option<(C, Self)> {
+        Some(<Self as NeverFailedTypedNode<C, R>>::parse_with(
+            input, stack,
+        ))
+    }
+
+    #[inline]
+    fn try_check_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        _tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Some(<Self as NeverFailedTypedNode<C, R>>::check_with(
+            input, stack,
+        ))
+    }
+}
+impl<S, T: TriviaWrapper> fmt::Debug for SkipTrivia<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SkipTrivia")
+    }
+}
@@ -0,0 +1,501 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Queries over parse trees, in the spirit of CSS selectors.
+//!
+//! A query is a list of selectors separated by `,`.
+//! A selector is a list of compounds separated by combinators:
+//!
+//! - `a b` matches `b` that is a descendant of `a`.
+//! - `a > b` matches `b` that is a child of `a`.
+//!
+//! A compound is a rule name from [`RuleType::name`] or `*` for any rule, followed by:
+//!
+//! - `:first-child` or `:last-child`.
+//! - `:has(b)` or `:has(> b)`, which requires a descendant or a child matching `b`.
+//! - Predicates on the matched text:
+//!   `[text = "a"]`, `[text != "a"]`, `[text ^= "a"]`, `[text $= "a"]` and `[text *= "a"]`.
+//! - A capture `@name`, which reports the node matching the compound.
+//!
+//! For example, `call:has(> member:first-child) > ident@name` matches every `ident`
+//! directly inside a `call` whose first child is a `member`, and captures it as `name`.
+//!
+//! ```
+//! # use pest_typed::query::Query;
+//! let query = Query::new("call:has(> member:first-child) > ident@name").unwrap();
+//! assert_eq!(query.capture_names(), ["name"]);
+//! assert!(Query::new("call >").is_err());
+//! ```
+
+use crate::{
+    flat_tree::FlatTree,
+    input::RefStr,
+    iterators::{Pair, Token},
+    RuleType, Span,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Prefix,
+    Suffix,
+    Contains,
+}
+
+#[derive(Clone, Debug)]
+struct Predicate {
+    operator: Operator,
+    value: String,
+}
+
+impl Predicate {
+    fn matches(&self, text: &str) -> bool {
+        let value = self.value.as_str();
+        match self.operator {
+            Operator::Equal => text == value,
+            Operator::NotEqual => text != value,
+            Operator::Prefix => text.starts_with(value),
+            Operator::Suffix => text.ends_with(value),
+            Operator::Contains => text.contains(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Compound {
+    /// [`None`] for `*`.
+    rule: Option<String>,
+    first_child: bool,
+    last_child: bool,
+    has: Vec<(Combinator, Selector)>,
+    predicates: Vec<Predicate>,
+    /// Index in [`Query::capture_names`].
+    capture: Option<usize>,
+}
+
+/// Compounds with the combinators before them. The combinator of the first one is ignored.
+#[derive(Clone, Debug)]
+struct Selector {
+    compounds: Vec<(Combinator, Compound)>,
+}
+
+/// A compiled query.
+///
+/// See the [module documentation](self) for the syntax.
+#[derive(Clone, Debug)]
+pub struct Query {
+    selectors: Vec<Selector>,
+    captures: Vec<String>,
+}
+
+/// Error in the source of a [`Query`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryError {
+    /// Byte offset in the source.
+    pub offset: usize,
+    /// Description of the error.
+    pub message: &'static str,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at offset {})", self.message, self.offset)
+    }
+}
+
+impl core::error::Error for QueryError {}
+
+/// A node captured by a [`Query`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capture<'q, S: RefStr, R: RuleType> {
+    /// Name of the capture.
+    pub name: &'q str,
+    /// The captured node.
+    pub token: Token<S, R>,
+}
+
+/// A match of a [`Query`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryMatch<'q, S: RefStr, R: RuleType> {
+    /// The node matching the last compound of a selector.
+    pub token: Token<S, R>,
+    /// Captured nodes in document order.
+    pub captures: Vec<Capture<'q, S, R>>,
+}
+
+impl<'q, S: RefStr, R: RuleType> QueryMatch<'q, S, R> {
+    /// The first node captured as `name`.
+    pub fn capture(&self, name: &str) -> Option<&Token<S, R>> {
+        self.captures
+            .iter()
+            .find(|capture| capture.name == name)
+            .map(|capture| &capture.token)
+    }
+}
+
+impl Query {
+    /// Compile a query.
+    pub fn new(source: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser {
+            source,
+            offset: 0,
+            captures: Vec::new(),
+        };
+        let selectors = parser.selectors()?;
+        parser.skip_whitespace();
+        if parser.offset < source.len() {
+            return Err(parser.error("Unexpected character."));
+        }
+        Ok(Self {
+            selectors,
+            captures: parser.captures,
+        })
+    }
+    /// Names of captures in the query, in order of appearance.
+    pub fn capture_names(&self) -> &[String] {
+        &self.captures
+    }
+    /// Find all matches in the tree rooted at `pair`, in document order.
+    ///
+    /// Each node is reported at most once, with the captures of the first selector it matches.
+    pub fn matches<'q, S: RefStr, R: RuleType>(
+        &'q self,
+        pair: &impl Pair<S, R>,
+    ) -> Vec<QueryMatch<'q, S, R>> {
+        self.matches_in(&FlatTree::from_pair(pair), pair.span().get_input())
+    }
+    /// Find all matches in a [`FlatTree`] whose positions are in `input`, in document order.
+    pub fn matches_in<'q, S: RefStr, R: RuleType>(
+        &'q self,
+        tree: &FlatTree<R>,
+        input: S,
+    ) -> Vec<QueryMatch<'q, S, R>> {
+        let matcher = Matcher { tree, input };
+        let mut res = Vec::new();
+        let mut captures = Vec::new();
+        for node in 0..tree.len() {
+            for selector in &self.selectors {
+                captures.clear();
+                let last = selector.compounds.len() - 1;
+                if matcher.select(selector, last, node, None, &mut captures) {
+                    let to_token = |index| {
+                        matcher
+                            .tree
+                            .to_token(matcher.input.clone(), index)
+                            .expect("Positions in the tree should be valid in the input.")
+                    };
+                    captures.sort_by_key(|&(_, node)| node);
+                    res.push(QueryMatch {
+                        token: to_token(node),
+                        captures: captures
+                            .iter()
+                            .map(|&(capture, node)| Capture {
+                                name: self.captures[capture].as_str(),
+                                token: to_token(node),
+                            })
+                            .collect(),
+                    });
+                    break;
+                }
+            }
+        }
+        res
+    }
+}
+
+struct Matcher<'t, S, R> {
+    tree: &'t FlatTree<R>,
+    input: S,
+}
+
+impl<S: RefStr, R: RuleType> Matcher<'_, S, R> {
+    fn text(&self, node: usize) -> S {
+        let node = &self.tree.nodes()[node];
+        Span::new(self.input.clone(), node.start, node.end)
+            .expect("Positions in the tree should be valid in the input.")
+            .as_str()
+    }
+    fn compound(
+        &self,
+        compound: &Compound,
+        node: usize,
+        captures: &mut Vec<(usize, usize)>,
+    ) -> bool {
+        let flat = &self.tree.nodes()[node];
+        if let Some(rule) = &compound.rule {
+            if flat.rule.name() != rule {
+                return false;
+            }
+        }
+        if compound.first_child && flat.parent.is_none_or(|parent| parent + 1 != node) {
+            return false;
+        }
+        if compound.last_child && (flat.parent.is_none() || self.tree.next_sibling(node).is_some())
+        {
+            return false;
+        }
+        if !compound.predicates.is_empty() {
+            let text = self.text(node);
+            let text = text.to_text();
+            if !compound.predicates.iter().all(|p| p.matches(&text)) {
+                return false;
+            }
+        }
+        let len = captures.len();
+        for (combinator, selector) in &compound.has {
+            let last = selector.compounds.len() - 1;
+            let found = self.tree.descendants(node).any(|descendant| {
+                self.select(
+                    selector,
+                    last,
+                    descendant,
+                    Some((*combinator, node)),
+                    captures,
+                )
+            });
+            if !found {
+                captures.truncate(len);
+                return false;
+            }
+        }
+        if let Some(capture) = compound.capture {
+            captures.push((capture, node));
+        }
+        true
+    }
+    /// Whether `node` matches the compound at `index` of `selector`,
+    /// and compounds before it match the ancestors.
+    ///
+    /// `anchor` is the node that the first compound is relative to in `:has`.
+    fn select(
+        &self,
+        selector: &Selector,
+        index: usize,
+        node: usize,
+        anchor: Option<(Combinator, usize)>,
+        captures: &mut Vec<(usize, usize)>,
+    ) -> bool {
+        let len = captures.len();
+        let (combinator, compound) = &selector.compounds[index];
+        if !self.compound(compound, node, captures) {
+            return false;
+        }
+        let parent = self.tree.parent(node);
+        let matched = match (index, anchor) {
+            (0, None) => true,
+            (0, Some((Combinator::Child, anchor))) => parent == Some(anchor),
+            (0, Some((Combinator::Descendant, anchor))) => {
+                self.ancestors(node).any(|ancestor| ancestor == anchor)
+            }
+            (_, _) => match combinator {
+                Combinator::Child => parent.is_some_and(|parent| {
+                    self.select(selector, index - 1, parent, anchor, captures)
+                }),
+                Combinator::Descendant => self
+                    .ancestors(node)
+                    .any(|ancestor| self.select(selector, index - 1, ancestor, anchor, captures)),
+            },
+        };
+        if !matched {
+            captures.truncate(len);
+        }
+        matched
+    }
+    fn ancestors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.tree.parent(node), |&node| self.tree.parent(node))
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    offset: usize,
+    captures: Vec<String>,
+}
+
+impl<'s> Parser<'s> {
+    const fn error(&self, message: &'static str) -> QueryError {
+        QueryError {
+            offset: self.offset,
+            message,
+        }
+    }
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+    fn eat(&mut self, prefix: &str) -> bool {
+        if self.source[self.offset..].starts_with(prefix) {
+            self.offset += prefix.len();
+            true
+        } else {
+            false
+        }
+    }
+    fn expect(&mut self, prefix: &str, message: &'static str) -> Result<(), QueryError> {
+        self.skip_whitespace();
+        match self.eat(prefix) {
+            true => Ok(()),
+            false => Err(self.error(message)),
+        }
+    }
+    /// Returns whether any whitespace is skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.offset;
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.offset += c.len_utf8();
+        }
+        self.offset > start
+    }
+    fn name(&mut self) -> Option<&'s str> {
+        let start = self.offset;
+        while let Some(c) = self.peek() {
+            let valid = match self.offset == start {
+                true => c == '_' || c.is_ascii_alphabetic(),
+                false => c == '_' || c == '-' || c.is_ascii_alphanumeric(),
+            };
+            if !valid {
+                break;
+            }
+            self.offset += 1;
+        }
+        let source = self.source;
+        let name = &source[start..self.offset];
+        (!name.is_empty()).then_some(name)
+    }
+    fn selectors(&mut self) -> Result<Vec<Selector>, QueryError> {
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.selector(Combinator::Descendant)?);
+            self.skip_whitespace();
+            if !self.eat(",") {
+                return Ok(selectors);
+            }
+        }
+    }
+    fn selector(&mut self, first: Combinator) -> Result<Selector, QueryError> {
+        let mut compounds = Vec::new();
+        let mut combinator = first;
+        loop {
+            compounds.push((combinator, self.compound()?));
+            let spaced = self.skip_whitespace();
+            combinator = if self.eat(">") {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if spaced
+                && matches!(self.peek(), Some(c) if c == '*' || c == '_' || c.is_ascii_alphabetic())
+            {
+                Combinator::Descendant
+            } else {
+                return Ok(Selector { compounds });
+            };
+        }
+    }
+    fn compound(&mut self) -> Result<Compound, QueryError> {
+        let mut compound = Compound::default();
+        if !self.eat("*") {
+            let name = self
+                .name()
+                .ok_or_else(|| self.error("Expected a rule name or `*`."))?;
+            compound.rule = Some(name.to_owned());
+        }
+        loop {
+            if self.eat(":") {
+                match self.name() {
+                    Some("first-child") => compound.first_child = true,
+                    Some("last-child") => compound.last_child = true,
+                    Some("has") => {
+                        self.expect("(", "Expected `(`.")?;
+                        self.skip_whitespace();
+                        let combinator = match self.eat(">") {
+                            true => Combinator::Child,
+                            false => Combinator::Descendant,
+                        };
+                        self.skip_whitespace();
+                        let selector = self.selector(combinator)?;
+                        self.expect(")", "Expected `)`.")?;
+                        compound.has.push((combinator, selector));
+                    }
+                    _ => return Err(self.error("Unknown pseudo-class.")),
+                }
+            } else if self.eat("[") {
+                self.skip_whitespace();
+                if self.name() != Some("text") {
+                    return Err(self.error("Expected `text`."));
+                }
+                self.skip_whitespace();
+                let operator = [
+                    ("=", Operator::Equal),
+                    ("!=", Operator::NotEqual),
+                    ("^=", Operator::Prefix),
+                    ("$=", Operator::Suffix),
+                    ("*=", Operator::Contains),
+                ]
+                .into_iter()
+                .find(|(op, _)| self.eat(op))
+                .map(|(_, operator)| operator)
+                .ok_or_else(|| self.error("Expected an operator."))?;
+                self.skip_whitespace();
+                let value = self.string()?;
+                self.expect("]", "Expected `]`.")?;
+                compound.predicates.push(Predicate { operator, value });
+            } else if self.eat("@") {
+                let name = self
+                    .name()
+                    .ok_or_else(|| self.error("Expected a capture name."))?;
+                let name = name.to_owned();
+                let index = match self.captures.iter().position(|n| *n == name) {
+                    Some(index) => index,
+                    None => {
+                        self.captures.push(name);
+                        self.captures.len() - 1
+                    }
+                };
+                compound.capture = Some(index);
+            } else {
+                return Ok(compound);
+            }
+        }
+    }
+    fn string(&mut self) -> Result<String, QueryError> {
+        if !self.eat("\"") {
+            return Err(self.error("Expected a string."));
+        }
+        let mut value = String::new();
+        loop {
+            let c = self
+                .peek()
+                .ok_or_else(|| self.error("Unterminated string."))?;
+            self.offset += c.len_utf8();
+            match c {
+                '"' => return Ok(value),
+                '\\' => {
+                    let c = self
+                        .peek()
+                        .ok_or_else(|| self.error("Unterminated string."))?;
+                    self.offset += c.len_utf8();
+                    value.push(match c {
+                        'n' => '\n',
+                        't' => '\t',
+                        c => c,
+                    });
+                }
+                c => value.push(c),
+            }
+        }
+    }
+}
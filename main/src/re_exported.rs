@@ -12,6 +12,8 @@
 /// `Box` in corresponding context.
 #[cfg(not(feature = "std"))]
 pub use ::alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+pub use ::alloc::string::String;
 /// `vec` in corresponding context.
 #[cfg(not(feature = "std"))]
 pub use ::alloc::vec;
@@ -32,6 +34,8 @@ pub use ::std::option::Option;
 #[cfg(feature = "std")]
 pub use ::std::result::Result;
 #[cfg(feature = "std")]
+pub use ::std::string::String;
+#[cfg(feature = "std")]
 pub use ::std::vec;
 #[cfg(feature = "std")]
 pub use ::std::vec::Vec;
@@ -0,0 +1,197 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rewrite text at spans of a parsed input.
+//!
+//! A [`Rewriter`] collects edits on spans of nodes without building a new tree,
+//! and applies them all at once to produce the new source.
+//!
+//! Only string inputs can be rewritten, as texts of other inputs,
+//! such as Latin-1 texts of byte inputs, are not their sources.
+
+use crate::{error::Error, Box, ParsableTypedNode, Position, RefStr, RuleType, Span};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Deref};
+use derive_where::derive_where;
+
+/// Replace the text at a span.
+///
+/// Insertions are edits on empty spans.
+#[derive_where(Clone, Debug, Hash, PartialEq, Eq; S: RefStr)]
+pub struct Edit<S> {
+    /// Span to replace.
+    pub span: Span<S>,
+    /// Text to replace it with.
+    pub text: String,
+}
+
+/// Error when adding an edit to a [`Rewriter`].
+#[derive_where(Clone, Debug, PartialEq, Eq; S: RefStr)]
+pub enum RewriteError<S> {
+    /// The span is not in the input of the rewriter.
+    ForeignSpan(Span<S>),
+    /// The span overlaps with that of an existing edit, which is the first span.
+    Overlap(Span<S>, Span<S>),
+}
+
+impl<S: RefStr> fmt::Display for RewriteError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForeignSpan(span) => write!(
+                f,
+                "Span {}..{} is not in the rewritten input.",
+                span.start(),
+                span.end()
+            ),
+            Self::Overlap(existing, span) => write!(
+                f,
+                "Span {}..{} overlaps with edited span {}..{}.",
+                span.start(),
+                span.end(),
+                existing.start(),
+                existing.end()
+            ),
+        }
+    }
+}
+
+impl<S: RefStr> core::error::Error for RewriteError<S> {}
+
+/// Whether `pos` is strictly inside `start..end`.
+const fn inside(pos: usize, start: usize, end: usize) -> bool {
+    start < pos && pos < end
+}
+
+/// Whether edits on two spans conflict.
+///
+/// Insertions conflict only with replacements strictly around them,
+/// so they can be put at both ends of replaced spans.
+const fn overlap((start, end): (usize, usize), (other_start, other_end): (usize, usize)) -> bool {
+    if start == end {
+        inside(start, other_start, other_end)
+    } else if other_start == other_end {
+        inside(other_start, start, end)
+    } else {
+        start < other_end && other_start < end
+    }
+}
+
+/// Collect edits on spans of one input and apply them in one pass.
+///
+/// ```
+/// use pest_typed::{rewriter::Rewriter, Span};
+///
+/// let input = "let a = 1;";
+/// let mut rewriter = Rewriter::new(input);
+/// rewriter.replace(&Span::new(input, 4, 5).unwrap(), "b").unwrap();
+/// rewriter.insert_after(&Span::new(input, 8, 9).unwrap(), "0").unwrap();
+/// assert!(rewriter.delete(&Span::new(input, 4, 6).unwrap()).is_err());
+/// assert_eq!(rewriter.apply(), "let b = 10;");
+/// ```
+#[derive_where(Clone, Debug; S: RefStr)]
+pub struct Rewriter<S> {
+    input: S,
+    edits: Vec<Edit<S>>,
+}
+
+impl<S: RefStr + Deref<Target = str>> Rewriter<S> {
+    /// Create a rewriter on `input`, which should be the input that spans of edits are in.
+    pub const fn new(input: S) -> Self {
+        Self {
+            input,
+            edits: Vec::new(),
+        }
+    }
+    /// Edits in the order they are added.
+    pub fn edits(&self) -> &[Edit<S>] {
+        &self.edits
+    }
+    /// Whether there is no edit.
+    pub const fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+    /// Replace the text at `span` with `text`.
+    ///
+    /// Fails if `span` is not in the input, or if it overlaps with the span of an existing edit.
+    /// Insertions at the same position are applied in the order they are added.
+    pub fn replace(
+        &mut self,
+        span: &Span<S>,
+        text: impl Into<String>,
+    ) -> Result<&mut Self, RewriteError<S>> {
+        if !span.get_input().ptr_eq(&self.input) {
+            return Err(RewriteError::ForeignSpan(span.clone()));
+        }
+        let range = (span.start(), span.end());
+        if let Some(edit) = self
+            .edits
+            .iter()
+            .find(|edit| overlap((edit.span.start(), edit.span.end()), range))
+        {
+            return Err(RewriteError::Overlap(edit.span.clone(), span.clone()));
+        }
+        self.edits.push(Edit {
+            span: span.clone(),
+            text: text.into(),
+        });
+        Ok(self)
+    }
+    /// Insert `text` before `span`.
+    pub fn insert_before(
+        &mut self,
+        span: &Span<S>,
+        text: impl Into<String>,
+    ) -> Result<&mut Self, RewriteError<S>> {
+        let start = span.start_pos();
+        self.replace(&start.span(&start), text)
+    }
+    /// Insert `text` after `span`.
+    pub fn insert_after(
+        &mut self,
+        span: &Span<S>,
+        text: impl Into<String>,
+    ) -> Result<&mut Self, RewriteError<S>> {
+        let end = span.end_pos();
+        self.replace(&end.span(&end), text)
+    }
+    /// Delete the text at `span`.
+    pub fn delete(&mut self, span: &Span<S>) -> Result<&mut Self, RewriteError<S>> {
+        self.replace(span, String::new())
+    }
+    /// Apply all edits to the input and return the new text.
+    pub fn apply(&self) -> String {
+        let mut edits: Vec<&Edit<S>> = self.edits.iter().collect();
+        // Stable, so insertions at the same position keep their order,
+        // and come before replacements starting there.
+        edits.sort_by_key(|edit| (edit.span.start(), edit.span.end()));
+        let mut output = String::new();
+        let mut pos = 0;
+        for edit in edits {
+            if let Some(unchanged) = self.input.get(pos..edit.span.start()) {
+                output.push_str(unchanged.as_str());
+            }
+            output.push_str(&edit.text);
+            pos = edit.span.end();
+        }
+        if let Some(rest) = self.input.get(pos..) {
+            output.push_str(rest.as_str());
+        }
+        output
+    }
+    /// Apply all edits into `output`, and parse it with `T` to validate the rewrite.
+    ///
+    /// The rewrite is valid if the result is [`Ok`].
+    pub fn apply_parse<'o, R: RuleType, T: ParsableTypedNode<Position<&'o str>, R>>(
+        &self,
+        output: &'o mut String,
+    ) -> Result<T, Box<Error<R>>> {
+        *output = self.apply();
+        T::try_parse(output.as_str())
+    }
+}
@@ -0,0 +1,417 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Text stored in chunks, such as ropes in editors.
+
+use crate::{input::RefStr, Input, Position};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Bound, RangeBounds},
+    ptr,
+};
+
+/// Text stored in chunks, such as a rope.
+///
+/// Implement this for your own rope to parse it with [`RopeStr`].
+pub trait ChunkedText {
+    /// Get the length in bytes.
+    fn len(&self) -> usize;
+    /// Check if is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Get the chunk containing the byte at `pos`, and the offset of the chunk.
+    ///
+    /// `pos` is less than [`len()`](ChunkedText::len).
+    /// Chunks must not be empty, and must be split at character boundaries.
+    fn chunk_at(&self, pos: usize) -> (&str, usize);
+}
+
+/// A small rope, which stores text in chunks.
+#[derive(Clone, Debug, Default)]
+pub struct Rope {
+    chunks: Vec<String>,
+    /// `ends[i]` is the end of `chunks[i]`.
+    ends: Vec<usize>,
+}
+
+impl Rope {
+    /// Create an empty rope.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            ends: Vec::new(),
+        }
+    }
+    /// Append a chunk.
+    pub fn push(&mut self, chunk: impl Into<String>) {
+        let chunk = chunk.into();
+        if chunk.is_empty() {
+            return;
+        }
+        self.ends.push(self.len() + chunk.len());
+        self.chunks.push(chunk);
+    }
+    /// Iterate over chunks.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.chunks.iter().map(String::as_str)
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Rope {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut rope = Self::new();
+        for chunk in iter {
+            rope.push(chunk);
+        }
+        rope
+    }
+}
+
+impl ChunkedText for Rope {
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+    #[inline]
+    fn chunk_at(&self, pos: usize) -> (&str, usize) {
+        let i = self.ends.partition_point(|&end| end <= pos);
+        let start = i.checked_sub(1).map_or(0, |i| self.ends[i]);
+        (&self.chunks[i], start)
+    }
+}
+
+impl<'i> Input for &'i Rope {
+    type Cursor = Position<RopeStr<'i, Rope>>;
+    type String = RopeStr<'i, Rope>;
+
+    #[inline]
+    fn as_cursor(&self) -> Self::Cursor {
+        Position::from_start(RopeStr::new(self))
+    }
+}
+
+enum Text<'i, T: ?Sized> {
+    Chunked(&'i T),
+    Static(&'static str),
+}
+
+impl<T: ?Sized> Clone for Text<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for Text<'_, T> {}
+
+/// A slice of a [`ChunkedText`].
+///
+/// [`to_text`](RefStr::to_text) borrows the text if it's in a single chunk,
+/// and copies it otherwise.
+pub struct RopeStr<'i, T: ?Sized> {
+    text: Text<'i, T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'i, T: ChunkedText + ?Sized> RopeStr<'i, T> {
+    /// Create from the whole text.
+    #[inline]
+    pub fn new(text: &'i T) -> Self {
+        Self {
+            text: Text::Chunked(text),
+            start: 0,
+            end: text.len(),
+        }
+    }
+    /// Get the byte range in the whole text.
+    #[inline]
+    pub const fn range(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    fn total_len(&self) -> usize {
+        match self.text {
+            Text::Chunked(text) => text.len(),
+            Text::Static(text) => text.len(),
+        }
+    }
+    fn chunk_at(&self, pos: usize) -> (&'i str, usize) {
+        match self.text {
+            Text::Chunked(text) => text.chunk_at(pos),
+            Text::Static(text) => (text, 0),
+        }
+    }
+    fn is_char_boundary(&self, pos: usize) -> bool {
+        if pos >= self.total_len() {
+            return pos == self.total_len();
+        }
+        let (chunk, offset) = self.chunk_at(pos);
+        chunk.is_char_boundary(pos - offset)
+    }
+    /// Iterate over pieces of chunks in this slice.
+    fn pieces(&self) -> impl Iterator<Item = &'i str> + '_ {
+        let mut pos = self.start;
+        core::iter::from_fn(move || {
+            if pos >= self.end {
+                return None;
+            }
+            let (chunk, offset) = self.chunk_at(pos);
+            let end = self.end.min(offset + chunk.len());
+            let piece = &chunk[pos - offset..end - offset];
+            pos = end;
+            Some(piece)
+        })
+    }
+    fn starts_with_by(&self, string: &str, eq: impl Fn(&[u8], &[u8]) -> bool) -> bool {
+        if string.len() > self.end - self.start {
+            return false;
+        }
+        let mut rest = string.as_bytes();
+        for piece in self.pieces() {
+            let len = piece.len().min(rest.len());
+            if !eq(&piece.as_bytes()[..len], &rest[..len]) {
+                return false;
+            }
+            rest = &rest[len..];
+            if rest.is_empty() {
+                break;
+            }
+        }
+        true
+    }
+}
+
+impl<T: ?Sized> Clone for RopeStr<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for RopeStr<'_, T> {}
+
+impl<T: ChunkedText + ?Sized> fmt::Debug for RopeStr<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_text(), f)
+    }
+}
+
+impl<T: ChunkedText + ?Sized> PartialEq for RopeStr<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.chars().eq(other.chars())
+    }
+}
+impl<T: ChunkedText + ?Sized> Eq for RopeStr<'_, T> {}
+
+impl<T: ChunkedText + ?Sized> Hash for RopeStr<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in self.pieces() {
+            state.write(piece.as_bytes());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+struct Chars<'s, 'i, T: ?Sized> {
+    string: &'s RopeStr<'i, T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: ChunkedText + ?Sized> Iterator for Chars<'_, '_, T> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (chunk, offset) = self.string.chunk_at(self.front);
+        let c = chunk[self.front - offset..].chars().next()?;
+        self.front += c.len_utf8();
+        Some(c)
+    }
+}
+
+impl<T: ChunkedText + ?Sized> DoubleEndedIterator for Chars<'_, '_, T> {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (chunk, offset) = self.string.chunk_at(self.back - 1);
+        let c = chunk[..self.back - offset].chars().next_back()?;
+        self.back -= c.len_utf8();
+        Some(c)
+    }
+}
+
+unsafe impl<T: ChunkedText + ?Sized> RefStr for RopeStr<'_, T> {
+    #[inline]
+    fn from_static(s: &'static str) -> Self {
+        Self {
+            text: Text::Static(s),
+            start: 0,
+            end: s.len(),
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn to_text(&self) -> Cow<'_, str> {
+        let mut pieces = self.pieces();
+        match (pieces.next(), pieces.next()) {
+            (None, _) => Cow::Borrowed(""),
+            (Some(piece), None) => Cow::Borrowed(piece),
+            (Some(first), Some(second)) => {
+                let mut string = String::with_capacity(self.len());
+                string.push_str(first);
+                string.push_str(second);
+                pieces.for_each(|piece| string.push_str(piece));
+                Cow::Owned(string)
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn get_range_unchecked(&self, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        Self {
+            start: self.start + start,
+            end: self.start + end,
+            ..*self
+        }
+    }
+
+    fn get(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        let valid = start <= end
+            && end <= self.len()
+            && self.is_char_boundary(self.start + start)
+            && self.is_char_boundary(self.start + end);
+        // Checked above.
+        valid.then(|| unsafe { self.get_range_unchecked(start..end) })
+    }
+
+    #[inline]
+    fn get_checked(&self, range: impl RangeBounds<usize>) -> Self {
+        self.get(range)
+            .unwrap_or_else(|| panic!("Range out of bounds"))
+    }
+
+    #[inline]
+    fn starts_with(&self, string: &str) -> bool {
+        self.starts_with_by(string, |piece, string| piece == string)
+    }
+
+    #[inline]
+    fn starts_with_insensitive(&self, string: &str) -> bool {
+        self.starts_with_by(string, <[u8]>::eq_ignore_ascii_case)
+    }
+
+    #[inline]
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        Chars {
+            string: self,
+            front: self.start,
+            back: self.end,
+        }
+    }
+
+    #[inline]
+    fn ptr_eq(&self, other: &Self) -> bool {
+        let same_text = match (self.text, other.text) {
+            (Text::Chunked(lhs), Text::Chunked(rhs)) => ptr::addr_eq(lhs, rhs),
+            (Text::Static(lhs), Text::Static(rhs)) => ptr::eq(lhs, rhs),
+            _ => false,
+        };
+        same_text && self.start == other.start && self.end == other.end
+    }
+
+    #[inline]
+    fn ptr_hash<H: Hasher>(&self, state: &mut H) {
+        match self.text {
+            Text::Chunked(text) => ptr::hash(ptr::from_ref(text).cast::<()>(), state),
+            Text::Static(text) => ptr::hash(text, state),
+        }
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cursor;
+
+    fn rope() -> Rope {
+        ["ab", "cé", "\u{e9}d", "\nef"].into_iter().collect()
+    }
+
+    #[test]
+    fn text() {
+        let rope = rope();
+        let input = RopeStr::new(&rope);
+
+        assert_eq!(input.len(), 11);
+        assert_eq!(input.to_text(), "abcé\u{e9}d\nef");
+        assert!(matches!(
+            input.get_checked(2..5).to_text(),
+            Cow::Borrowed("cé")
+        ));
+        assert!(matches!(input.get_checked(1..8).to_text(), Cow::Owned(_)));
+        assert_eq!(input.get_checked(1..8).to_text(), "bcé\u{e9}d");
+        assert!(input.get(..4).is_none());
+        assert!(input.get(..12).is_none());
+        assert_eq!(
+            input.get_checked(3..).chars().rev().collect::<String>(),
+            "fe\nd\u{e9}é"
+        );
+        assert_eq!(input.get_checked(1..3), RopeStr::from_static("bc"));
+    }
+
+    #[test]
+    fn cursor() {
+        let rope = rope();
+        let mut cursor = (&rope).as_cursor();
+
+        assert!(cursor.match_string("abc"));
+        assert!(!cursor.match_insensitive("É\u{e9}"));
+        assert!(cursor.match_string("é\u{e9}"));
+        assert!(cursor.skip_until(&["\n"]));
+        assert_eq!(cursor.byte_offset(), 8);
+        assert!(cursor.match_range('\n'..'\n'));
+        assert!(cursor.match_insensitive("EF"));
+        assert!(cursor.at_end());
+        assert_eq!(cursor.line_col(()), (2, 3));
+    }
+}
@@ -10,13 +10,16 @@
 //! Macros and functions for defining structs, most of which are [RuleStruct](crate::RuleStruct).
 
 use crate::{
-    predefined_node::EOI, tracker::Tracker, Cursor, NeverFailedTypedNode, RuleType, Span, Stack,
-    TypedNode,
+    predefined_node::EOI,
+    tracker::{EventSink, Tracker},
+    Cursor, NeverFailedTypedNode, RuleType, Span, Stack, TypedNode,
 };
 
 /// Implement [`Pairs`](crate::iterators::Pairs) for a struct that is a [`Pair`](crate::iterators::Pair).
 ///
 /// Normally used by non-silent rules.
+/// The struct must implement [`TypedRule`](crate::iterators::TypedRule),
+/// see [impl_typed_rule](crate::impl_typed_rule!).
 ///
 /// Arguments:
 ///
@@ -29,11 +32,50 @@ macro_rules! impl_pairs_with_self {
             $crate::iterators::Pairs<S, $Rule> for $name<S, INHERITED>
         {
             #[inline]
-            fn for_self_or_each_child(
-                &self,
-                f: &mut impl $crate::re_exported::FnMut($crate::iterators::Token<S, $Rule>),
-            ) {
-                f($crate::iterators::Pair::<S, $Rule>::as_token(self))
+            fn visit_self_or_children<'t>(
+                &'t self,
+                visitor: &mut impl $crate::iterators::PairVisitor<'t, S, $Rule>,
+            ) -> ::core::ops::ControlFlow<()> {
+                visitor.visit_rule(self)
+            }
+        }
+    };
+}
+
+/// Implement [`TypedRule`](crate::iterators::TypedRule) for a struct.
+///
+/// Normally used by [declare_rule_struct](crate::declare_rule_struct!).
+///
+/// Arguments:
+///
+/// - `unsafe`. See below.
+/// - `$name:ident`. Name of generated struct.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+/// - `$descendants:tt`. Optional. Rules that may be among descendants, such as `[Rule::a, Rule::b]`.
+///
+///   All rules may be if omitted.
+///
+/// # Safety
+///
+/// `$name` must be the name of a struct rather than a type alias,
+/// so that [`TypedRule::Key`](crate::iterators::TypedRule::Key) is different for different types.
+#[macro_export]
+macro_rules! impl_typed_rule {
+    (@may_contain $rule:ident) => {{
+        let _ = $rule;
+        true
+    }};
+    (@may_contain $rule:ident, [$($descendant:expr),* $(,)?]) => {
+        [$($descendant),*].contains(&$rule)
+    };
+    (unsafe $name:ident, $Rule:ty $(, $descendants:tt)?) => {
+        unsafe impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::iterators::TypedRule<S, $Rule> for $name<S, INHERITED>
+        {
+            type Key = $name<&'static ::core::primitive::str, INHERITED>;
+            #[inline]
+            fn may_contain(rule: $Rule) -> ::core::primitive::bool {
+                $crate::impl_typed_rule!(@may_contain rule $(, $descendants)?)
             }
         }
     };
@@ -55,11 +97,11 @@ macro_rules! impl_pairs_with_inner {
             $crate::iterators::Pairs<S, $Rule> for $name<S, INHERITED>
         {
             #[inline]
-            fn for_self_or_each_child(
-                &self,
-                f: &mut impl $crate::re_exported::FnMut($crate::iterators::Token<S, $Rule>),
-            ) {
-                self.content.for_self_or_each_child(f);
+            fn visit_self_or_children<'t>(
+                &'t self,
+                visitor: &mut impl $crate::iterators::PairVisitor<'t, S, $Rule>,
+            ) -> ::core::ops::ControlFlow<()> {
+                visitor.visit_silent(<Self as $crate::RuleWrapper<$Rule>>::RULE, &self.content)
             }
         }
     };
@@ -151,10 +193,11 @@ macro_rules! impl_pair_with_empty {
             $crate::iterators::Pair<S, $Rule> for $name<S, INHERITED>
         {
             #[inline]
-            fn for_each_child(
-                &self,
-                f: impl $crate::re_exported::FnMut($crate::iterators::Token<S, $Rule>),
-            ) {
+            fn visit_children<'t>(
+                &'t self,
+                _visitor: &mut impl $crate::iterators::PairVisitor<'t, S, $Rule>,
+            ) -> ::core::ops::ControlFlow<()> {
+                ::core::ops::ControlFlow::Continue(())
             }
         }
     };
@@ -187,11 +230,11 @@ macro_rules! impl_pair_with_content {
             $crate::iterators::Pair<S, $Rule> for $name<S, INHERITED>
         {
             #[inline]
-            fn for_each_child(
-                &self,
-                mut f: impl $crate::re_exported::FnMut($crate::iterators::Token<S, $Rule>),
-            ) {
-                $crate::iterators::Pairs::<S, $Rule>::for_self_or_each_child(&self.content, &mut f);
+            fn visit_children<'t>(
+                &'t self,
+                visitor: &mut impl $crate::iterators::PairVisitor<'t, S, $Rule>,
+            ) -> ::core::ops::ControlFlow<()> {
+                $crate::iterators::Pairs::<S, $Rule>::visit_self_or_children(&self.content, visitor)
             }
         }
     };
@@ -308,6 +351,21 @@ macro_rules! impl_parse {
                     <$Rule>::EOI,
                 )
             }
+            #[inline]
+            fn try_check_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::primitive::bool {
+                $crate::rule::check_without_ignore_reporting::<C, $Rule, Self>(
+                    input,
+                    stack,
+                    tracker,
+                    events,
+                    <$Rule>::EOI,
+                )
+            }
         }
     };
     ($name:ident, $Rule:ty, $ignored:ty, $non_true:tt) => {
@@ -330,6 +388,21 @@ macro_rules! impl_parse {
             ) -> ::core::primitive::bool {
                 $crate::rule::check::<C, $Rule, Self, $ignored>(input, stack, tracker, <$Rule>::EOI)
             }
+            #[inline]
+            fn try_check_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::primitive::bool {
+                $crate::rule::check_reporting::<C, $Rule, Self, $ignored>(
+                    input,
+                    stack,
+                    tracker,
+                    events,
+                    <$Rule>::EOI,
+                )
+            }
         }
     };
 }
@@ -377,6 +450,17 @@ macro_rules! impl_try_parse_with {
                 let input = <$inner>::try_check_partial_with(input, stack, tracker)?;
                 Some(input)
             }
+            #[inline]
+            fn try_check_partial_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::option::Option<C> {
+                let input =
+                    <$inner>::try_check_partial_reporting_with(input, stack, tracker, events)?;
+                Some(input)
+            }
         }
     };
     ($name:ident, $Rule:ty, $inner:ty, $atomicity:expr, Span) => {
@@ -405,9 +489,20 @@ macro_rules! impl_try_parse_with {
                 stack: &mut $crate::Stack<$crate::Span<C::String>>,
                 tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
             ) -> ::core::option::Option<C> {
-                tracker.record_during_with(
+                Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+            }
+            /// Rules inside atomic rules are not reported.
+            #[inline]
+            fn try_check_partial_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::option::Option<C> {
+                tracker.record_reporting_during_with(
                     input.clone(),
-                    |tracker| {
+                    events,
+                    |tracker, _| {
                         let input = <$inner>::try_check_partial_with(input, stack, tracker)?;
                         Some(input)
                     },
@@ -444,11 +539,25 @@ macro_rules! impl_try_parse_with {
                 stack: &mut $crate::Stack<$crate::Span<C::String>>,
                 tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
             ) -> ::core::option::Option<C> {
-                tracker.record_during_with(
+                Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+            }
+            #[inline]
+            fn try_check_partial_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::option::Option<C> {
+                tracker.record_reporting_during_with(
                     input.clone(),
-                    |tracker| {
-                        let input =
-                            <$inner>::try_check_partial_with(input.clone(), stack, tracker)?;
+                    events,
+                    |tracker, events| {
+                        let input = <$inner>::try_check_partial_reporting_with(
+                            input.clone(),
+                            stack,
+                            tracker,
+                            events,
+                        )?;
                         Some(input)
                     },
                     <Self as $crate::RuleWrapper<$Rule>>::RULE,
@@ -458,6 +567,102 @@ macro_rules! impl_try_parse_with {
     };
 }
 
+/// Implement [NeverFailedTypedNode](crate::NeverFailedTypedNode) and
+/// [NeverFailedParsableTypedNode](crate::NeverFailedParsableTypedNode) for structs
+/// whose inner expression never fails.
+///
+/// Whether the inner expression never fails is decided by the generator,
+/// so a single [Tracker](crate::tracker::Tracker) is shared in the whole rule,
+/// and choices with such a branch, such as `"c" | item?`, are supported.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+/// - `$ignored:ty`. Type of auto-skipped parsing  expressions, skipped after the matched part.
+///
+///   Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
+///
+/// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
+#[macro_export]
+macro_rules! impl_never_failed {
+    ($name:ident, $Rule:ty, $ignored:ty, true) => {
+        $crate::impl_never_failed!(@never_failed $name, $Rule);
+        impl<C: $crate::Cursor<String = S>, S: $crate::RefStr>
+            $crate::NeverFailedParsableTypedNode<C, $Rule> for $name<S, 1>
+        {
+            #[inline]
+            fn parse_with_until_end(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+            ) -> ::core::option::Option<Self> {
+                let (input, res) =
+                    <Self as $crate::NeverFailedTypedNode<C, $Rule>>::parse_with(input, stack);
+                input.at_end().then_some(res)
+            }
+        }
+    };
+    ($name:ident, $Rule:ty, $ignored:ty, $non_true:tt) => {
+        $crate::impl_never_failed!(@never_failed $name, $Rule);
+        impl<C: $crate::Cursor<String = S>, S: $crate::RefStr>
+            $crate::NeverFailedParsableTypedNode<C, $Rule> for $name<S, 1>
+        {
+            #[inline]
+            fn parse_with_until_end(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+            ) -> ::core::option::Option<Self> {
+                let (input, res) =
+                    <Self as $crate::NeverFailedTypedNode<C, $Rule>>::parse_with(input, stack);
+                let input = <$ignored as $crate::NeverFailedTypedNode<C, $Rule>>::check_with(
+                    input, stack,
+                );
+                input.at_end().then_some(res)
+            }
+        }
+    };
+    (@never_failed $name:ident, $Rule:ty) => {
+        impl<
+                C: $crate::Cursor<String = S>,
+                S: $crate::RefStr,
+                const INHERITED: ::core::primitive::usize,
+            > $crate::NeverFailedTypedNode<C, $Rule> for $name<C::String, INHERITED>
+        {
+            #[inline]
+            fn parse_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+            ) -> (C, Self) {
+                let mut tracker = $crate::tracker::Tracker::new(input.as_position());
+                match <Self as $crate::TypedNode<C, $Rule>>::try_parse_partial_with(
+                    input,
+                    stack,
+                    &mut tracker,
+                ) {
+                    ::core::option::Option::Some(res) => res,
+                    ::core::option::Option::None => {
+                        ::core::unreachable!("`{}` never fails.", ::core::stringify!($name))
+                    }
+                }
+            }
+            #[inline]
+            fn check_with(input: C, stack: &mut $crate::Stack<$crate::Span<C::String>>) -> C {
+                let mut tracker = $crate::tracker::Tracker::new(input.as_position());
+                match <Self as $crate::TypedNode<C, $Rule>>::try_check_partial_with(
+                    input,
+                    stack,
+                    &mut tracker,
+                ) {
+                    ::core::option::Option::Some(input) => input,
+                    ::core::option::Option::None => {
+                        ::core::unreachable!("`{}` never fails.", ::core::stringify!($name))
+                    }
+                }
+            }
+        }
+    };
+}
+
 /// Implement [Clone](core::clone::Clone), [Hash](core::hash::Hash), [PartialEq](core::cmp::PartialEq) and [Eq](core::cmp::Eq) for structs.
 ///
 /// Arguments:
@@ -611,9 +816,10 @@ macro_rules! rule_inner {
 /// - `$inner:ty`. Type of inner parsing expression.
 /// - `$emission:tt`. `Span`, `Expression` or `Both`.
 /// - `$boxed:tt`. `true` or `false`.
+/// - `$descendants:tt`. Optional. See [impl_typed_rule](crate::impl_typed_rule!).
 #[macro_export]
 macro_rules! declare_rule_struct {
-    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Expression, $boxed:tt) => {
+    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Expression, $boxed:tt $(, $descendants:tt)?) => {
         $(
             #[doc = $doc]
         )*
@@ -632,7 +838,7 @@ macro_rules! declare_rule_struct {
         }
         $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
     };
-    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Span, $boxed:tt) => {
+    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Span, $boxed:tt $(, $descendants:tt)?) => {
         $(
             #[doc = $doc]
         )*
@@ -648,8 +854,10 @@ macro_rules! declare_rule_struct {
                     .finish()
             }
         }
+        // SAFETY: `$name` is the struct declared above.
+        $crate::impl_typed_rule!(unsafe $name, $Rule $(, $descendants)?);
     };
-    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Both, $boxed:tt) => {
+    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Both, $boxed:tt $(, $descendants:tt)?) => {
         $(
             #[doc = $doc]
         )*
@@ -668,10 +876,223 @@ macro_rules! declare_rule_struct {
                     .finish()
             }
         }
+        // SAFETY: `$name` is the struct declared above.
+        $crate::impl_typed_rule!(unsafe $name, $Rule $(, $descendants)?);
         $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
     };
 }
 
+/// Implement [`SemanticEq`](crate::semantic::SemanticEq) and [`SemanticHash`](crate::semantic::SemanticHash) for a struct.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+#[macro_export]
+macro_rules! impl_semantic {
+    ($name:ident, Span) => {
+        $crate::impl_semantic!($name, span);
+    };
+    ($name:ident, Expression) => {
+        $crate::impl_semantic!($name, content);
+    };
+    ($name:ident, Both) => {
+        $crate::impl_semantic!($name, content);
+    };
+    ($name:ident, $field:ident) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::semantic::SemanticEq for $name<S, INHERITED>
+        {
+            #[inline]
+            fn semantic_eq(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::semantic::SemanticEq::semantic_eq(&self.$field, &other.$field)
+            }
+        }
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::semantic::SemanticHash for $name<S, INHERITED>
+        {
+            #[inline]
+            fn semantic_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $crate::semantic::SemanticHash::semantic_hash(&self.$field, state)
+            }
+        }
+    };
+}
+
+/// Implement [`Display`](core::fmt::Display) for a struct, which prints the matched text.
+///
+/// Also implement [`Spanned`](crate::Spanned) for silent rules by their inner expressions,
+/// as other rules have implemented it by [`impl_pair`](crate::impl_pair!).
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+/// - `$inner:ty`. Type of inner parsing expression.
+/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+#[macro_export]
+macro_rules! impl_display {
+    ($name:ident, $Rule:ty, $inner:ty, Expression) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> $crate::Spanned<S, $Rule>
+            for $name<S, INHERITED>
+        where
+            $inner: $crate::Spanned<S, $Rule>,
+        {
+            #[inline]
+            fn span(&self) -> $crate::Span<S> {
+                $crate::Spanned::<S, $Rule>::span(&self.content)
+            }
+        }
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> ::core::fmt::Display
+            for $name<S, INHERITED>
+        where
+            $inner: $crate::Spanned<S, $Rule>,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let span = $crate::Spanned::<S, $Rule>::span(&self.content);
+                f.write_str(&$crate::RefStr::to_text(&span.as_str()))
+            }
+        }
+    };
+    ($name:ident, $Rule:ty, $inner:ty, $emission:tt) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> ::core::fmt::Display
+            for $name<S, INHERITED>
+        {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(&$crate::RefStr::to_text(&self.span.as_str()))
+            }
+        }
+    };
+}
+
+/// Implement [`Build`](crate::builder::Build) and [`BuildRule`](crate::builder::BuildRule) for a struct.
+///
+/// Spans of rules with contents are derived from the contents, so they cover the built text.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$inner:ty`. Type of inner parsing expression.
+/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+#[macro_export]
+macro_rules! impl_build {
+    ($name:ident, $inner:ty, Span) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> $crate::builder::Build<S>
+            for $name<S, INHERITED>
+        {
+            #[inline]
+            fn write_text(
+                &self,
+                trivia: &::core::primitive::str,
+                text: &mut $crate::re_exported::String,
+            ) {
+                $crate::builder::Build::<S>::write_text(&self.span, trivia, text)
+            }
+            #[inline]
+            fn rebase(
+                &mut self,
+                trivia: &::core::primitive::str,
+                input: &S,
+                offset: ::core::primitive::usize,
+            ) -> ::core::primitive::usize {
+                $crate::builder::Build::<S>::rebase(&mut self.span, trivia, input, offset)
+            }
+        }
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::builder::BuildRule<S> for $name<S, INHERITED>
+        {
+            type Content = $crate::Span<S>;
+            #[inline]
+            fn from_content(span: Self::Content) -> Self {
+                Self { span }
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Expression) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> $crate::builder::Build<S>
+            for $name<S, INHERITED>
+        where
+            $inner: $crate::builder::Build<S>,
+        {
+            #[inline]
+            fn write_text(
+                &self,
+                trivia: &::core::primitive::str,
+                text: &mut $crate::re_exported::String,
+            ) {
+                $crate::builder::Build::<S>::write_text(&self.content, trivia, text)
+            }
+            #[inline]
+            fn rebase(
+                &mut self,
+                trivia: &::core::primitive::str,
+                input: &S,
+                offset: ::core::primitive::usize,
+            ) -> ::core::primitive::usize {
+                $crate::builder::Build::<S>::rebase(&mut self.content, trivia, input, offset)
+            }
+        }
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::builder::BuildRule<S> for $name<S, INHERITED>
+        {
+            type Content = $inner;
+            #[inline]
+            fn from_content(content: Self::Content) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    _phantom: ::core::marker::PhantomData,
+                }
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Both) => {
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize> $crate::builder::Build<S>
+            for $name<S, INHERITED>
+        where
+            $inner: $crate::builder::Build<S>,
+        {
+            #[inline]
+            fn write_text(
+                &self,
+                trivia: &::core::primitive::str,
+                text: &mut $crate::re_exported::String,
+            ) {
+                $crate::builder::Build::<S>::write_text(&self.content, trivia, text)
+            }
+            #[inline]
+            fn rebase(
+                &mut self,
+                trivia: &::core::primitive::str,
+                input: &S,
+                offset: ::core::primitive::usize,
+            ) -> ::core::primitive::usize {
+                let end =
+                    $crate::builder::Build::<S>::rebase(&mut self.content, trivia, input, offset);
+                if let ::core::option::Option::Some(span) =
+                    $crate::Span::new(input.clone(), offset, end)
+                {
+                    self.span = span;
+                }
+                end
+            }
+        }
+        impl<S: $crate::RefStr, const INHERITED: ::core::primitive::usize>
+            $crate::builder::BuildRule<S> for $name<S, INHERITED>
+        {
+            type Content = $inner;
+            #[inline]
+            fn from_content(content: Self::Content) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    span: $crate::Span::new_full(<S as $crate::RefStr>::from_static("")),
+                }
+            }
+        }
+    };
+}
+
 /// Start point of a rule.
 ///
 /// Arguments:
@@ -688,6 +1109,9 @@ macro_rules! declare_rule_struct {
 /// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
 /// - `$emission:tt`. `Span`, `Expression` or `Both`.
 /// - `$boxed:tt`. `true` or `false`.
+/// - `$descendants:tt`. Optional. Rules that may be among descendants, such as `[Rule::a, Rule::b]`.
+///
+///   Used to skip subtrees while searching for rules. All rules may be if omitted.
 ///
 /// See the below macros that reference this:
 /// - [atomic_rule](`crate::atomic_rule!`).
@@ -697,11 +1121,14 @@ macro_rules! declare_rule_struct {
 /// - [silent_rule](`crate::silent_rule!`).
 #[macro_export]
 macro_rules! rule {
-    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $atomicity:tt, $emission:tt, $boxed:tt) => {
-        $crate::declare_rule_struct!($vis $name, $($doc)*, $Rule, $inner, $emission, $boxed);
+    ($vis:vis $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $atomicity:tt, $emission:tt, $boxed:tt $(, $descendants:tt)?) => {
+        $crate::declare_rule_struct!($vis $name, $($doc)*, $Rule, $inner, $emission, $boxed $(, $descendants)?);
         $crate::impl_rule_wrapper!($name, $Rule, $rule);
         $crate::impl_try_parse_with!($name, $Rule, $inner, $atomicity, $emission);
         $crate::impl_common_traits!($name, $Rule, $inner, $atomicity, $emission);
+        $crate::impl_semantic!($name, $emission);
+        $crate::impl_display!($name, $Rule, $inner, $emission);
+        $crate::impl_build!($name, $inner, $emission);
         $crate::impl_parse!($name, $Rule, $ignored, $atomicity);
         $crate::impl_deref!($name, $inner, $emission);
         $crate::impl_pairs!($name, $Rule, $inner, $emission);
@@ -818,13 +1245,17 @@ macro_rules! rule_eoi {
             $vis $name,
             "The rule for end of input.",
             $Rule,
-            $crate::predefined_node::EOI,
+            $crate::predefined_node::EOI<S>,
             Both,
-            false
+            false,
+            []
         );
         $crate::impl_rule_wrapper!($name, $Rule, <$Rule>::EOI);
-        $crate::impl_try_parse_with!($name, $Rule, $crate::predefined_node::EOI, INHERITED, Both);
-        $crate::impl_common_traits!($name, $Rule, $crate::predefined_node::EOI, INHERITED, Both);
+        $crate::impl_try_parse_with!($name, $Rule, $crate::predefined_node::EOI<S>, INHERITED, Both);
+        $crate::impl_common_traits!($name, $Rule, $crate::predefined_node::EOI<S>, INHERITED, Both);
+        $crate::impl_semantic!($name, Both);
+        $crate::impl_display!($name, $Rule, $crate::predefined_node::EOI<S>, Both);
+        $crate::impl_build!($name, $crate::predefined_node::EOI<S>, Both);
         impl<C: $crate::Cursor, const INHERITED: usize> $crate::ParsableTypedNode<C, $Rule> for $name<C::String, INHERITED> {
             #[inline]
             fn try_parse_with(
@@ -852,8 +1283,23 @@ macro_rules! rule_eoi {
                     <$Rule>::EOI,
                 )
             }
+            #[inline]
+            fn try_check_reporting_with(
+                input: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, $Rule>,
+                events: &mut impl $crate::tracker::EventSink<C::String, $Rule>,
+            ) -> ::core::primitive::bool {
+                $crate::rule::check_without_ignore_reporting::<C, $Rule, Self>(
+                    input,
+                    stack,
+                    tracker,
+                    events,
+                    <$Rule>::EOI,
+                )
+            }
         }
-        $crate::impl_deref!($name, $crate::predefined_node::EOI, Expression);
+        $crate::impl_deref!($name, $crate::predefined_node::EOI<S>, Expression);
         $crate::impl_pairs_with_self!($name, $Rule);
         $crate::impl_pair_with_empty!($name, $Rule, <$Rule>::EOI);
     };
@@ -905,15 +1351,35 @@ pub fn check<
     tracker: &mut Tracker<C::String, R>,
     rule_eoi: R,
 ) -> bool {
-    let input = match _Self::try_check_partial_with(input, stack, tracker) {
+    check_reporting::<C, R, _Self, IGNORED>(input, stack, tracker, &mut (), rule_eoi)
+}
+
+/// Check as a non-atomic rule, and report [`Event`](crate::tracker::Event)s to `events`.
+///
+/// For [rule](crate::rule!) to implement [ParsableTypedNode](crate::ParsableTypedNode).
+#[inline]
+pub fn check_reporting<
+    C: Cursor,
+    R: RuleType,
+    _Self: TypedNode<C, R>,
+    IGNORED: NeverFailedTypedNode<C, R>,
+>(
+    input: C,
+    stack: &mut Stack<Span<C::String>>,
+    tracker: &mut Tracker<C::String, R>,
+    events: &mut impl EventSink<C::String, R>,
+    rule_eoi: R,
+) -> bool {
+    let input = match _Self::try_check_partial_reporting_with(input, stack, tracker, events) {
         Some(input) => input,
         None => return false,
     };
     let input = IGNORED::check_with(input, stack);
     tracker
-        .record_during_with(
+        .record_reporting_during_with(
             input.clone(),
-            |tracker| EOI::try_check_partial_with(input, stack, tracker),
+            events,
+            |tracker, _| EOI::try_check_partial_with(input, stack, tracker),
             rule_eoi,
         )
         .is_some()
@@ -954,14 +1420,29 @@ pub fn check_without_ignore<C: Cursor, R: RuleType, _Self: TypedNode<C, R>>(
     tracker: &mut Tracker<C::String, R>,
     rule_eoi: R,
 ) -> bool {
-    let input = match _Self::try_check_partial_with(input, stack, tracker) {
+    check_without_ignore_reporting::<C, R, _Self>(input, stack, tracker, &mut (), rule_eoi)
+}
+
+/// Check without auto-skipped parsing expressions, and report [`Event`](crate::tracker::Event)s to `events`.
+///
+/// For [rule](crate::rule!) to implement [ParsableTypedNode](crate::ParsableTypedNode).
+#[inline]
+pub fn check_without_ignore_reporting<C: Cursor, R: RuleType, _Self: TypedNode<C, R>>(
+    input: C,
+    stack: &mut Stack<Span<C::String>>,
+    tracker: &mut Tracker<C::String, R>,
+    events: &mut impl EventSink<C::String, R>,
+    rule_eoi: R,
+) -> bool {
+    let input = match _Self::try_check_partial_reporting_with(input, stack, tracker, events) {
         Some(input) => input,
         None => return false,
     };
     tracker
-        .record_during_with(
+        .record_reporting_during_with(
             input.clone(),
-            |tracker| EOI::try_check_partial_with(input, stack, tracker),
+            events,
+            |tracker, _| EOI::try_check_partial_with(input, stack, tracker),
             rule_eoi,
         )
         .is_some()
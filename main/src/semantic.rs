@@ -0,0 +1,261 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Equality and hashing that only consider matched content and rule structure.
+
+use crate::{
+    predefined_node::{
+        AlwaysFail, AtomicRepeat, CharRange, CharSet, Empty, Insens, Lazy, Negative, NewLineType,
+        Optional, PeekSlice1, PeekSlice2, Positive, Push, PushLiteral, RepeatMin, RepeatMinMax,
+        Skip, SkipChar, SkipTrivia, Skipped, Str, ANY, DROP, EOI, NEWLINE, PEEK, PEEK_ALL, POP,
+        POP_ALL, SOI,
+    },
+    CharSetWrapper, RefStr, Span, StringArrayWrapper, StringWrapper, TriviaWrapper,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::hash::{Hash, Hasher};
+
+/// Equality that only considers matched content and rule structure.
+///
+/// Unlike [`PartialEq`], skipped `WHITESPACE` and `COMMENT` are ignored,
+/// and [`Span`]s are compared by their texts rather than positions in the input,
+/// so trees parsed from different places can be equal.
+pub trait SemanticEq {
+    /// Whether `self` and `other` match the same content with the same structure.
+    fn semantic_eq(&self, other: &Self) -> bool;
+}
+
+/// Hashing consistent with [`SemanticEq`].
+pub trait SemanticHash: SemanticEq {
+    /// Feed matched content and rule structure into `state`.
+    fn semantic_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// Wrapper that implements [`PartialEq`], [`Eq`] and [`Hash`] by [`SemanticEq`] and [`SemanticHash`].
+///
+/// Useful to deduplicate trees with collections such as `HashSet`.
+#[derive(Clone, Copy, Debug)]
+pub struct Semantic<T>(pub T);
+
+impl<T: SemanticEq> PartialEq for Semantic<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.semantic_eq(&other.0)
+    }
+}
+impl<T: SemanticEq> Eq for Semantic<T> {}
+impl<T: SemanticHash> Hash for Semantic<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.semantic_hash(state)
+    }
+}
+
+impl<T: SemanticEq + ?Sized> SemanticEq for &T {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        (**self).semantic_eq(*other)
+    }
+}
+impl<T: SemanticHash + ?Sized> SemanticHash for &T {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        (**self).semantic_hash(state)
+    }
+}
+
+impl<S: RefStr> SemanticEq for Span<S> {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<S: RefStr> SemanticHash for Span<S> {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// Implement by [`PartialEq`] and [`Hash`], for nodes without spans.
+macro_rules! impl_by_eq {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl$(<$($tt)*>)? SemanticEq for $node {
+            #[inline]
+            fn semantic_eq(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+        impl$(<$($tt)*>)? SemanticHash for $node {
+            #[inline]
+            fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+                self.hash(state)
+            }
+        }
+    };
+}
+
+/// Implement for nodes that are always equal, including those only matching constants.
+macro_rules! impl_always_eq {
+    ($node:ty $(, $($tt:tt)*)?) => {
+        impl$(<$($tt)*>)? SemanticEq for $node {
+            #[inline(always)]
+            fn semantic_eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+        impl$(<$($tt)*>)? SemanticHash for $node {
+            #[inline(always)]
+            fn semantic_hash<H: Hasher>(&self, _state: &mut H) {}
+        }
+    };
+}
+
+/// Implement by a field.
+macro_rules! impl_by_field {
+    ($node:ty, $field:ident $(, $($tt:tt)*)?) => {
+        impl$(<$($tt)*>)? SemanticEq for $node {
+            #[inline]
+            fn semantic_eq(&self, other: &Self) -> bool {
+                self.$field.semantic_eq(&other.$field)
+            }
+        }
+        impl$(<$($tt)*>)? SemanticHash for $node {
+            #[inline]
+            fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+                self.$field.semantic_hash(state)
+            }
+        }
+    };
+}
+
+impl_by_eq!(char);
+impl_by_eq!(());
+
+impl_always_eq!(Str<S, T>, S, T: StringWrapper);
+impl_by_field!(Insens<S, T>, span, S: RefStr, T);
+impl_by_field!(Skip<S, Strings>, span, S: RefStr, Strings: StringArrayWrapper);
+impl_by_field!(SkipChar<S, N>, span, S: RefStr, const N: usize);
+impl_by_field!(CharRange<S, MIN, MAX>, content, S, const MIN: char, const MAX: char);
+impl_by_field!(CharSet<S, T>, content, S, T: CharSetWrapper);
+impl_by_field!(Positive<T>, content, T: SemanticHash);
+impl_by_field!(Optional<S, T>, content, S, T: SemanticHash);
+impl_always_eq!(Negative<S, T>, S, T);
+impl_by_field!(ANY<S>, content, S);
+impl_always_eq!(SOI<S>, S);
+impl_always_eq!(EOI<S>, S);
+impl_by_eq!(NewLineType);
+impl_by_field!(NEWLINE<S>, content, S);
+impl_by_field!(PEEK<S>, span, S: RefStr);
+impl_by_field!(PEEK_ALL<S>, span, S: RefStr);
+impl_always_eq!(DROP<S>, S);
+impl_by_field!(POP<S>, span, S: RefStr);
+impl_by_field!(POP_ALL<S>, span, S: RefStr);
+impl_always_eq!(AlwaysFail<S>, S);
+impl_always_eq!(Empty<S>, S);
+impl_by_field!(Push<T>, content, T: SemanticHash);
+impl_always_eq!(PushLiteral<S, T>, S, T: StringWrapper);
+impl_by_field!(PeekSlice2<S, START, END>, span, S: RefStr, const START: i32, const END: i32);
+impl_by_field!(PeekSlice1<S, START>, span, S: RefStr, const START: i32);
+impl_always_eq!(SkipTrivia<S, T>, S, T: TriviaWrapper);
+impl_by_field!(AtomicRepeat<S, T>, content, S, T: SemanticHash);
+impl_by_field!(RepeatMin<S, T, MIN>, content, S, T: SemanticHash, const MIN: usize);
+impl_by_field!(RepeatMinMax<S, T, MIN, MAX>, content, S, T: SemanticHash, const MIN: usize, const MAX: usize);
+impl_by_field!(Skipped<T, Skip, SKIP>, matched, T: SemanticHash, Skip, const SKIP: usize);
+// Only the span is compared, as the subtree is not stored.
+impl_by_field!(Lazy<S, T>, span, S: RefStr, T);
+
+impl<T: SemanticEq + ?Sized> SemanticEq for Box<T> {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        (**self).semantic_eq(other)
+    }
+}
+impl<T: SemanticHash + ?Sized> SemanticHash for Box<T> {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        (**self).semantic_hash(state)
+    }
+}
+
+impl<T: SemanticEq> SemanticEq for Option<T> {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.semantic_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+impl<T: SemanticHash> SemanticHash for Option<T> {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(inner) = self {
+            inner.semantic_hash(state);
+        }
+    }
+}
+
+impl<T: SemanticEq> SemanticEq for [T] {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.semantic_eq(b))
+    }
+}
+impl<T: SemanticHash> SemanticHash for [T] {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self {
+            item.semantic_hash(state);
+        }
+    }
+}
+
+impl<T: SemanticEq> SemanticEq for Vec<T> {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.as_slice().semantic_eq(other.as_slice())
+    }
+}
+impl<T: SemanticHash> SemanticHash for Vec<T> {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().semantic_hash(state)
+    }
+}
+
+impl<T: SemanticEq, const N: usize> SemanticEq for [T; N] {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.as_slice().semantic_eq(other.as_slice())
+    }
+}
+impl<T: SemanticHash, const N: usize> SemanticHash for [T; N] {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().semantic_hash(state)
+    }
+}
+
+impl<T1: SemanticEq, T2: SemanticEq> SemanticEq for (T1, T2) {
+    #[inline]
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.0.semantic_eq(&other.0) && self.1.semantic_eq(&other.1)
+    }
+}
+impl<T1: SemanticHash, T2: SemanticHash> SemanticHash for (T1, T2) {
+    #[inline]
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.semantic_hash(state);
+        self.1.semantic_hash(state);
+    }
+}
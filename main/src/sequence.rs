@@ -77,12 +77,21 @@ macro_rules! seq {
             }
             #[inline]
             fn try_check_partial_with(
+                cursor: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+                tracker: &mut $crate::tracker::Tracker<C::String, R>,
+            ) -> ::core::option::Option<C> {
+                Self::try_check_partial_reporting_with(cursor, stack, tracker, &mut ())
+            }
+            #[inline]
+            fn try_check_partial_reporting_with(
                 mut cursor: C,
                 stack: &mut $crate::Stack<$crate::Span<C::String>>,
                 tracker: &mut $crate::tracker::Tracker<C::String, R>,
+                events: &mut impl $crate::tracker::EventSink<C::String, R>,
             ) -> ::core::option::Option<C> {
                 {
-                    let next = T0::try_check_partial_with(cursor, stack, tracker)?;
+                    let next = T0::try_check_partial_reporting_with(cursor, stack, tracker, events)?;
                     cursor = next;
                 }
                 $(
@@ -91,7 +100,7 @@ macro_rules! seq {
                             let next = Skip::check_with(cursor, stack);
                             cursor = next;
                         }
-                        let next = $T::try_check_partial_with(cursor, stack, tracker)?;
+                        let next = $T::try_check_partial_reporting_with(cursor, stack, tracker, events)?;
                         cursor = next;
                     }
                 )*
@@ -99,6 +108,64 @@ macro_rules! seq {
                 Some(cursor)
             }
         }
+        impl<
+                C: $crate::Cursor,
+                R: $crate::RuleType,
+                $T0: $crate::NeverFailedTypedNode<C, R>,
+                $($T: $crate::NeverFailedTypedNode<C, R>, )*
+                Skip: $crate::NeverFailedTypedNode<C, R> + ::core::default::Default,
+                const SKIP: ::core::primitive::usize,
+            > $crate::NeverFailedTypedNode<C, R> for $name<
+                $crate::predefined_node::Skipped<$T0, Skip, SKIP>,
+                $(
+                    $crate::predefined_node::Skipped<$T, Skip, SKIP>,
+                )*
+            >
+        {
+            #[inline]
+            fn parse_with(
+                mut cursor: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+            ) -> (C, Self) {
+                let content =
+                (
+                    {
+                        let skipped = ::core::array::from_fn(|_| Skip::default());
+                        let (next, matched) = T0::parse_with(cursor, stack);
+                        cursor = next;
+                        $crate::predefined_node::Skipped { skipped, matched }
+                    },
+                    $(
+                        {
+                            let skipped = ::core::array::from_fn(|_| {
+                                let (next, skipped) = Skip::parse_with(cursor.clone(), stack);
+                                cursor = next;
+                                skipped
+                            });
+                            let (next, matched) = $T::parse_with(cursor, stack);
+                            cursor = next;
+                            $crate::predefined_node::Skipped { skipped, matched }
+                        },
+                    )*
+                );
+
+                (cursor, Self::from(content))
+            }
+            #[inline]
+            fn check_with(
+                mut cursor: C,
+                stack: &mut $crate::Stack<$crate::Span<C::String>>,
+            ) -> C {
+                cursor = T0::check_with(cursor, stack);
+                $(
+                    for _ in 0..SKIP {
+                        cursor = Skip::check_with(cursor, stack);
+                    }
+                    cursor = $T::check_with(cursor, stack);
+                )*
+                cursor
+            }
+        }
         impl<
                 I,
                 R: $crate::RuleType,
@@ -107,11 +174,12 @@ macro_rules! seq {
             > $crate::iterators::Pairs<I, R> for $name<$T0, $($T, )*>
         {
             #[inline]
-            fn for_self_or_each_child(&self, f: &mut impl $crate::re_exported::FnMut($crate::iterators::Token<I, R>)) {
-                self.content.0.for_self_or_each_child(f);
+            fn visit_self_or_children<'t>(&'t self, visitor: &mut impl $crate::iterators::PairVisitor<'t, I, R>) -> ::core::ops::ControlFlow<()> {
+                self.content.0.visit_self_or_children(visitor)?;
                 $(
-                    self.content.$t.for_self_or_each_child(f);
+                    self.content.$t.visit_self_or_children(visitor)?;
                 )*
+                ::core::ops::ControlFlow::Continue(())
             }
         }
         impl<$T0, $($T, )*> ::core::ops::Deref for $name<T0, $($T, )*> {
@@ -150,6 +218,114 @@ macro_rules! seq {
                 )*
             }
         }
+        impl<$T0: $crate::semantic::SemanticEq, $($T: $crate::semantic::SemanticEq, )*>
+            $crate::semantic::SemanticEq for $name<T0, $($T, )*>
+        {
+            #[inline]
+            fn semantic_eq(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::semantic::SemanticEq::semantic_eq(&self.content.$t0, &other.content.$t0)
+                $(
+                    && $crate::semantic::SemanticEq::semantic_eq(&self.content.$t, &other.content.$t)
+                )*
+            }
+        }
+        impl<$T0: $crate::semantic::SemanticHash, $($T: $crate::semantic::SemanticHash, )*>
+            $crate::semantic::SemanticHash for $name<T0, $($T, )*>
+        {
+            #[inline]
+            fn semantic_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $crate::semantic::SemanticHash::semantic_hash(&self.content.$t0, state);
+                $(
+                    $crate::semantic::SemanticHash::semantic_hash(&self.content.$t, state);
+                )*
+            }
+        }
+        impl<
+                S: $crate::RefStr,
+                R: $crate::RuleType,
+                $T0: $crate::Spanned<S, R>,
+                $($T: $crate::Spanned<S, R>, )*
+            > $crate::Spanned<S, R> for $name<T0, $($T, )*>
+        {
+            #[inline]
+            fn span(&self) -> $crate::Span<S> {
+                let span = $crate::Spanned::<S, R>::span(&self.content.$t0);
+                $(
+                    let span = span
+                        .start_pos()
+                        .span(&$crate::Spanned::<S, R>::span(&self.content.$t).end_pos());
+                )*
+                span
+            }
+        }
+        impl<$T0: ::core::fmt::Display, $($T: ::core::fmt::Display, )*>
+            ::core::fmt::Display for $name<T0, $($T, )*>
+        {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.content.$t0, f)?;
+                $(
+                    ::core::fmt::Display::fmt(&self.content.$t, f)?;
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+        impl<
+                S: $crate::RefStr,
+                $T0: $crate::builder::Build<S>,
+                $($T: $crate::builder::Build<S>, )*
+                IGNORED: ::core::default::Default,
+                const SKIP: ::core::primitive::usize,
+            > $crate::builder::Build<S> for $name<
+                $crate::predefined_node::Skipped<$T0, IGNORED, SKIP>,
+                $($crate::predefined_node::Skipped<$T, IGNORED, SKIP>, )*
+            >
+        {
+            fn write_text(&self, trivia: &::core::primitive::str, text: &mut $crate::re_exported::String) {
+                $crate::builder::Build::<S>::write_text(&self.content.$t0, trivia, text);
+                $(
+                    if SKIP > 0 {
+                        $crate::builder::Build::<S>::write_text_after(&self.content.$t, trivia, text);
+                    } else {
+                        $crate::builder::Build::<S>::write_text(&self.content.$t, trivia, text);
+                    }
+                )*
+            }
+            fn rebase(
+                &mut self,
+                trivia: &::core::primitive::str,
+                input: &S,
+                offset: ::core::primitive::usize,
+            ) -> ::core::primitive::usize {
+                let offset = $crate::builder::Build::<S>::rebase(&mut self.content.$t0, trivia, input, offset);
+                $(
+                    let offset = if SKIP > 0 {
+                        $crate::builder::Build::<S>::rebase_after(&mut self.content.$t, trivia, input, offset)
+                    } else {
+                        $crate::builder::Build::<S>::rebase(&mut self.content.$t, trivia, input, offset)
+                    };
+                )*
+                offset
+            }
+        }
+        /// Wrap matched elements without skipped trivia.
+        impl<$T0, $($T, )* IGNORED: ::core::default::Default, const SKIP: ::core::primitive::usize>
+            ::core::convert::From<( $T0, $($T, )* )>
+            for $name<
+                $crate::predefined_node::Skipped<$T0, IGNORED, SKIP>,
+                $($crate::predefined_node::Skipped<$T, IGNORED, SKIP>, )*
+            >
+        {
+            #[inline]
+            fn from(matched: ( $T0, $($T, )* )) -> Self {
+                Self {
+                    content: (
+                        ::core::convert::From::from(matched.$t0),
+                        $(::core::convert::From::from(matched.$t), )*
+                    ),
+                }
+            }
+        }
         impl<$T0, $($T),*, const SKIP: usize, IGNORED> $name<$crate::predefined_node::Skipped<$T0, IGNORED, SKIP>, $($crate::predefined_node::Skipped<$T, IGNORED, SKIP>, )*> {
             /// Convert the reference of a sequence into a tuple of references of matched elements.
             pub const fn as_ref(&self) -> ( &$T0, $(&$T, )* ) {
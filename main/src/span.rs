@@ -345,7 +345,7 @@ impl<S: RefStr> Span<S> {
             let mut chars = slice.chars();
             for _ in 0..n {
                 if let Some(c) = chars.next() {
-                    len += c.len_utf8();
+                    len += S::char_len(c);
                 } else {
                     return false;
                 }
@@ -421,11 +421,9 @@ impl<S: RefStr> Span<S> {
             let Some(string) = self.input.get(from..) else {
                 continue;
             };
-            let bytes = string.as_str().as_bytes();
 
             for slice in strings.iter() {
-                let to = slice.len();
-                if Some(slice.as_bytes()) == bytes.get(0..to) {
+                if string.starts_with(slice) {
                     self.start = from;
                     return true;
                 }
@@ -443,7 +441,8 @@ impl<S: RefStr> Span<S> {
     #[allow(dead_code)]
     pub(crate) fn match_char(&self, c: char) -> bool {
         let slice = self.input.get_checked(self.start..self.end);
-        matches!(slice.chars().next(), Some(cc) if c == cc)
+        let next = slice.chars().next();
+        matches!(next, Some(cc) if c == cc)
     }
 
     /// Matches the char at the `Position` against a filter function and returns `true` if a match
@@ -455,9 +454,10 @@ impl<S: RefStr> Span<S> {
         F: FnOnce(char) -> bool,
     {
         let slice = self.input.get_checked(self.start..self.end);
-        if let Some(c) = slice.chars().next() {
+        let next = slice.chars().next();
+        if let Some(c) = next {
             if f(c) {
-                self.start += c.len_utf8();
+                self.start += S::char_len(c);
                 true
             } else {
                 false
@@ -472,11 +472,15 @@ impl<S: RefStr> Span<S> {
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn match_string(&mut self, string: &str) -> bool {
-        let to = self.start + string.len();
+        let to = self.start + S::str_len(string);
 
         if self.end < to {
             false
-        } else if Some(string) == self.input.get(self.start..to).as_ref().map(S::as_str) {
+        } else if self
+            .input
+            .get(self.start..to)
+            .is_some_and(|slice| slice.starts_with(string))
+        {
             self.start = to;
             true
         } else {
@@ -495,7 +499,7 @@ impl<S: RefStr> Span<S> {
         };
 
         if matched {
-            self.start += string.len();
+            self.start += S::str_len(string);
             true
         } else {
             false
@@ -509,7 +513,7 @@ impl<S: RefStr> Span<S> {
     pub(crate) fn match_range(&mut self, range: Range<char>) -> bool {
         if let Some(c) = self.input.get_checked(self.start..self.end).chars().next() {
             if range.start <= c && c <= range.end {
-                self.start += c.len_utf8();
+                self.start += S::char_len(c);
                 return true;
             }
         }
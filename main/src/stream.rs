@@ -0,0 +1,546 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Input read from a [`Read`] on demand.
+
+use crate::input::RefStr;
+use alloc::{borrow::Cow, format, rc::Rc, string::String, vec, vec::Vec};
+use core::{
+    cell::RefCell,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Bound, RangeBounds},
+    ptr,
+};
+use std::io::{self, Read};
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+struct Buffer<R> {
+    /// `None` once the reader is exhausted or has failed.
+    reader: Option<R>,
+    /// Retained text, starting at `base`.
+    text: String,
+    base: usize,
+    /// Number of line breaks in released text.
+    released_lines: usize,
+    /// Bytes of an incomplete character at the end of the last chunk.
+    pending: Vec<u8>,
+    chunk_size: usize,
+    error: Option<io::Error>,
+}
+
+impl<R: Read> Buffer<R> {
+    const fn buffered_end(&self) -> usize {
+        self.base + self.text.len()
+    }
+    /// Read until `end` is buffered, and return `false` if the reader is exhausted before that.
+    fn fill_to(&mut self, end: usize) -> bool {
+        while self.buffered_end() < end {
+            if !self.read_chunk() {
+                // The last chunk may still be partly valid.
+                return self.buffered_end() >= end;
+            }
+        }
+        true
+    }
+    fn fill_all(&mut self) -> usize {
+        while self.read_chunk() {}
+        self.buffered_end()
+    }
+    fn fail(&mut self, error: io::Error) -> bool {
+        self.reader = None;
+        self.error = Some(error);
+        false
+    }
+    fn read_chunk(&mut self) -> bool {
+        let Some(reader) = self.reader.as_mut() else {
+            return false;
+        };
+        let mut chunk = vec![0; self.chunk_size];
+        let read = loop {
+            match reader.read(&mut chunk) {
+                Ok(read) => break read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return self.fail(error),
+            }
+        };
+        if read == 0 {
+            self.reader = None;
+            if !self.pending.is_empty() {
+                return self.fail(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "stream ended in the middle of a character",
+                ));
+            }
+            return false;
+        }
+        self.pending.extend_from_slice(&chunk[..read]);
+        match core::str::from_utf8(&self.pending) {
+            Ok(text) => {
+                self.text.push_str(text);
+                self.pending.clear();
+            }
+            Err(error) => {
+                let valid = error.valid_up_to();
+                // Checked by `from_utf8`.
+                self.text
+                    .push_str(unsafe { core::str::from_utf8_unchecked(&self.pending[..valid]) });
+                self.pending.drain(..valid);
+                if error.error_len().is_some() {
+                    return self.fail(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream did not contain valid UTF-8",
+                    ));
+                }
+            }
+        }
+        true
+    }
+    /// Check if `pos` has not been released, and record an error otherwise.
+    fn check(&mut self, pos: usize) -> bool {
+        if pos < self.base {
+            self.error.get_or_insert_with(|| {
+                io::Error::other(format!(
+                    "position {} of the stream has been released, and text before position {} is no longer available",
+                    pos, self.base,
+                ))
+            });
+            return false;
+        }
+        true
+    }
+    fn is_char_boundary(&mut self, pos: usize) -> bool {
+        self.check(pos) && self.text.is_char_boundary(pos - self.base)
+    }
+    fn slice(&mut self, start: usize, end: usize) -> Option<&str> {
+        self.check(start)
+            .then(|| &self.text[start - self.base..end - self.base])
+    }
+    fn bytes(&mut self, start: usize, end: usize) -> Option<&[u8]> {
+        self.check(start)
+            .then(|| &self.text.as_bytes()[start - self.base..end - self.base])
+    }
+}
+
+/// Input read from a [`Read`] on demand, for documents too large to be kept in memory.
+///
+/// Text is read in chunks when the parser reaches it and kept until [released](ReadStr::release),
+/// so that the parser can backtrack within it.
+/// Matching released text, such as backtracking past a released position, fails,
+/// and an error is recorded.
+///
+/// Offsets of [`Position`](crate::Position) and [`Span`](crate::Span) are counted from the start of the stream,
+/// and [`to_text`](RefStr::to_text) copies text into an owned string.
+/// Measuring the length of the unread part of the input, such as with [`len`](RefStr::len),
+/// [`to_text`](RefStr::to_text) or [`Cursor::end`](crate::Cursor::end), reads the rest of the stream.
+///
+/// Nothing is released automatically,
+/// so the retained text only stays bounded if [`release`](ReadStr::release) is called between partial parses,
+/// such as with [`try_parse_partial`](crate::ParsableTypedNode::try_parse_partial).
+/// Two inputs are equal if they are the same part of the same stream.
+///
+/// Parsing errors are reported in the retained text,
+/// and their line numbers include the [`released_lines`](ReadStr::released_lines).
+///
+/// Errors of the reader, including invalid UTF-8, end the input early.
+/// They can be retrieved with [`take_error`](ReadStr::take_error).
+pub struct ReadStr<R> {
+    buffer: Rc<RefCell<Buffer<R>>>,
+    start: usize,
+    end: Option<usize>,
+}
+
+impl<R: Read> ReadStr<R> {
+    /// Create from a reader.
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE)
+    }
+    /// Create from a reader, reading `chunk_size` bytes each time.
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        Self::from_buffer(Buffer {
+            reader: Some(reader),
+            text: String::new(),
+            base: 0,
+            released_lines: 0,
+            pending: Vec::new(),
+            chunk_size: chunk_size.max(1),
+            error: None,
+        })
+    }
+    fn from_buffer(buffer: Buffer<R>) -> Self {
+        Self {
+            buffer: Rc::new(RefCell::new(buffer)),
+            start: 0,
+            end: None,
+        }
+    }
+    /// Release text before the line containing `pos`, which will never be backtracked to.
+    ///
+    /// The line itself is kept, so that errors can still be reported with it.
+    pub fn release(&self, pos: usize) {
+        let mut buffer = self.buffer.borrow_mut();
+        let pos = (self.start + pos).min(buffer.buffered_end());
+        if pos <= buffer.base {
+            return;
+        }
+        let retained = &buffer.text[..pos - buffer.base];
+        let line_start = retained.rfind('\n').map_or(0, |i| i + 1);
+        let lines = retained[..line_start].matches('\n').count();
+        buffer.text.drain(..line_start);
+        buffer.base += line_start;
+        buffer.released_lines += lines;
+    }
+    /// Get the position before which text has been released.
+    pub fn released(&self) -> usize {
+        self.buffer.borrow().base
+    }
+    /// Get the number of lines that have been released.
+    pub fn released_lines(&self) -> usize {
+        self.buffer.borrow().released_lines
+    }
+    /// Take the error that ended the input early or the first attempt to read released text, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.buffer.borrow_mut().error.take()
+    }
+
+    /// Resolve `range` into positions in the stream, saturating on overflow.
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, Option<usize>) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(self.start.saturating_add(end.saturating_add(1))),
+            Bound::Excluded(&end) => Some(self.start.saturating_add(end)),
+            Bound::Unbounded => self.end,
+        };
+        (self.start.saturating_add(start), end)
+    }
+    fn end_or_fill(&self) -> usize {
+        self.end
+            .unwrap_or_else(|| self.buffer.borrow_mut().fill_all())
+    }
+}
+
+impl<R> Clone for ReadStr<R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<R> fmt::Debug for ReadStr<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadStr")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<R: Read> PartialEq for ReadStr<R> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+impl<R: Read> Eq for ReadStr<R> {}
+
+impl<R: Read> Hash for ReadStr<R> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr_hash(state);
+    }
+}
+
+struct Chars<'s, R> {
+    string: &'s ReadStr<R>,
+    front: usize,
+    back: Option<usize>,
+}
+
+impl<R: Read> Iterator for Chars<'_, R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self
+            .back
+            .or(self.string.end)
+            .is_some_and(|end| self.front >= end)
+        {
+            return None;
+        }
+        let mut buffer = self.string.buffer.borrow_mut();
+        // A character is at most 4 bytes long.
+        buffer.fill_to(self.front + 4);
+        let end = buffer.buffered_end();
+        let c = buffer.slice(self.front, end)?.chars().next()?;
+        self.front += c.len_utf8();
+        Some(c)
+    }
+}
+
+impl<R: Read> DoubleEndedIterator for Chars<'_, R> {
+    fn next_back(&mut self) -> Option<char> {
+        let back = match self.back {
+            Some(back) => back,
+            None => self.string.end_or_fill(),
+        };
+        if back <= self.front {
+            return None;
+        }
+        let c = {
+            let mut buffer = self.string.buffer.borrow_mut();
+            buffer.slice(self.front, back)?.chars().next_back()?
+        };
+        self.back = Some(back - c.len_utf8());
+        Some(c)
+    }
+}
+
+unsafe impl<R: Read> RefStr for ReadStr<R> {
+    fn from_static(s: &'static str) -> Self {
+        Self {
+            end: Some(s.len()),
+            ..Self::from_buffer(Buffer {
+                reader: None,
+                text: s.into(),
+                base: 0,
+                released_lines: 0,
+                pending: Vec::new(),
+                chunk_size: DEFAULT_CHUNK_SIZE,
+                error: None,
+            })
+        }
+    }
+
+    /// Reads the rest of the stream if the end of the input is unknown.
+    #[inline]
+    fn len(&self) -> usize {
+        self.end_or_fill() - self.start
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        match self.end {
+            Some(end) => self.start == end,
+            None => !self.buffer.borrow_mut().fill_to(self.start + 1),
+        }
+    }
+
+    /// Reads the rest of the stream if the end of the input is unknown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text has been released.
+    #[inline]
+    fn to_text(&self) -> Cow<'_, str> {
+        let end = self.end_or_fill();
+        let mut buffer = self.buffer.borrow_mut();
+        match buffer.slice(self.start, end) {
+            Some(text) => Cow::Owned(text.into()),
+            None => panic!(
+                "Text from position {} of the stream has been released.",
+                self.start
+            ),
+        }
+    }
+
+    /// The text is the retained text until the end of the line containing `pos`,
+    /// or `None` if `pos` has been released.
+    fn text_at(&self, pos: usize) -> Option<(Cow<'_, str>, usize)> {
+        let pos = self.start + pos;
+        let mut buffer = self.buffer.borrow_mut();
+        if pos < buffer.base || !buffer.fill_to(pos) {
+            return None;
+        }
+        let offset = pos - buffer.base;
+        let line_end = loop {
+            match buffer.text[offset..].find('\n') {
+                Some(i) => break offset + i,
+                None if buffer.read_chunk() => continue,
+                None => break buffer.text.len(),
+            }
+        };
+        Some((Cow::Owned(buffer.text[..line_end].into()), offset))
+    }
+
+    #[inline]
+    fn lines_before_text(&self) -> Option<usize> {
+        Some(self.buffer.borrow().released_lines)
+    }
+
+    #[inline]
+    unsafe fn get_range_unchecked(&self, range: impl RangeBounds<usize>) -> Self {
+        let (start, end) = self.bounds(range);
+        Self {
+            buffer: self.buffer.clone(),
+            start,
+            end,
+        }
+    }
+
+    fn get(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let (start, end) = self.bounds(range);
+        let limit = end.unwrap_or(start);
+        if start > limit || self.end.is_some_and(|slice_end| limit > slice_end) {
+            return None;
+        }
+        let mut buffer = self.buffer.borrow_mut();
+        if !buffer.fill_to(limit) {
+            return None;
+        }
+        if !buffer.is_char_boundary(start) || !buffer.is_char_boundary(limit) {
+            return None;
+        }
+        Some(Self {
+            buffer: self.buffer.clone(),
+            start,
+            end,
+        })
+    }
+
+    #[inline]
+    fn get_checked(&self, range: impl RangeBounds<usize>) -> Self {
+        self.get(range)
+            .unwrap_or_else(|| panic!("Range out of bounds"))
+    }
+
+    fn starts_with(&self, string: &str) -> bool {
+        let end = self.start + string.len();
+        if self.end.is_some_and(|slice_end| end > slice_end) {
+            return false;
+        }
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.fill_to(end) && buffer.bytes(self.start, end) == Some(string.as_bytes())
+    }
+
+    fn starts_with_insensitive(&self, string: &str) -> bool {
+        let end = self.start + string.len();
+        if self.end.is_some_and(|slice_end| end > slice_end) {
+            return false;
+        }
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.fill_to(end)
+            && buffer
+                .bytes(self.start, end)
+                .is_some_and(|bytes| bytes.eq_ignore_ascii_case(string.as_bytes()))
+    }
+
+    #[inline]
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        Chars {
+            string: self,
+            front: self.start,
+            back: self.end,
+        }
+    }
+
+    #[inline]
+    fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.buffer, &other.buffer)
+            && self.start == other.start
+            && self.end == other.end
+    }
+
+    #[inline]
+    fn ptr_hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(Rc::as_ptr(&self.buffer), state);
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cursor, Input, Position};
+
+    /// Reads at most 3 bytes each time.
+    struct Slow<'a>(&'a [u8]);
+
+    impl Read for Slow<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(3).min(self.0.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn cursor() {
+        let input = ReadStr::with_chunk_size(Slow("ab\nçdé\nf".as_bytes()), 2);
+        let mut cursor = input.as_cursor();
+
+        assert!(cursor.match_string("ab\n"));
+        assert!(cursor.match_range('a'..'ç'));
+        assert!(cursor.skip_until(&["é"]));
+        assert_eq!(cursor.byte_offset(), 6);
+        assert!(!cursor.at_end());
+        assert_eq!(cursor.as_position().span(&cursor).as_str().to_text(), "");
+
+        let start = cursor.clone();
+        assert!(cursor.skip(3));
+        assert_eq!(start.span(&cursor).as_str().to_text(), "é\nf");
+        assert!(cursor.at_end());
+        assert!(!cursor.skip(1));
+        assert!(input.take_error().is_none());
+    }
+
+    #[test]
+    fn release() {
+        let input = ReadStr::with_chunk_size(Slow(b"a\nbb\nccc\n"), 4);
+        let mut cursor = input.as_cursor();
+
+        assert!(cursor.match_string("a\nbb\nc"));
+        input.release(cursor.byte_offset());
+        assert_eq!(input.released(), 5);
+        assert_eq!(input.released_lines(), 2);
+        assert_eq!(
+            input.text_at(cursor.byte_offset()),
+            Some((Cow::Owned("ccc".into()), 1))
+        );
+        assert_eq!(input.text_at(1), None);
+
+        let mut position = Position::new(input.clone(), 5).unwrap();
+        assert!(position.match_string("ccc"));
+    }
+
+    #[test]
+    fn backtrack_past_release() {
+        let input = ReadStr::new(Slow(b"a\nb"));
+        let mut cursor = input.as_cursor();
+        let mut start = cursor.clone();
+
+        assert!(cursor.match_string("a\nb"));
+        input.release(cursor.byte_offset());
+        assert!(!start.match_string("a"));
+        assert_eq!(start.advance_char(), None);
+        let error = input.take_error().unwrap();
+        assert!(format!("{error}").contains("has been released"));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let input = ReadStr::new(Slow(b"ab\xff"));
+        let mut cursor = input.as_cursor();
+
+        assert!(cursor.match_string("ab"));
+        assert!(cursor.at_end());
+        let error = input.take_error().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,417 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Pre-lexed token sequences as input.
+
+use crate::input::RefStr;
+use alloc::borrow::Cow;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Bound, Range, RangeBounds},
+    ptr,
+};
+
+/// Kind of a [`LexToken`], usually an enumeration.
+pub trait TokenKind: Copy + Eq + Hash + fmt::Debug {
+    /// Check if a string literal in the grammar denotes this kind.
+    fn matches(self, literal: &str) -> bool;
+    /// Check if a case-insensitive string literal in the grammar denotes this kind.
+    #[inline]
+    fn matches_insensitive(self, literal: &str) -> bool {
+        self.matches(literal)
+    }
+}
+
+/// A token produced by an external lexer.
+pub trait LexToken: fmt::Debug {
+    /// Kind of the token.
+    type Kind: TokenKind;
+    /// Get the kind of the token.
+    fn kind(&self) -> Self::Kind;
+    /// Get the byte range of the token in the source.
+    fn span(&self) -> Range<usize>;
+}
+
+impl<K: TokenKind> LexToken for (K, Range<usize>) {
+    type Kind = K;
+
+    #[inline(always)]
+    fn kind(&self) -> K {
+        self.0
+    }
+    #[inline(always)]
+    fn span(&self) -> Range<usize> {
+        self.1.clone()
+    }
+}
+
+/// A slice of tokens together with the source they were lexed from.
+///
+/// Tokens are produced by an external lexer, and the grammar only describes the structure on top of them:
+///
+/// - A string literal matches a single token whose kind [matches](TokenKind::matches) it.
+///   An empty string literal matches no token, as usual.
+/// - `ANY` matches any single token.
+/// - A token kind declared with `#[token_kind = "IDENT"]` on the parser can be referenced as rule `IDENT`,
+///   which matches a single token whose kind matches `"IDENT"`.
+///
+/// Offsets in [`Position`](crate::Position) and [`Span`](crate::Span) are token indices,
+/// and [`source_range`](Tokens::source_range) maps them back to the source through each token's own span.
+///
+/// The text of a slice, as returned by [`to_text`](RefStr::to_text), ranges from its first token to its last token.
+/// The first token of the whole sequence also covers the text before it,
+/// and the last token of the whole sequence also covers the text after it,
+/// so that the whole sequence covers the whole source.
+///
+/// Two slices are equal if their tokens have the same kinds and the same text.
+pub struct Tokens<'i, T> {
+    source: &'i str,
+    tokens: &'i [T],
+    start: usize,
+    end: usize,
+}
+
+impl<'i, T: LexToken> Tokens<'i, T> {
+    /// Create from the source and the tokens lexed from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spans of tokens are out of the source, not aligned to character boundaries,
+    /// or not in ascending order.
+    pub fn new(source: &'i str, tokens: &'i [T]) -> Self {
+        let mut last = 0;
+        for token in tokens {
+            let span = token.span();
+            assert!(
+                last <= span.start && span.start <= span.end && source.get(span.clone()).is_some(),
+                "Invalid span {:?} of token {:?}.",
+                span,
+                token,
+            );
+            last = span.end;
+        }
+        Self {
+            source,
+            tokens,
+            start: 0,
+            end: tokens.len(),
+        }
+    }
+    /// Get the whole source.
+    #[inline]
+    pub const fn source(&self) -> &'i str {
+        self.source
+    }
+    /// Get tokens in this slice.
+    #[inline]
+    pub fn tokens(&self) -> &'i [T] {
+        &self.tokens[self.start..self.end]
+    }
+    /// Get the byte range in the source from the start of the first token to the end of the last token.
+    ///
+    /// An empty slice is mapped to the start of the next token,
+    /// or the end of the source if there is no next token.
+    #[inline]
+    pub fn source_range(&self) -> Range<usize> {
+        match self.tokens() {
+            [] => {
+                let pos = self.token_start(self.start);
+                pos..pos
+            }
+            [first, .., last] => first.span().start..last.span().end,
+            [only] => only.span(),
+        }
+    }
+
+    fn token_start(&self, index: usize) -> usize {
+        self.tokens
+            .get(index)
+            .map_or_else(|| self.source.len(), |token| token.span().start)
+    }
+    fn text_range(&self) -> Range<usize> {
+        if self.start == self.end {
+            let pos = if self.start == 0 {
+                0
+            } else {
+                self.token_start(self.start)
+            };
+            return pos..pos;
+        }
+        let start = if self.start == 0 {
+            0
+        } else {
+            self.tokens[self.start].span().start
+        };
+        let end = if self.end == self.tokens.len() {
+            self.source.len()
+        } else {
+            self.tokens[self.end - 1].span().end
+        };
+        start..end
+    }
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.end - self.start,
+        };
+        (start, end)
+    }
+    const fn slice(&self, start: usize, end: usize) -> Self {
+        Self {
+            start: self.start + start,
+            end: self.start + end,
+            ..*self
+        }
+    }
+    fn line_break_after(&self, index: usize) -> bool {
+        let gap = self.tokens[index].span().end..self.token_start(index + 1);
+        self.source[gap].contains('\n')
+    }
+}
+
+impl<T> Clone for Tokens<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Tokens<'_, T> {}
+
+impl<T: LexToken> fmt::Debug for Tokens<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.tokens().iter().map(LexToken::kind))
+            .finish()
+    }
+}
+
+impl<T: LexToken> PartialEq for Tokens<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs, rhs) = (self.tokens(), other.tokens());
+        lhs.len() == rhs.len()
+            && lhs.iter().zip(rhs).all(|(l, r)| {
+                let (l_text, r_text) = (&self.source[l.span()], &other.source[r.span()]);
+                l.kind() == r.kind() && l_text == r_text
+            })
+    }
+}
+impl<T: LexToken> Eq for Tokens<'_, T> {}
+
+impl<T: LexToken> Hash for Tokens<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.end - self.start);
+        for token in self.tokens() {
+            token.kind().hash(state);
+            self.source[token.span()].hash(state);
+        }
+    }
+}
+
+/// Each token is a single character,
+/// which is a line feed if there is a line break between it and the next token,
+/// and U+FFFC otherwise.
+/// Therefore, line numbers and column numbers are counted in tokens.
+unsafe impl<T: LexToken> RefStr for Tokens<'_, T> {
+    #[inline]
+    fn from_static(s: &'static str) -> Self {
+        Self {
+            source: s,
+            tokens: &[],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    fn to_text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.source[self.text_range()])
+    }
+
+    #[inline(always)]
+    fn char_len(_c: char) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn str_len(s: &str) -> usize {
+        usize::from(!s.is_empty())
+    }
+
+    #[inline]
+    fn text_at(&self, pos: usize) -> Option<(Cow<'_, str>, usize)> {
+        let text = self.text_range();
+        let offset = match pos {
+            0 => 0,
+            pos if pos < self.len() => self.token_start(self.start + pos) - text.start,
+            pos if pos == self.len() => text.len(),
+            _ => return None,
+        };
+        Some((self.to_text(), offset))
+    }
+
+    #[inline]
+    unsafe fn get_range_unchecked(&self, range: impl RangeBounds<usize>) -> Self {
+        let (start, end) = self.bounds(range);
+        debug_assert!(start <= end && end <= self.len());
+        self.slice(start, end)
+    }
+
+    #[inline]
+    fn get(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let (start, end) = self.bounds(range);
+        (start <= end && end <= self.len()).then(|| self.slice(start, end))
+    }
+
+    #[inline]
+    fn get_checked(&self, range: impl RangeBounds<usize>) -> Self {
+        self.get(range)
+            .unwrap_or_else(|| panic!("Range out of bounds"))
+    }
+
+    #[inline]
+    fn starts_with(&self, string: &str) -> bool {
+        string.is_empty()
+            || self
+                .tokens()
+                .first()
+                .is_some_and(|token| token.kind().matches(string))
+    }
+
+    #[inline]
+    fn starts_with_insensitive(&self, string: &str) -> bool {
+        string.is_empty()
+            || self
+                .tokens()
+                .first()
+                .is_some_and(|token| token.kind().matches_insensitive(string))
+    }
+
+    #[inline]
+    fn chars(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
+        (self.start..self.end).map(|index| match self.line_break_after(index) {
+            true => '\n',
+            false => '\u{fffc}',
+        })
+    }
+
+    #[inline]
+    fn ptr_eq(&self, other: &Self) -> bool {
+        ptr::eq::<str>(self.source, other.source)
+            && ptr::eq::<[T]>(self.tokens, other.tokens)
+            && self.start == other.start
+            && self.end == other.end
+    }
+
+    #[inline]
+    fn ptr_hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash::<str, H>(self.source, state);
+        ptr::hash::<[T], H>(self.tokens, state);
+        state.write_usize(self.start);
+        state.write_usize(self.end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cursor, Input};
+
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    enum Kind {
+        Select,
+        Ident,
+        Comma,
+    }
+
+    impl TokenKind for Kind {
+        fn matches(self, literal: &str) -> bool {
+            match self {
+                Self::Select => literal == "SELECT",
+                Self::Ident => literal == "IDENT",
+                Self::Comma => literal == ",",
+            }
+        }
+    }
+
+    const SOURCE: &str = " SELECT a,\n  a ";
+
+    fn tokens() -> [(Kind, Range<usize>); 4] {
+        [
+            (Kind::Select, 1..7),
+            (Kind::Ident, 8..9),
+            (Kind::Comma, 9..10),
+            (Kind::Ident, 13..14),
+        ]
+    }
+
+    #[test]
+    fn cursor() {
+        let tokens = tokens();
+        let input = Tokens::new(SOURCE, &tokens);
+        let mut cursor = input.as_cursor();
+
+        assert!(!cursor.match_string("IDENT"));
+        assert!(cursor.match_string("SELECT"));
+        assert!(cursor.match_string(""));
+        assert_eq!(cursor.byte_offset(), 1);
+        assert!(cursor.match_string("IDENT"));
+        assert!(cursor.skip_until(&["IDENT"]));
+        assert_eq!(cursor.byte_offset(), 3);
+        assert_eq!(cursor.advance_char(), Some('\u{fffc}'));
+        assert!(cursor.at_end());
+    }
+
+    #[test]
+    fn text() {
+        let tokens = tokens();
+        let input = Tokens::new(SOURCE, &tokens);
+
+        assert_eq!(input.to_text(), SOURCE);
+        assert_eq!(input.get_checked(1..3).to_text(), "a,");
+        assert_eq!(input.get_checked(1..3).source_range(), 8..10);
+        assert_eq!(input.get_checked(2..2).source_range(), 9..9);
+        assert_eq!(input.get_checked(1..).to_text(), "a,\n  a ");
+        assert_eq!(input.text_at(3).unwrap().1, 13);
+        assert_eq!(input.text_at(4).unwrap().1, SOURCE.len());
+        assert_eq!(input.text_at(5), None);
+
+        assert_eq!(input.get_checked(1..2), input.get_checked(3..4));
+        assert_ne!(input.get_checked(0..1), input.get_checked(1..2));
+        assert!(!input.get_checked(1..2).ptr_eq(&input.get_checked(3..4)));
+    }
+
+    #[test]
+    fn lines() {
+        let tokens = tokens();
+        let input = Tokens::new(SOURCE, &tokens);
+        let position = crate::Position::new(input, 3).unwrap();
+
+        assert_eq!(position.line_col(()), (2, 1));
+        assert_eq!(position.line_of(()).to_text(), "a ");
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_span() {
+        let tokens = [(Kind::Select, 1..7), (Kind::Ident, 5..9)];
+        let _ = Tokens::new(SOURCE, &tokens);
+    }
+}
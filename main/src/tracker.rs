@@ -10,10 +10,11 @@
 //! Tracker for parsing failures.
 
 use crate::{
-    error::{Error, ErrorVariant},
+    error::{Error, ErrorVariant, LineColLocation},
     input::RefStr,
     line_indexer::LineIndexer,
     position::Position,
+    span::Span,
     Cursor, RuleType, RuleWrapper,
 };
 use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String, vec::Vec};
@@ -21,6 +22,7 @@ use core::{
     cmp::Ordering,
     fmt::{self, Display},
 };
+use derive_where::derive_where;
 
 /// Some special errors that are not matching failures.
 pub enum SpecialError {
@@ -47,6 +49,88 @@ impl Display for SpecialError {
 
 type Tracked<R> = (Vec<R>, Vec<R>, Vec<SpecialError>);
 
+/// Event of a rule reported while checking.
+///
+/// See [`ParsableTypedNode::try_check_with_events`](crate::ParsableTypedNode::try_check_with_events).
+#[derive_where(Clone, Debug, Hash, Eq, PartialEq; S: RefStr)]
+pub enum Event<S, R: RuleType> {
+    /// Started matching a rule at the position.
+    Enter(R, Position<S>),
+    /// Matched a rule with the span.
+    Exit(R, Span<S>),
+    /// Failed to match a rule at the position.
+    ///
+    /// Fails are never rolled back.
+    Fail(R, Position<S>),
+    /// Discard the given number of latest [`Event::Enter`]s and [`Event::Exit`]s that are not discarded yet,
+    /// as the attempt reporting them has been backtracked.
+    Rollback(usize),
+}
+
+/// Receiver of [`Event`]s reported in the check path.
+///
+/// Nothing is reported to `()`, which is passed when events are not needed,
+/// so checking without events costs nothing more.
+///
+/// See [`TypedNode::try_check_partial_reporting_with`](crate::TypedNode::try_check_partial_reporting_with).
+pub trait EventSink<S, R: RuleType> {
+    /// Whether events are reported. Events are neither built nor counted if `false`.
+    const REPORTS: bool = false;
+    /// Report an event.
+    #[inline(always)]
+    fn report(&mut self, event: Event<S, R>) {
+        let _ = event;
+    }
+    /// Count of reported [`Event::Enter`]s and [`Event::Exit`]s that are not rolled back.
+    #[inline(always)]
+    fn reported(&self) -> usize {
+        0
+    }
+    /// Roll back events reported since there were `len` events, with an [`Event::Rollback`].
+    ///
+    /// [`Event::Fail`] is kept, as it is not a part of the matched tree.
+    #[inline(always)]
+    fn rollback(&mut self, len: usize) {
+        let _ = len;
+    }
+}
+
+impl<S, R: RuleType> EventSink<S, R> for () {}
+
+/// Report events to a callback as soon as they happen.
+pub(crate) struct Callback<F> {
+    f: F,
+    reported: usize,
+}
+impl<F> Callback<F> {
+    #[inline]
+    pub(crate) const fn new(f: F) -> Self {
+        Self { f, reported: 0 }
+    }
+}
+impl<S, R: RuleType, F: FnMut(Event<S, R>)> EventSink<S, R> for Callback<F> {
+    const REPORTS: bool = true;
+    #[inline]
+    fn report(&mut self, event: Event<S, R>) {
+        if matches!(event, Event::Enter(..) | Event::Exit(..)) {
+            self.reported += 1;
+        }
+        (self.f)(event);
+    }
+    #[inline]
+    fn reported(&self) -> usize {
+        self.reported
+    }
+    #[inline]
+    fn rollback(&mut self, len: usize) {
+        if self.reported > len {
+            let discarded = self.reported - len;
+            (self.f)(Event::Rollback(discarded));
+            self.reported = len;
+        }
+    }
+}
+
 /// Error tracker.
 pub struct Tracker<S, R: RuleType> {
     position: Position<S>,
@@ -72,7 +156,7 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
     }
     #[inline]
     fn prepare(&mut self, pos: Position<S>) -> bool {
-        debug_assert_eq!(pos.input(), self.position.input());
+        debug_assert!(pos.input().ptr_eq(&self.position.input()));
         match pos.cmp(&self.position) {
             Ordering::Less => false,
             Ordering::Equal => true,
@@ -162,7 +246,7 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
         if let Some((_, _, has_children)) = self.stack.last_mut() {
             *has_children = true;
         }
-        debug_assert_eq!(pos.input(), self.position.input());
+        debug_assert!(pos.input().ptr_eq(&self.position.input()));
         self.stack.push((rule, pos.byte_offset(), false));
         let res = f(self);
         let succeeded = res.is_some();
@@ -172,6 +256,34 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
         }
         res
     }
+    /// Record if the result doesn't match the state during calling `f`,
+    /// and report [`Event`]s of `rule` to `events`.
+    ///
+    /// This is used in the check path, where `f` returns the cursor after the rule.
+    #[inline]
+    pub fn record_reporting_during_with<C: Cursor<String = S>, E: EventSink<S, R>>(
+        &mut self,
+        pos: C,
+        events: &mut E,
+        f: impl FnOnce(&mut Self, &mut E) -> Option<C>,
+        rule: R,
+    ) -> Option<C> {
+        if !E::REPORTS {
+            return self.record_during_with(pos, |tracker| f(tracker, events), rule);
+        }
+        let len = events.reported();
+        let start = pos.as_position();
+        events.report(Event::Enter(rule, start.clone()));
+        let res = self.record_during_with(pos.clone(), |tracker| f(tracker, events), rule);
+        match &res {
+            Some(end) => events.report(Event::Exit(rule, pos.span(end))),
+            None => {
+                events.rollback(len);
+                events.report(Event::Fail(rule, start));
+            }
+        }
+        res
+    }
     /// Record if the result doesn't match the state during calling `f`.
     #[inline]
     pub fn record_during<U, T: RuleWrapper<R>>(
@@ -181,22 +293,18 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
     ) -> Option<(U, T)> {
         self.record_during_with(pos, f, T::RULE)
     }
-    fn collect_to_message(self, indexer: impl LineIndexer<S>) -> String {
+    fn collect_to_message(self, indexer: impl LineIndexer<S>, text: &str, offset: usize) -> String {
         let (pos, attempts) = self.finish();
+        let former = &text[..offset];
         // "{} | "
         // "{} = "
-        let (line, col) = pos.line_col(&indexer);
+        let line = match pos.input.lines_before_text() {
+            Some(lines) => lines + former.matches('\n').count() + 1,
+            None => pos.line_col(&indexer).0,
+        };
         let spacing = format!("{}", line).len() + 3;
         let spacing = "\n".to_owned() + &" ".repeat(spacing);
-        // Will not remove trailing CR or LF.
-        let line_string = pos.line_of(&indexer);
-        let line_remained_index = line_string
-            .as_str()
-            .char_indices()
-            .nth(col.saturating_sub(1))
-            .map(|(idx, _)| idx)
-            .unwrap_or_else(|| line_string.len());
-        let line_matched = &line_string.as_str()[..line_remained_index];
+        let line_matched = &former[former.rfind('\n').map_or(0, |i| i + 1)..];
 
         use core::fmt::Write;
         let mut message = String::new();
@@ -243,25 +351,14 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
         message
     }
     /// Collect attempts to [`Error<R>`]
+    ///
+    /// The matched part of the line is taken from the text from [`RefStr::text_at`],
+    /// as the text of byte inputs and tokens is not the input itself.
     pub fn collect(self, indexer: impl LineIndexer<S>) -> Error<R> {
         let pos = self.position.clone();
-        match Position::new(pos.input.clone(), pos.pos()).ok_or_else(|| {
-            Error::new_from_pos(
-                ErrorVariant::CustomError {
-                    message: format!("Internal error (invalid character index {}).", pos.pos()),
-                },
-                pest::Position::from_start(pos.input.as_str()),
-            )
-        }) {
-            Ok(pos) => {
-                let message = self.collect_to_message(indexer);
-                Error::new_from_pos(
-                    ErrorVariant::CustomError { message },
-                    pos.as_pest_position(),
-                )
-            }
-            Err(err) => err,
-        }
+        error_at(&pos, |text, offset| {
+            self.collect_to_message(indexer, text, offset)
+        })
     }
     /// Finish matching and convert the tracker into recorded information.
     ///
@@ -277,6 +374,42 @@ impl<S: RefStr, R: RuleType> Tracker<S, R> {
     }
 }
 
+/// Create an error at `pos` with the message built from the text from [`RefStr::text_at`] and the offset in it.
+pub(crate) fn error_at<S: RefStr, R: RuleType>(
+    pos: &Position<S>,
+    message: impl FnOnce(&str, usize) -> String,
+) -> Error<R> {
+    // Byte inputs are decoded and tokens are mapped to the source, so offsets are measured in the text.
+    let Some((text, offset)) = pos.input.text_at(pos.pos()) else {
+        // Such as released text of streams.
+        return Error::new_from_pos(
+            ErrorVariant::CustomError {
+                message: format!("Text at position {} is not available.", pos.pos()),
+            },
+            pest::Position::from_start(""),
+        );
+    };
+    match pest::Position::new(&text, offset) {
+        Some(text_pos) => {
+            let message = message(&text, offset);
+            let mut error = Error::new_from_pos(ErrorVariant::CustomError { message }, text_pos);
+            // Lines before the text are not counted by `pest`.
+            if let Some(lines) = pos.input.lines_before_text() {
+                if let LineColLocation::Pos((line, _)) = &mut error.line_col {
+                    *line += lines;
+                }
+            }
+            error
+        }
+        None => Error::new_from_pos(
+            ErrorVariant::CustomError {
+                message: format!("Internal error (invalid character index {}).", pos.pos()),
+            },
+            pest::Position::from_start(&text),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,11 +453,7 @@ mod tests {
         wrap!(SOI);
         wrap!(Main);
         wrap!(Body);
-
-        wrap!(
-            #[expect(dead_code)]
-            EOI
-        );
+        wrap!(EOI);
     }
     #[test]
     fn negative() -> Result<(), ()> {
@@ -391,6 +520,24 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn eoi() {
+        let mut pos = Position::from_start("abc");
+        assert!(pos.match_string("abc"));
+        let mut tracker = Tracker::<&str, Rule>::new(pos);
+        let _ = tracker.record_during(pos, |_| None::<((), rule_wrappers::EOI)>);
+
+        assert_eq!(
+            format!("{}", tracker.collect(())),
+            r#" --> 1:4
+  |
+1 | abc
+  |    ^---
+  |
+  = abc^---
+    Expected [EOI]."#
+        );
+    }
+    #[test]
     fn unicode() -> Result<(), ()> {
         let mut pos = Position::from_start("αβψ\nδεφ\nγηι");
         let mut tracker = Tracker::<&str, Rule>::new(pos);
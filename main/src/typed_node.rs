@@ -8,8 +8,12 @@
 // modified, or distributed except according to those terms.
 
 use crate::{
-    error::Error, line_indexer::LineIndexer, predefined_node::restore_on_none, span::Span,
-    tracker::Tracker, Cursor, Input, RuleType, RuleWrapper, Stack,
+    error::Error,
+    line_indexer::LineIndexer,
+    predefined_node::{restore_on_none, restore_on_none_with},
+    span::Span,
+    tracker::{Callback, Event, EventSink, Tracker},
+    Cursor, Input, RuleType, RuleWrapper, Stack,
 };
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt::Debug;
@@ -17,7 +21,7 @@ use core::fmt::Debug;
 /// Node of concrete syntax tree that never fails.
 pub trait NeverFailedTypedNode<C: Cursor, R: RuleType>
 where
-    Self: Sized + Debug + Clone + PartialEq + Default,
+    Self: Sized + Debug + Clone + PartialEq,
 {
     /// Create typed node.
     fn parse_with(cursor: C, stack: &mut Stack<Span<C::String>>) -> (C, Self);
@@ -44,6 +48,21 @@ where
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<C>;
+
+    /// Check whether the typed node match some prefix of the input,
+    /// and report [`Event`]s of rules in it to `events`.
+    ///
+    /// Nothing is reported by default, as for lookaheads and nodes without inner rules.
+    #[inline]
+    fn try_check_partial_reporting_with(
+        cursor: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> Option<C> {
+        let _ = events;
+        Self::try_check_partial_with(cursor, stack, tracker)
+    }
 }
 
 /// Node of concrete syntax tree.
@@ -103,6 +122,20 @@ pub trait ParsableTypedNode<C: Cursor, R: RuleType>: TypedNode<C, R> {
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> bool;
+    /// Check whether the typed node match the whole input,
+    /// and report [`Event`]s of rules in it to `events`.
+    ///
+    /// Nothing is reported by default.
+    #[inline]
+    fn try_check_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> bool {
+        let _ = events;
+        Self::try_check_with(input, stack, tracker)
+    }
     /// Check whether the typed node match the whole input.
     #[inline]
     fn try_check_with_cache(
@@ -143,25 +176,59 @@ pub trait ParsableTypedNode<C: Cursor, R: RuleType>: TypedNode<C, R> {
     fn try_check_partial(input: impl Input<Cursor = C>) -> Result<C, Box<Error<R>>> {
         Self::try_check_partial_with_cache(input, ())
     }
+    /// Check whether the typed node match the whole input,
+    /// and report [`Event`]s of the matched rules to `f` in order.
+    ///
+    /// No typed node is built, so this costs about as much as [`ParsableTypedNode::try_check`].
+    ///
+    /// Events are reported as soon as they happen, so nothing is buffered.
+    /// Events of attempts that are backtracked, such as failed alternatives,
+    /// are undone by a following [`Event::Rollback`], while [`Event::Fail`]s are never rolled back.
+    /// After applying rollbacks, [`Event::Enter`] and [`Event::Exit`] are always paired like a tree.
+    /// Rules inside atomic rules, lookaheads and auto-skipped trivia are not reported.
+    ///
+    /// The stream ends with [`Event::Fail`] if the input can not be matched.
+    #[inline]
+    fn try_check_with_events(
+        input: impl Input<Cursor = C>,
+        f: impl FnMut(Event<C::String, R>),
+    ) -> Result<(), Box<Error<R>>> {
+        let cursor = input.as_cursor();
+        let mut stack = Stack::new();
+        let mut tracker = Tracker::new(cursor.as_position());
+        let mut events = Callback::new(f);
+        let res = Self::try_check_reporting_with(cursor, &mut stack, &mut tracker, &mut events);
+        match res {
+            true => Ok(()),
+            false => Err(Box::new(tracker.collect(()))),
+        }
+    }
 }
 
-/// Node of concrete syntax tree.
+/// Node of concrete syntax tree that never fails.
+///
+/// Matching a prefix of the input never fails,
+/// so [`NeverFailedParsableTypedNode::parse_partial`] always returns a typed node.
+/// [`NeverFailedParsableTypedNode::parse`] returns [`None`] if input is left after the matched part.
 pub trait NeverFailedParsableTypedNode<C: Cursor, R: RuleType>: NeverFailedTypedNode<C, R> {
-    /// Create typed node.
-    fn parse_with_until_end(cursor: C, stack: &mut Stack<Span<C::String>>) -> Self;
-    /// Parse the whole input into given typed node.
+    /// Create typed node from the whole input,
+    /// or [`None`] if input other than trivia is left after the matched part.
+    fn parse_with_until_end(cursor: C, stack: &mut Stack<Span<C::String>>) -> Option<Self>;
+    /// Parse the whole input into given typed node,
+    /// or [`None`] if input other than trivia is left after the matched part.
     /// A rule is not atomic by default.
     #[inline]
-    fn parse(cursor: C) -> Self {
+    fn parse(input: impl Input<Cursor = C>) -> Option<Self> {
         let mut stack = Stack::new();
-        Self::parse_with_until_end(cursor, &mut stack)
+        Self::parse_with_until_end(input.as_cursor(), &mut stack)
     }
-    /// Parse the whole input into given typed node.
+    /// Parse the input from the start into given typed node,
+    /// and return the cursor after the matched part.
     /// A rule is not atomic by default.
     #[inline]
-    fn parse_partial(cursor: C) -> (C, Self) {
+    fn parse_partial(input: impl Input<Cursor = C>) -> (C, Self) {
         let mut stack = Stack::new();
-        Self::parse_with(cursor, &mut stack)
+        Self::parse_with(input.as_cursor(), &mut stack)
     }
 }
 
@@ -175,9 +242,15 @@ impl<R: RuleType, T: RuleWrapper<R>> RuleStorage<R> for T {
     }
 }
 
-/// A trait for those struct that correspond to non-silent rules.
+/// A trait for nodes that know the span they have matched.
+///
+/// Implemented by rule structs, predefined nodes,
+/// and containers such as sequences, choices and silent rules whose contents all implement it.
+///
+/// Nodes that consume nothing have empty spans at their positions.
+/// Use [`Optional`](crate::predefined_node::Optional) rather than [`Option`] for a span of an optional node.
 pub trait Spanned<I, R: RuleType> {
-    /// The span of a matched expression by a non-silent rule.
+    /// The span of the matched expression.
     fn span(&self) -> Span<I>;
 }
 
@@ -216,12 +289,22 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>, const N: usize> TypedNode<C, R>
 
     #[inline]
     fn try_check_partial_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+    ) -> Option<C> {
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
         mut input: C,
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
     ) -> Option<C> {
         for _ in 0..N {
-            let next = T::try_check_partial_with(input, stack, tracker)?;
+            let next = T::try_check_partial_reporting_with(input, stack, tracker, events)?;
             input = next;
         }
         Some(input)
@@ -249,8 +332,18 @@ impl<C: Cursor, R: RuleType, T1: TypedNode<C, R>, T2: TypedNode<C, R>> TypedNode
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<C> {
-        let input = T1::try_check_partial_with(input, stack, tracker)?;
-        T2::try_check_partial_with(input, stack, tracker)
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> Option<C> {
+        let input = T1::try_check_partial_reporting_with(input, stack, tracker, events)?;
+        T2::try_check_partial_reporting_with(input, stack, tracker, events)
     }
 }
 
@@ -277,8 +370,18 @@ impl<C: Cursor, R: RuleType, T: TypedNode<C, R>> TypedNode<C, R> for Option<T> {
         stack: &mut Stack<Span<C::String>>,
         tracker: &mut Tracker<C::String, R>,
     ) -> Option<C> {
-        match restore_on_none(stack, |stack| {
-            T::try_check_partial_with(input.clone(), stack, tracker)
+        Self::try_check_partial_reporting_with(input, stack, tracker, &mut ())
+    }
+
+    #[inline]
+    fn try_check_partial_reporting_with(
+        input: C,
+        stack: &mut Stack<Span<C::String>>,
+        tracker: &mut Tracker<C::String, R>,
+        events: &mut impl EventSink<C::String, R>,
+    ) -> Option<C> {
+        match restore_on_none_with(stack, events, |stack, events| {
+            T::try_check_partial_reporting_with(input.clone(), stack, tracker, events)
         }) {
             Some(input) => Some(input),
             None => Some(input),
@@ -9,7 +9,7 @@
 
 //! Wrappers of constants and types, so that they can be used in generics easier.
 
-use crate::RuleType;
+use crate::{predefined_node::Trivia, RuleType};
 
 /// An object containing a constant.
 pub trait Storage<T> {
@@ -52,6 +52,63 @@ impl<T: StringArrayWrapper> Storage<&'static [&'static str]> for T {
     }
 }
 
+/// A wrapper for a set of characters as a generics argument.
+///
+/// A character belongs to the set if it's in one of [`CharSetWrapper::RANGES`]
+/// or satisfies one of [`CharSetWrapper::PROPERTIES`].
+///
+/// ASCII characters in [`CharSetWrapper::RANGES`] are looked up in a precomputed bitmap,
+/// and other characters are looked up by binary search.
+pub trait CharSetWrapper {
+    /// Inclusive character ranges.
+    ///
+    /// Should be sorted and non-overlapping, or binary search may give wrong results.
+    const RANGES: &'static [(char, char)];
+    /// Predicates (usually unicode properties in [`pest::unicode`]) accepted by this set.
+    const PROPERTIES: &'static [fn(char) -> bool] = &[];
+    /// Bitmap of ASCII characters in [`CharSetWrapper::RANGES`].
+    const ASCII: u128 = ascii_bitmap(Self::RANGES);
+
+    /// Whether `c` is in this set.
+    #[inline]
+    fn contains(c: char) -> bool {
+        let in_ranges = if c.is_ascii() {
+            Self::ASCII & (1u128 << c as u32) != 0
+        } else {
+            let index = Self::RANGES.partition_point(|&(_, max)| max < c);
+            matches!(Self::RANGES.get(index), Some(&(min, _)) if min <= c)
+        };
+        in_ranges || Self::PROPERTIES.iter().any(|f| f(c))
+    }
+}
+
+/// Collect ASCII characters in `ranges` into a bitmap.
+const fn ascii_bitmap(ranges: &[(char, char)]) -> u128 {
+    let mut res = 0u128;
+    let mut i = 0;
+    while i < ranges.len() {
+        let (min, max) = ranges[i];
+        let mut c = min as u32;
+        while c <= max as u32 && c < 128 {
+            res |= 1u128 << c;
+            c += 1;
+        }
+        i += 1;
+    }
+    res
+}
+
+/// A wrapper for trivia shapes as a generics argument.
+///
+/// See [`crate::predefined_node::SkipTrivia`].
+pub trait TriviaWrapper {
+    /// Trivia to skip, tried in order.
+    const TRIVIA: &'static [Trivia];
+    /// Number of leading shapes in [`TRIVIA`](Self::TRIVIA) from `WHITESPACE`.
+    /// The rest are from `COMMENT`.
+    const WHITESPACE: usize = Self::TRIVIA.len();
+}
+
 /// Rule wrapper.
 pub trait RuleWrapper<R: RuleType> {
     /// Wrapped rule.
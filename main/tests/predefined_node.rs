@@ -22,8 +22,8 @@ use pest_typed::{
     predefined_node::*,
     rule_eoi,
     sequence::{Seq2, Seq3},
-    silent_rule, BoundWrapper, ParsableTypedNode, RefStr, RuleStruct, RuleType, RuleWrapper,
-    Storage, StringArrayWrapper, StringWrapper, TypeWrapper,
+    silent_rule, BoundWrapper, CharSetWrapper, ParsableTypedNode, RefStr, RuleStruct, RuleType,
+    RuleWrapper, Storage, StringArrayWrapper, StringWrapper, TypeWrapper,
 };
 use std::{fmt::Write, ops::Deref, string::String};
 
@@ -75,14 +75,14 @@ atomic_rule!(
     "White space.",
     Rule,
     Rule::WHITESPACE,
-    CharRange::<' ', ' '>
+    CharRange::<S, ' ', ' '>
 );
 compound_atomic_rule!(
     COMMENT,
     "Comment.",
     Rule,
     Rule::COMMENT,
-    CharRange::<'\t', '\t'>,
+    CharRange::<S, '\t', '\t'>,
     false
 );
 normal_rule!(
@@ -90,8 +90,8 @@ normal_rule!(
     "String \"Foo\".",
     Rule,
     Rule::Foo,
-    Str::<Foo>,
-    AtomicRepeat<Choice2<WHITESPACE<C::String>, COMMENT<C::String>>>,
+    Str::<S, Foo>,
+    AtomicRepeat<C::String, Choice2<WHITESPACE<C::String>, COMMENT<C::String>>>,
     false
 );
 rule_eoi!(EOI, Rule);
@@ -123,7 +123,7 @@ fn test_range<S: RefStr>() {
     let comment = COMMENT::try_parse(S::from_static("\t")).unwrap();
     assert_eq!(
         format!("{:?}", comment),
-        "COMMENT { content: CharRange { content: '\\t' }, span: Span { str: \"\\t\", start: 0, end: 1 } }"
+        "COMMENT { content: CharRange { content: '\\t', span: Span { str: \"\\t\", start: 0, end: 1 } }, span: Span { str: \"\\t\", start: 0, end: 1 } }"
     );
 }
 
@@ -140,7 +140,52 @@ fn range() {
     test_range::<shared_vec::ArcString>();
 }
 
-type Ignore<S> = AtomicRepeat<Choice2<WHITESPACE<S>, COMMENT<S>>>;
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct IdentChar;
+impl CharSetWrapper for IdentChar {
+    const RANGES: &'static [(char, char)] =
+        &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z'), ('α', 'ω')];
+    const PROPERTIES: &'static [fn(char) -> bool] = &[pest_typed::unicode::IDEOGRAPHIC];
+}
+
+#[test]
+fn char_set() {
+    for c in ['0', '9', 'A', 'Z', '_', 'a', 'z', 'α', 'ω', '中'] {
+        assert!(IdentChar::contains(c), "{c:?}");
+    }
+    for c in ['/', ':', '@', '[', '`', '{', ' ', '\x7f', 'Ω', '√'] {
+        assert!(!IdentChar::contains(c), "{c:?}");
+    }
+
+    atomic_rule!(
+        Ident,
+        "Identifier characters.",
+        Rule,
+        Rule::String,
+        RepOnce<S, CharSet<S, IdentChar>, Ignore<S>, 0>
+    );
+    silent_rule!(
+        Hex,
+        "Hexadecimal digits.",
+        Rule,
+        Rule::String,
+        RepOnce<S, ASCII_HEX_DIGIT<S>, Ignore<S>, 0>,
+        Ignore<S>,
+        false
+    );
+    Ident::try_check("a_0β中").unwrap();
+    Ident::try_check("a-b").unwrap_err();
+    let hex = Hex::try_parse("09afAF").unwrap();
+    let chars: String = hex.content.iter_matched().map(|c| c.content).collect();
+    assert_eq!(chars, "09afAF");
+    assert_eq!(
+        format!("{:?}", hex.content.iter_matched().next().unwrap()),
+        "CharSet { content: '0' }"
+    );
+    Hex::try_check("0g").unwrap_err();
+}
+
+type Ignore<S> = AtomicRepeat<S, Choice2<WHITESPACE<S>, COMMENT<S>>>;
 
 #[test]
 fn ignore() {
@@ -158,7 +203,7 @@ fn ignore() {
 }
 
 fn test_repetition<S: RefStr>() {
-    type REP<S> = Rep<StrFoo<S, 0>, Ignore<S>, 1>;
+    type REP<S> = Rep<S, StrFoo<S, 0>, Ignore<S>, 1>;
     non_atomic_rule!(
         R,
         "Repetion of [StrFoo].",
@@ -234,8 +279,9 @@ fn repetition() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn repetition_at_least_once() {
-    type REP<S> = RepOnce<Insens<S, Foo>, Ignore<S>, 1>;
+    type REP<S> = RepOnce<S, Insens<S, Foo>, Ignore<S>, 1>;
     non_atomic_rule!(
         R,
         "Repetion of [StrFoo].",
@@ -297,7 +343,7 @@ fn skip() {
         "Quoted string.",
         Rule,
         Rule::String,
-        Seq2<Skipped<Skip<S, NewLine>, Ignore<S>, 0>, Skipped<NEWLINE, Ignore<S>, 0>>,
+        Seq2<Skipped<Skip<S, NewLine>, Ignore<S>, 0>, Skipped<NEWLINE<S>, Ignore<S>, 0>>,
         false
     );
 
@@ -335,13 +381,13 @@ fn positive_predicate() {
         "Match 'a'.",
         Rule,
         Rule::Quote,
-        (Positive<Str<A>>, Str<A>)
+        (Positive<Str<S, A>>, Str<S, A>)
     );
     PositiveAThenA::try_parse("ab").unwrap_err();
     PositiveAThenA::try_parse("a").unwrap();
     PositiveAThenA::try_parse("aa").unwrap_err();
 
-    atomic_rule!(Quote, "A single `'`.", Rule, Rule::Quote, CharRange<'\'', '\''>);
+    atomic_rule!(Quote, "A single `'`.", Rule, Rule::Quote, CharRange<S, '\'', '\''>);
     compound_atomic_rule!(
         Lifetime,
         "A simplified example of rust lifetime specifier.",
@@ -351,12 +397,15 @@ fn positive_predicate() {
             Skipped<Quote<S, 0>, Ignore<S>, 0>,
             Skipped<
                 Positive<
-                    Seq2<Skipped<ANY, Ignore<S>, 0>, Skipped<Negative<Quote<S, 0>>, Ignore<S>, 0>>,
+                    Seq2<
+                        Skipped<ANY<S>, Ignore<S>, 0>,
+                        Skipped<Negative<S, Quote<S, 0>>, Ignore<S>, 0>,
+                    >,
                 >,
                 Ignore<S>,
                 0,
             >,
-            Skipped<AtomicRepeat<CharRange<'a', 'z'>>, Ignore<S>, 0>,
+            Skipped<AtomicRepeat<S, CharRange<S, 'a', 'z'>>, Ignore<S>, 0>,
         >,
         false
     );
@@ -407,7 +456,7 @@ fn negative_predicate() {
         "Any character except \"foo\" or \"bar\".",
         Rule,
         Rule::NotFooBar,
-        AtomicRepeat<(Negative<Choice2<Str<StrFoo>, Str<StrBar>>>, ANY)>,
+        AtomicRepeat<S, (Negative<S, Choice2<Str<S, StrFoo>, Str<S, StrBar>>>, ANY<S>)>,
         false
     );
     let _ = not_foo_bar::<&str, 1>::try_parse("").unwrap();
@@ -415,10 +464,13 @@ fn negative_predicate() {
     for i in baz.iter() {
         let (neg, any) = i;
         assert_eq!(
-            <Negative<_> as Pairs<&str, Rule>>::self_or_children(neg).len(),
+            <Negative<_, _> as Pairs<&str, Rule>>::self_or_children(neg).len(),
+            0
+        );
+        assert_eq!(
+            <ANY<&str> as Pairs<&str, Rule>>::self_or_children(any).len(),
             0
         );
-        assert_eq!(<ANY as Pairs<&str, Rule>>::self_or_children(any).len(), 0);
     }
     let _ = not_foo_bar::<&str, 1>::try_parse("Foofoo").unwrap_err();
     let _ = not_foo_bar::<&str, 1>::try_parse("bazfoo").unwrap_err();
@@ -433,7 +485,7 @@ fn peek() {
         Rule::RepFoo,
         Seq2<
             Skipped<Push<Insens<S, Foo>>, Ignore<S>, 0>,
-            Skipped<RepeatMinMax<Skipped<PEEK<S>, Ignore<S>, 0>, 1, 3>, Ignore<S>, 0>,
+            Skipped<RepeatMinMax<S, Skipped<PEEK<S>, Ignore<S>, 0>, 1, 3>, Ignore<S>, 0>,
         >,
         false
     );
@@ -457,6 +509,11 @@ fn peek() {
                     },
                 },
             ],
+            span: Span {
+                str: \"foO\",
+                start: 3,
+                end: 6,
+            },
         },
     ),
     span: Span {
@@ -503,7 +560,7 @@ fn rep() {
         "Repeat previously matched expression 0 to 3 times",
         Rule,
         Rule::RepFoo,
-        RepeatMin<Skipped<Str<Foo>, Ignore<S>, 0>, 0>,
+        RepeatMin<S, Skipped<Str<S, Foo>, Ignore<S>, 0>, 0>,
         false
     );
 
@@ -583,8 +640,8 @@ fn nested_rep() {
         Rule,
         Rule::NestedRep,
         (
-            RepeatMin<Skipped<Str<Bar1>, Ignore<S>, 0>, 0>,
-            Choice2<(SOI, ANY), Str<Bar2>>
+            RepeatMin<S, Skipped<Str<S, Bar1>, Ignore<S>, 0>, 0>,
+            Choice2<(SOI<S>, ANY<S>), Str<S, Bar2>>
         ),
         false
     );
@@ -592,12 +649,12 @@ fn nested_rep() {
     let x = Nested_Rep::try_parse("x").unwrap();
     assert_eq!(
         format!("{:?}", x),
-        "Nested_Rep { content: (RepeatMin { content: [] }, Choice2 { _0: (SOI, ANY { content: 'x' }) }), span: Span { str: \"x\", start: 0, end: 1 } }"
+        "Nested_Rep { content: (RepeatMin { content: [], span: Span { str: \"\", start: 0, end: 0 } }, Choice2 { _0: (SOI { span: Span { str: \"\", start: 0, end: 0 } }, ANY { content: 'x', span: Span { str: \"x\", start: 0, end: 1 } }) }), span: Span { str: \"x\", start: 0, end: 1 } }"
     );
 
     let x = Nested_Rep::try_parse("ab").unwrap();
     assert_eq!(
         format!("{:?}", x),
-        "Nested_Rep { content: (RepeatMin { content: [Str] }, Choice2 { _1: Str }), span: Span { str: \"ab\", start: 0, end: 2 } }"
+        "Nested_Rep { content: (RepeatMin { content: [Str], span: Span { str: \"a\", start: 0, end: 1 } }, Choice2 { _1: Str }), span: Span { str: \"ab\", start: 0, end: 2 } }"
     );
 }